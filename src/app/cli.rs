@@ -1,12 +1,23 @@
 use std::{env, fs};
-use std::{fmt::Display, path::PathBuf, str::FromStr};
+use std::{
+    fmt::Display,
+    path::{Path, PathBuf},
+    str::FromStr,
+};
 
 use anyhow::anyhow;
 use clap::{CommandFactory, Parser};
 use tracing_subscriber::filter::{self, Directive};
 
+use crate::auth::device::DEFAULT_SCOPES;
 use crate::errors::AppError;
-use crate::logging::{PROJECT_NAME, get_data_dir};
+use crate::github::ProxyConfig;
+use crate::logging::{DEFAULT_MAX_LOG_FILES, PROJECT_NAME, get_data_dir};
+use crate::ui::DEFAULT_LABEL_CACHE_TTL;
+use crate::ui::components::{
+    issue_conversation::{DEFAULT_COMMENT_WARN_CHARS, DEFAULT_TAB_WIDTH},
+    label_list::LabelSort,
+};
 
 #[derive(Parser)]
 #[clap(author, version = version(), about, long_about = None, styles = get_styles())]
@@ -14,25 +25,89 @@ pub struct Cli {
     /// Top-level CLI arguments controlling repository selection and runtime behavior.
     #[clap(flatten)]
     pub args: Args,
+    /// Runs a one-off command against `owner/repo` instead of starting the TUI.
+    #[clap(subcommand)]
+    pub command: Option<Command>,
+}
+
+#[derive(clap::Subcommand, Clone)]
+pub enum Command {
+    /// Posts a comment on an issue or pull request and prints the new comment's URL.
+    ///
+    /// Never prompts for a token, so store one with `--set-token` or pass `--env` first.
+    Comment(CommentArgs),
+    /// Opens a new issue and prints its number and URL.
+    ///
+    /// Never prompts for a token, so store one with `--set-token` or pass `--env` first.
+    Create(CreateArgs),
+    /// Signs in through GitHub's device flow and stores the token like `--set-token`.
+    ///
+    /// Prints a code to enter at the shown URL, then waits until it has been authorized.
+    /// Doesn't need OWNER or REPO.
+    Auth(AuthArgs),
+}
+
+#[derive(clap::Args, Clone)]
+pub struct AuthArgs {
+    /// Client ID of the OAuth app to sign in with. Device flow must be enabled for the app.
+    #[clap(long, env = "GITV_TUI_CLIENT_ID")]
+    pub client_id: String,
+    /// Comma separated OAuth scopes to request.
+    #[clap(long, value_delimiter = ',', default_value = DEFAULT_SCOPES)]
+    pub scopes: Vec<String>,
+}
+
+#[derive(clap::Args, Clone)]
+pub struct CommentArgs {
+    /// Issue or pull request number to comment on.
+    pub number: u64,
+    /// Comment body in Markdown. Read from stdin when neither this nor `--body-file` is given.
+    #[clap(long, conflicts_with = "body_file")]
+    pub body: Option<String>,
+    /// File to read the comment body from; `-` reads stdin.
+    #[clap(long, value_name = "PATH")]
+    pub body_file: Option<PathBuf>,
+}
+
+#[derive(clap::Args, Clone)]
+pub struct CreateArgs {
+    /// Title of the new issue.
+    #[clap(long)]
+    pub title: String,
+    /// Issue body in Markdown. Read from stdin when neither this nor `--body-file` is given.
+    #[clap(long, conflicts_with = "body_file")]
+    pub body: Option<String>,
+    /// File to read the issue body from; `-` reads stdin.
+    #[clap(long, value_name = "PATH")]
+    pub body_file: Option<PathBuf>,
 }
 
 #[derive(clap::Args, Clone)]
 pub struct Args {
     /// GitHub repository owner or organization (for example: `rust-lang`).
     ///
-    /// This is required unless `--print-log-dir` or `--set-token` is provided.
-    #[clap(required_unless_present_any = [ "print_log_dir", "set_token", "generate_man" ])]
+    /// Falls back to `default_owner` in the config file, then, inside a git checkout, to the
+    /// owner of the `origin` remote.
     pub owner: Option<String>,
     /// GitHub repository name under `owner` (for example: `rust`).
     ///
-    /// This is required unless `--print-log-dir` or `--set-token` is provided.
-    #[clap(required_unless_present_any = [ "print_log_dir", "set_token", "generate_man" ])]
+    /// Falls back to `default_repo` in the config file, then, inside a git checkout, to the
+    /// name of the `origin` remote.
     pub repo: Option<String>,
     /// Global logging verbosity used by the application logger.
     ///
-    /// Defaults to `info`.
-    #[clap(long, short, default_value_t = LogLevel::Info)]
-    pub log_level: LogLevel,
+    /// Falls back to `log_level` in the config file, then `info`. The command palette's "Log
+    /// level" entries change it while the app runs.
+    #[clap(long, short)]
+    pub log_level: Option<LogLevel>,
+    /// How often the log file is rotated.
+    ///
+    /// Defaults to `daily`.
+    #[clap(long, default_value_t = LogRotation::Daily)]
+    pub log_rotation: LogRotation,
+    /// Number of rotated log files to keep before the oldest is deleted.
+    #[clap(long, default_value_t = DEFAULT_MAX_LOG_FILES)]
+    pub log_retention: usize,
     /// Prints the directory where log files are written and exits.
     #[clap(long, short)]
     pub print_log_dir: bool,
@@ -45,12 +120,247 @@ pub struct Args {
     #[clap(long)]
     pub generate_man: bool,
 
+    /// Prints a completion script for `SHELL` to stdout and exits.
+    #[clap(long, value_enum, value_name = "SHELL")]
+    pub generate_completions: Option<clap_complete::Shell>,
+
     /// When provided, this command will read the GitHub token from the environment variable
     #[clap(short, long)]
     pub env: bool,
+
+    /// Runs without contacting GitHub, serving issues, comments and labels from JSON fixtures in
+    /// `DIR` (see `fixtures/mock`). Changes are rejected instead of being sent.
+    #[clap(long, alias = "offline", value_name = "DIR")]
+    pub mock: Option<PathBuf>,
+
+    /// PEM file with extra root certificates to trust, e.g. a corporate CA.
+    #[clap(long, env = "GITV_TUI_CA_CERT", value_name = "PATH")]
+    pub ca_cert: Option<PathBuf>,
+
+    /// HTTP proxy to tunnel GitHub traffic through (`http://[user[:password]@]host:port`).
+    ///
+    /// Falls back to `proxy` in the config file, then to `HTTPS_PROXY`. Not used when `NO_PROXY`
+    /// lists the GitHub API host.
+    #[clap(
+        long,
+        env = "GITV_TUI_PROXY",
+        value_name = "URL",
+        hide_env_values = true
+    )]
+    pub proxy: Option<ProxyConfig>,
+
+    /// API root to use instead of `https://api.github.com`, e.g. for GitHub Enterprise Server.
+    ///
+    /// Falls back to `base_url` in the config file.
+    #[clap(long, env = "GITV_TUI_BASE_URL", value_name = "URL")]
+    pub base_url: Option<String>,
+
+    /// Comma separated columns shown on each issue list row, in order.
+    ///
+    /// Available: number, title, author, state, labels, dots (a colored dot per label),
+    /// comments, updated. Unknown names are ignored. Defaults to
+    /// `number,title,state,dots,comments`.
+    #[clap(long, env = "GITV_TUI_COLUMNS", value_name = "LIST")]
+    pub columns: Option<String>,
+
+    /// Number of columns a tab character advances to in rendered code blocks.
+    #[clap(
+        long,
+        default_value_t = DEFAULT_TAB_WIDTH as u16,
+        value_parser = clap::value_parser!(u16).range(1..=16)
+    )]
+    pub tab_width: u16,
+
+    /// Creates missing labels without asking for confirmation first; only the color prompt is
+    /// shown.
+    #[clap(long, env = "GITV_TUI_NO_LABEL_CONFIRM")]
+    pub no_label_confirm: bool,
+
+    /// Order labels are listed in.
+    #[clap(long, value_enum, env = "GITV_TUI_LABEL_SORT", default_value_t = LabelSort::Name)]
+    pub label_sort: LabelSort,
+
+    /// Shows labels named `prefix: name` (e.g. `area: parser`) in sections per prefix.
+    #[clap(long, env = "GITV_TUI_GROUP_LABELS")]
+    pub group_labels: bool,
+
+    /// Extra logins whose comments are highlighted as your own, e.g. `my-bot[bot]`.
+    #[clap(
+        long,
+        env = "GITV_TUI_SELF_LOGINS",
+        value_name = "LOGIN",
+        value_delimiter = ','
+    )]
+    pub self_logins: Vec<String>,
+
+    /// Checks the open issue for new comments every `SECONDS` and shows how many arrived.
+    /// Polling pauses while the terminal is unfocused or idle. 0 disables it.
+    #[clap(
+        long,
+        env = "GITV_TUI_POLL_INTERVAL",
+        value_name = "SECONDS",
+        default_value_t = 0
+    )]
+    pub poll_interval: u64,
+
+    /// Separates multiple labels typed into the search bar's label field. Quote a label name to
+    /// include the separator in it, e.g. `"foo;bar"`.
+    #[clap(
+        long,
+        env = "GITV_TUI_LABEL_SEPARATOR",
+        value_name = "CHAR",
+        default_value_t = ';'
+    )]
+    pub label_separator: char,
+
+    /// How long the repository's label list is cached before the next label add refetches it.
+    /// Press `R` in the label list to refresh it sooner.
+    #[clap(
+        long,
+        env = "GITV_TUI_LABEL_CACHE_TTL",
+        value_name = "SECONDS",
+        default_value_t = DEFAULT_LABEL_CACHE_TTL.as_secs()
+    )]
+    pub label_cache_ttl: u64,
+
+    /// Length at which the comment composer's character count turns red. GitHub rejects
+    /// comments over 65536 characters, so those are never sent.
+    #[clap(
+        long,
+        env = "GITV_TUI_COMMENT_WARN_CHARS",
+        value_name = "CHARS",
+        default_value_t = DEFAULT_COMMENT_WARN_CHARS
+    )]
+    pub comment_warn_chars: usize,
+
+    /// Number of issues fetched per search request, at most 100.
+    ///
+    /// Falls back to `per_page` in the config file. Defaults to 15 for the first load and 10 for
+    /// searches.
+    #[clap(long, env = "GITV_TUI_PER_PAGE", value_name = "COUNT")]
+    pub per_page: Option<u32>,
+
+    /// Search query the issue list starts with, e.g. `is:open sort:updated`. It is also put in
+    /// the search input.
+    ///
+    /// Falls back to `default_query` in the config file, then to the open issues.
+    #[clap(long, env = "GITV_TUI_DEFAULT_QUERY", value_name = "QUERY")]
+    pub default_query: Option<String>,
+
+    /// Seconds to wait for GitHub to answer a request before giving up with an error; 0 waits
+    /// forever. Press `Ctrl+G` to cancel a search or an issue list load sooner.
+    ///
+    /// Falls back to `request_timeout` in the config file, then 30.
+    #[clap(long, env = "GITV_TUI_REQUEST_TIMEOUT", value_name = "SECONDS")]
+    pub request_timeout: Option<u64>,
+}
+
+impl Args {
+    /// Takes OWNER and REPO from the `origin` remote when neither the command line nor the config
+    /// file set them and the current directory is inside a git checkout. Fails if that remote
+    /// doesn't point at GitHub.
+    pub fn detect_repository(&mut self) -> Result<(), AppError> {
+        if self.owner.is_some() || self.repo.is_some() {
+            return Ok(());
+        }
+        let Some(url) = origin_url(&env::current_dir()?) else {
+            return Ok(());
+        };
+        let (owner, repo) = parse_github_remote(&url).ok_or_else(|| {
+            anyhow!(
+                "the `origin` remote ({url}) is not a GitHub repository; pass OWNER and REPO \
+                 explicitly"
+            )
+        })?;
+        self.owner = Some(owner);
+        self.repo = Some(repo);
+        Ok(())
+    }
+
+    /// The repository to open, once the config file has filled in anything left out.
+    pub fn repository(&self) -> Result<(String, String), AppError> {
+        match (&self.owner, &self.repo) {
+            (Some(owner), Some(repo)) => Ok((owner.clone(), repo.clone())),
+            _ => Err(anyhow!(
+                "no repository given; pass OWNER and REPO, run inside a GitHub checkout or set \
+                 `default_owner` and `default_repo` in `{}`",
+                crate::config::config_file().display()
+            )
+            .into()),
+        }
+    }
+}
+
+/// URL of the `origin` remote of the checkout containing `dir`, if there is one.
+fn origin_url(dir: &Path) -> Option<String> {
+    let dot_git = dir
+        .ancestors()
+        .map(|dir| dir.join(".git"))
+        .find(|path| path.exists())?;
+    let git_dir = if dot_git.is_file() {
+        // Worktrees and submodules point at the real git directory, which for worktrees in
+        // turn points at the main repository holding the config.
+        let pointer = fs::read_to_string(&dot_git).ok()?;
+        let git_dir = dot_git
+            .parent()?
+            .join(pointer.strip_prefix("gitdir:")?.trim());
+        match fs::read_to_string(git_dir.join("commondir")) {
+            Ok(common) => git_dir.join(common.trim()),
+            Err(_) => git_dir,
+        }
+    } else {
+        dot_git
+    };
+    let config = fs::read_to_string(git_dir.join("config")).ok()?;
+    remote_url(&config, "origin")
+}
+
+/// Reads `url` from the `[remote "<name>"]` section of a git config file.
+fn remote_url(config: &str, name: &str) -> Option<String> {
+    let section = format!("remote \"{name}\"");
+    let mut in_section = false;
+    for line in config.lines().map(str::trim) {
+        if let Some(header) = line.strip_prefix('[') {
+            in_section = header.trim_end_matches(']').trim() == section;
+        } else if in_section
+            && let Some((key, value)) = line.split_once('=')
+            && key.trim() == "url"
+        {
+            return Some(value.trim().trim_matches('"').to_string());
+        }
+    }
+    None
+}
+
+/// Owner and repository of a GitHub remote in SSH (`git@github.com:owner/repo.git`,
+/// `ssh://git@github.com/owner/repo`) or HTTPS (`https://github.com/owner/repo.git`) form.
+fn parse_github_remote(url: &str) -> Option<(String, String)> {
+    let path = match url.strip_prefix("git@github.com:") {
+        Some(path) => path,
+        None => {
+            let (_, rest) = url.split_once("://")?;
+            let (authority, path) = rest.split_once('/')?;
+            let host = authority
+                .rsplit_once('@')
+                .map_or(authority, |(_, host)| host);
+            let host = host.split_once(':').map_or(host, |(host, _)| host);
+            if !host.eq_ignore_ascii_case("github.com") {
+                return None;
+            }
+            path
+        }
+    };
+    let path = path.trim_end_matches('/');
+    let path = path.strip_suffix(".git").unwrap_or(path);
+    let (owner, repo) = path.split_once('/')?;
+    if owner.is_empty() || repo.is_empty() || repo.contains('/') {
+        return None;
+    }
+    Some((owner.to_string(), repo.to_string()))
 }
 
-#[derive(clap::ValueEnum, Clone, Debug)]
+#[derive(clap::ValueEnum, serde::Deserialize, Clone, Debug)]
+#[serde(rename_all = "lowercase")]
 pub enum LogLevel {
     Trace,
     Debug,
@@ -74,6 +384,26 @@ impl Display for LogLevel {
     }
 }
 
+#[derive(clap::ValueEnum, Clone, Copy, Debug)]
+pub enum LogRotation {
+    Hourly,
+    Daily,
+    Weekly,
+    Never,
+}
+
+impl Display for LogRotation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let s = match self {
+            LogRotation::Hourly => "hourly",
+            LogRotation::Daily => "daily",
+            LogRotation::Weekly => "weekly",
+            LogRotation::Never => "never",
+        };
+        write!(f, "{s}")
+    }
+}
+
 impl TryFrom<LogLevel> for Directive {
     type Error = filter::ParseError;
     fn try_from(value: LogLevel) -> Result<Self, Self::Error> {
@@ -176,3 +506,44 @@ pub fn generate_man_pages() -> Result<PathBuf, AppError> {
 
     Ok(man1_dir.join(man1_file))
 }
+
+/// Writes the completion script for `shell` to stdout. Scripts are registered for the `gitv`
+/// binary rather than the package name.
+pub fn generate_completions(shell: clap_complete::Shell) {
+    let mut cmd = Cli::command();
+    clap_complete::generate(shell, &mut cmd, "gitv", &mut std::io::stdout());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse_github_remote, remote_url};
+
+    #[test]
+    fn parses_github_remotes() {
+        let expected = Some(("JayanAXHF".to_string(), "gitv".to_string()));
+        for url in [
+            "git@github.com:JayanAXHF/gitv.git",
+            "ssh://git@github.com/JayanAXHF/gitv",
+            "https://github.com/JayanAXHF/gitv.git",
+            "https://token@github.com/JayanAXHF/gitv/",
+        ] {
+            assert_eq!(parse_github_remote(url), expected, "{url}");
+        }
+        assert_eq!(
+            parse_github_remote("git@gitlab.com:JayanAXHF/gitv.git"),
+            None
+        );
+        assert_eq!(parse_github_remote("https://github.com/JayanAXHF"), None);
+    }
+
+    #[test]
+    fn reads_the_named_remote() {
+        let config = "[core]\n\tbare = false\n[remote \"upstream\"]\n\turl = https://github.com/a/b\n\
+                      [remote \"origin\"]\n\turl = git@github.com:c/d.git\n";
+        assert_eq!(
+            remote_url(config, "origin").as_deref(),
+            Some("git@github.com:c/d.git")
+        );
+        assert_eq!(remote_url(config, "fork"), None);
+    }
+}
@@ -0,0 +1,163 @@
+use std::{io::Read, path::PathBuf};
+
+use anyhow::{Context, anyhow};
+
+use crate::app::App;
+use crate::app::cli::{Args, AuthArgs, Command};
+use crate::auth::{AuthProvider, device::device_login, keyring::KeyringAuth};
+use crate::errors::AppError;
+
+/// Runs a scripted command without starting the TUI.
+pub async fn run(args: Args, command: Command) -> Result<(), AppError> {
+    let (owner, repo) = args.repository()?;
+    App::init_logging(&args)?;
+    let client = App::connect(&args, false)?;
+    match command {
+        Command::Comment(comment) => {
+            let body = read_body(
+                comment.body,
+                comment.body_file.as_ref(),
+                std::io::stdin(),
+                "comment",
+            )?;
+            let body = non_empty(body, "comment")?;
+            let created = client
+                .inner()
+                .issues(&owner, &repo)
+                .create_comment(comment.number, body)
+                .await
+                .map_err(|err| match err {
+                    octocrab::Error::GitHub { source, .. } => anyhow!(
+                        "GitHub rejected the comment ({}): {}",
+                        source.status_code,
+                        source.message
+                    ),
+                    err => anyhow!("failed to post the comment: {err}"),
+                })?;
+            println!("{}", created.html_url);
+        }
+        Command::Create(create) => {
+            if create.title.trim().is_empty() {
+                return Err(anyhow!("issue title is empty").into());
+            }
+            let body = read_body(
+                create.body,
+                create.body_file.as_ref(),
+                std::io::stdin(),
+                "issue",
+            )?;
+            let handler = client.inner().issues(&owner, &repo);
+            let mut builder = handler.create(create.title.trim());
+            if !body.trim().is_empty() {
+                builder = builder.body(body);
+            }
+            let issue = builder.send().await.map_err(|err| match err {
+                octocrab::Error::GitHub { source, .. } => anyhow!(
+                    "GitHub rejected the issue ({}): {}",
+                    source.status_code,
+                    source.message
+                ),
+                err => anyhow!("failed to create the issue: {err}"),
+            })?;
+            println!("#{} {}", issue.number, issue.html_url);
+        }
+        Command::Auth(auth) => login(&auth).await?,
+    }
+    Ok(())
+}
+
+/// Signs in with the device flow and saves the token to the keyring.
+pub async fn login(auth: &AuthArgs) -> Result<(), AppError> {
+    let token = device_login(&auth.client_id, &auth.scopes, |code, url| {
+        println!("Open {url} and enter the code {code}");
+        println!("Waiting for authorization...");
+    })
+    .await?;
+    KeyringAuth::new("gitv")?.set_token(&token)?;
+    println!("Signed in; the token is stored in your credential store.");
+    Ok(())
+}
+
+/// Reads a body from `--body`, `--body-file` or, failing both, `stdin`. `what` names the body in
+/// error messages.
+fn read_body(
+    body: Option<String>,
+    body_file: Option<&PathBuf>,
+    mut stdin: impl Read,
+    what: &str,
+) -> Result<String, AppError> {
+    let body = match (body, body_file) {
+        (Some(body), _) => body,
+        (None, Some(path)) if path.as_os_str() != "-" => std::fs::read_to_string(path)
+            .with_context(|| format!("failed to read `{}`", path.display()))?,
+        (None, _) => {
+            let mut body = String::new();
+            stdin
+                .read_to_string(&mut body)
+                .with_context(|| format!("failed to read the {what} body from stdin"))?;
+            body
+        }
+    };
+    Ok(body)
+}
+
+/// Rejects a body that is empty or only whitespace.
+fn non_empty(body: String, what: &str) -> Result<String, AppError> {
+    if body.trim().is_empty() {
+        return Err(anyhow!("{what} body is empty").into());
+    }
+    Ok(body)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Write;
+
+    use super::{non_empty, read_body};
+
+    #[test]
+    fn prefers_the_body_argument() {
+        let file = tempfile::NamedTempFile::new().unwrap();
+        let body = read_body(
+            Some("from args".to_string()),
+            Some(&file.path().to_path_buf()),
+            "from stdin".as_bytes(),
+            "comment",
+        )
+        .unwrap();
+
+        assert_eq!(body, "from args");
+    }
+
+    #[test]
+    fn reads_the_body_file() {
+        let mut file = tempfile::NamedTempFile::new().unwrap();
+        write!(file, "from file").unwrap();
+        let body = read_body(
+            None,
+            Some(&file.path().to_path_buf()),
+            "from stdin".as_bytes(),
+            "comment",
+        )
+        .unwrap();
+
+        assert_eq!(body, "from file");
+    }
+
+    #[test]
+    fn reads_stdin_without_a_body_or_for_a_dash() {
+        let body = read_body(None, None, "piped".as_bytes(), "comment").unwrap();
+        assert_eq!(body, "piped");
+
+        let dash = "-".into();
+        let body = read_body(None, Some(&dash), "dashed".as_bytes(), "comment").unwrap();
+        assert_eq!(body, "dashed");
+    }
+
+    #[test]
+    fn rejects_blank_bodies() {
+        let err = non_empty(" \n".to_string(), "comment").unwrap_err();
+        assert_eq!(err.to_string(), "comment body is empty");
+        assert_eq!(non_empty("hi".to_string(), "comment").unwrap(), "hi");
+    }
+}
@@ -1,56 +1,192 @@
 use anyhow::anyhow;
 use inquire::Password;
 
-use crate::app::cli::Cli;
+use crate::app::cli::{Args, Cli, LogLevel};
 use crate::auth::AuthProvider;
 use crate::errors::AppError;
-use crate::github::GithubClient;
+use crate::github::mock::MOCK_WRITE_MESSAGE;
+use crate::github::retry::{DEFAULT_REQUEST_TIMEOUT, set_request_timeout};
+use crate::github::{ConnectionOptions, GithubClient, missing_scopes_warning};
 use crate::logging::LoggingConfig;
+use crate::ui::components::issue_conversation::CODE_TAB_WIDTH;
+use crate::ui::components::label_list::LabelSort;
+use crate::ui::issue_columns::{DEFAULT_ISSUE_COLUMNS, IssueColumn, parse_issue_columns};
 use crate::{logging, ui};
-use std::sync::OnceLock;
+use std::sync::{OnceLock, atomic::Ordering};
+use std::time::Duration;
+use tracing::{info, warn};
 
 pub struct App {
     pub owner: String,
     pub repo: String,
+    pub issue_columns: Vec<IssueColumn>,
+    pub mock: bool,
+    pub skip_label_confirm: bool,
+    pub label_sort: LabelSort,
+    pub group_labels: bool,
+    pub self_logins: Vec<String>,
+    pub poll_interval: Option<Duration>,
+    pub label_separator: char,
+    pub label_cache_ttl: Duration,
+    pub comment_warn_chars: usize,
+    pub per_page: Option<u8>,
+    pub default_query: Option<String>,
+    /// Login the token belongs to, or empty when running without one.
+    pub current_user: String,
 }
 
 pub static GITHUB_CLIENT: OnceLock<GithubClient> = OnceLock::new();
 
+/// The client set up by [`App::new`], or [`AppError::ClientUninitialized`] before that.
+pub fn client() -> Result<&'static GithubClient, AppError> {
+    GITHUB_CLIENT.get().ok_or(AppError::ClientUninitialized)
+}
+
 impl App {
     pub async fn new(cli: Cli) -> Result<Self, AppError> {
-        logging::init(LoggingConfig::new(cli.args.log_level))?;
-        let auth = if cli.args.env {
-            Box::new(crate::auth::env::EnvAuth) as Box<dyn AuthProvider>
-        } else {
-            Box::new(crate::auth::keyring::KeyringAuth::new("gitv")?) as Box<dyn AuthProvider>
-        };
-        let token = match auth.get_token().ok() {
-            Some(token) => token,
-            None => Self::handle_no_token(&auth)?,
-        };
-        let github = GithubClient::new(Some(token))?;
+        let (owner, repo) = cli.args.repository()?;
+        Self::init_logging(&cli.args)?;
+        let github = Self::connect(&cli.args, true)?;
+        let current_user = github.verify().await?.unwrap_or_default();
         let _ = GITHUB_CLIENT.set(github);
+        let issue_columns = Self::issue_columns(cli.args.columns.as_deref());
+        CODE_TAB_WIDTH.store(cli.args.tab_width.into(), Ordering::Relaxed);
+        set_request_timeout(Self::request_timeout(&cli.args));
         Ok(Self {
-            owner: cli.args.owner.unwrap_or_default(),
-            repo: cli.args.repo.unwrap_or_default(),
+            owner,
+            repo,
+            mock: cli.args.mock.is_some(),
+            issue_columns,
+            skip_label_confirm: cli.args.no_label_confirm,
+            label_sort: cli.args.label_sort,
+            group_labels: cli.args.group_labels,
+            self_logins: cli.args.self_logins.clone(),
+            poll_interval: (cli.args.poll_interval > 0)
+                .then(|| Duration::from_secs(cli.args.poll_interval)),
+            label_separator: cli.args.label_separator,
+            label_cache_ttl: Duration::from_secs(cli.args.label_cache_ttl),
+            comment_warn_chars: cli.args.comment_warn_chars,
+            // GitHub caps search pages at 100 results.
+            per_page: cli.args.per_page.map(|n| n.clamp(1, 100) as u8),
+            default_query: cli.args.default_query.clone(),
+            current_user,
         })
     }
 
     pub async fn run(&mut self) -> Result<(), AppError> {
         use crate::ui::AppState;
-        let current_user = GITHUB_CLIENT
-            .get()
-            .ok_or_else(|| AppError::Other(anyhow!("github client is not initialized")))?
-            .inner()
-            .current()
-            .user()
-            .await?
-            .login;
+        let banner = if self.mock {
+            Some(format!("{MOCK_WRITE_MESSAGE}."))
+        } else {
+            Self::token_scope_warning().await
+        };
 
-        let ap = AppState::new(self.repo.clone(), self.owner.clone(), current_user);
+        let ap = AppState::new(
+            self.repo.clone(),
+            self.owner.clone(),
+            self.current_user.clone(),
+        )
+        .with_banner(banner)
+        .with_issue_columns(self.issue_columns.clone())
+        .with_skip_label_confirm(self.skip_label_confirm)
+        .with_label_display(self.label_sort, self.group_labels)
+        .with_self_logins(self.self_logins.clone())
+        .with_poll_interval(self.poll_interval)
+        .with_label_separator(self.label_separator)
+        .with_label_cache_ttl(self.label_cache_ttl)
+        .with_comment_warn_chars(self.comment_warn_chars)
+        .with_search_defaults(self.per_page, self.default_query.clone());
         ui::run(ap).await
     }
 
+    pub(crate) fn init_logging(args: &Args) -> Result<(), AppError> {
+        logging::init(
+            LoggingConfig::new(args.log_level.clone().unwrap_or(LogLevel::Info))
+                .with_rotation(args.log_rotation)
+                .with_max_log_files(args.log_retention),
+        )
+    }
+
+    /// Builds the GitHub client from the auth and network flags. When no token is stored the
+    /// user is asked for one, unless `prompt_for_token` is off (e.g. for scripted commands).
+    pub(crate) fn connect(args: &Args, prompt_for_token: bool) -> Result<GithubClient, AppError> {
+        let owner = args.owner.as_deref().unwrap_or_default();
+        let repo = args.repo.as_deref().unwrap_or_default();
+        if let Some(dir) = &args.mock {
+            return GithubClient::mock(dir, owner, repo);
+        }
+        let auth = if args.env {
+            Box::new(crate::auth::env::EnvAuth) as Box<dyn AuthProvider>
+        } else {
+            Box::new(crate::auth::keyring::KeyringAuth::new("gitv")?) as Box<dyn AuthProvider>
+        };
+        // A token from the gh CLI is only used when nothing else is configured.
+        let token = auth.get_token().ok().or_else(|| {
+            if args.env {
+                return None;
+            }
+            let token = crate::auth::gh::GhCliAuth.get_token().ok()?;
+            info!("using the token stored by the gh CLI");
+            Some(token)
+        });
+        let token = match token {
+            Some(token) => token,
+            None if prompt_for_token => Self::handle_no_token(&auth)?,
+            None => {
+                return Err(AppError::Other(anyhow!(
+                    "no GitHub token found; run with --set-token or --env first"
+                )));
+            }
+        };
+        let mut connection = ConnectionOptions {
+            ca_cert: args.ca_cert.clone(),
+            proxy: args.proxy.clone(),
+            base_url: args.base_url.as_deref().map(str::parse).transpose()?,
+            timeout: Some(Self::request_timeout(args)),
+        };
+        connection.apply_proxy_env()?;
+        GithubClient::new(Some(token), &connection)
+    }
+
+    /// How long a GitHub request may take before it is abandoned; zero disables the limit.
+    fn request_timeout(args: &Args) -> Duration {
+        args.request_timeout
+            .map_or(DEFAULT_REQUEST_TIMEOUT, Duration::from_secs)
+    }
+
+    fn issue_columns(spec: Option<&str>) -> Vec<IssueColumn> {
+        let Some(spec) = spec else {
+            return DEFAULT_ISSUE_COLUMNS.to_vec();
+        };
+        let (columns, invalid) = parse_issue_columns(spec);
+        if !invalid.is_empty() {
+            warn!(
+                ?invalid,
+                ?columns,
+                "ignoring unknown or repeated issue list columns"
+            );
+        }
+        columns
+    }
+
+    async fn token_scope_warning() -> Option<String> {
+        let client = client().ok()?;
+        match client.token_scopes().await {
+            Ok(Some(scopes)) => {
+                let warning = missing_scopes_warning(&scopes);
+                if let Some(warning) = &warning {
+                    warn!(?scopes, "{warning}");
+                }
+                warning
+            }
+            Ok(None) => None,
+            Err(err) => {
+                warn!(error = %err, "failed to inspect token scopes");
+                None
+            }
+        }
+    }
+
     pub fn handle_no_token(auth: &impl AuthProvider) -> Result<String, AppError> {
         let prompt = Password::new("No token found. Please enter your github token")
             .with_display_toggle_enabled()
@@ -63,3 +199,4 @@ impl App {
 }
 
 pub mod cli;
+pub mod headless;
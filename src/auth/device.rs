@@ -0,0 +1,54 @@
+use std::time::{Duration, Instant};
+
+use anyhow::anyhow;
+use either::Either;
+use http::header::ACCEPT;
+use octocrab::auth::Continue;
+use secrecy::{ExposeSecret, SecretString};
+
+use crate::errors::AppError;
+
+/// Scopes requested when none are given: enough to read and change issues, comments and labels.
+pub const DEFAULT_SCOPES: &str = "repo";
+
+/// Signs in with GitHub's OAuth device flow and returns the access token.
+///
+/// `prompt` is called once with the user code and the URL where the user enters it; polling
+/// then continues until the user authorizes the app or the code expires.
+pub async fn device_login(
+    client_id: &str,
+    scopes: &[String],
+    prompt: impl FnOnce(&str, &str),
+) -> Result<String, AppError> {
+    // The device flow lives on the web host rather than the API, and only answers in JSON
+    // when asked to.
+    let crab = octocrab::Octocrab::builder()
+        .base_uri("https://github.com")?
+        .add_header(ACCEPT, "application/json".to_string())
+        .build()?;
+    let client_id = SecretString::from(client_id.to_string());
+    let codes = crab
+        .authenticate_as_device(&client_id, scopes)
+        .await
+        .map_err(|err| anyhow!("could not start the device login: {err}"))?;
+    prompt(&codes.user_code, &codes.verification_uri);
+
+    let deadline = Instant::now() + Duration::from_secs(codes.expires_in);
+    let mut interval = Duration::from_secs(codes.interval.max(1));
+    loop {
+        tokio::time::sleep(interval).await;
+        if Instant::now() >= deadline {
+            return Err(
+                anyhow!("the code expired before it was entered; run `gitv auth` again").into(),
+            );
+        }
+        match codes.poll_once(&crab, &client_id).await {
+            Ok(Either::Left(oauth)) => return Ok(oauth.access_token.expose_secret().to_string()),
+            Ok(Either::Right(Continue::AuthorizationPending)) => {}
+            // Every `slow_down` adds five seconds to the interval GitHub expects.
+            Ok(Either::Right(Continue::SlowDown)) => interval += Duration::from_secs(5),
+            // Denied and expired codes end the flow with an error GitHub doesn't let us retry.
+            Err(err) => return Err(anyhow!("device login was not completed: {err}").into()),
+        }
+    }
+}
@@ -0,0 +1,101 @@
+use std::{env, fs, path::PathBuf};
+
+use anyhow::anyhow;
+use directories::BaseDirs;
+
+use crate::{auth::AuthProvider, errors::AppError};
+
+/// Reads the token the official `gh` CLI stored for github.com in its `hosts.yml`.
+///
+/// Recent `gh` versions keep the token in the system keyring instead, in which case the file
+/// has no `oauth_token` and there is nothing to read.
+pub struct GhCliAuth;
+
+impl GhCliAuth {
+    const HOST: &'static str = "github.com";
+
+    /// `hosts.yml` in `gh`'s config directory, which honours the same overrides as `gh` itself.
+    fn hosts_file() -> Option<PathBuf> {
+        let dir = if let Some(dir) = env::var_os("GH_CONFIG_DIR") {
+            PathBuf::from(dir)
+        } else if let Some(dir) = env::var_os("XDG_CONFIG_HOME") {
+            PathBuf::from(dir).join("gh")
+        } else if cfg!(windows) {
+            PathBuf::from(env::var_os("AppData")?).join("GitHub CLI")
+        } else {
+            BaseDirs::new()?.home_dir().join(".config").join("gh")
+        };
+        Some(dir.join("hosts.yml"))
+    }
+}
+
+impl AuthProvider for GhCliAuth {
+    fn get_token(&self) -> Result<String, AppError> {
+        let path = Self::hosts_file().ok_or_else(|| anyhow!("no home directory to find gh in"))?;
+        let hosts = fs::read_to_string(&path)
+            .map_err(|err| anyhow!("failed to read `{}`: {err}", path.display()))?;
+        host_token(&hosts, Self::HOST)
+            .ok_or_else(|| anyhow!("`{}` has no token for {}", path.display(), Self::HOST).into())
+    }
+
+    fn set_token(&self, _token: &str) -> Result<(), AppError> {
+        Err(anyhow!("the gh CLI's config is read-only; use `gh auth login` to change it").into())
+    }
+}
+
+/// Finds `oauth_token` directly under the top-level `host:` entry of a `hosts.yml`, ignoring
+/// tokens of other hosts and of nested per-user entries.
+fn host_token(hosts: &str, host: &str) -> Option<String> {
+    let mut lines = hosts
+        .lines()
+        .filter(|line| !line.trim().is_empty() && !line.trim_start().starts_with('#'))
+        .skip_while(|line| line.trim_end().trim_matches(['"', '\'']) != format!("{host}:"));
+    lines.next()?;
+    let mut entry_indent = None;
+    for line in lines {
+        let indent = line.len() - line.trim_start().len();
+        if indent == 0 {
+            break;
+        }
+        if *entry_indent.get_or_insert(indent) != indent {
+            continue;
+        }
+        if let Some(value) = line.trim().strip_prefix("oauth_token:") {
+            let token = value.trim().trim_matches(['"', '\'']);
+            return (!token.is_empty()).then(|| token.to_string());
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::host_token;
+
+    #[test]
+    fn reads_only_the_github_com_entry() {
+        let hosts = "\
+github.example.com:
+    oauth_token: ghe_token
+github.com:
+    users:
+        octocat:
+            oauth_token: nested_token
+    git_protocol: ssh
+    oauth_token: gho_token
+    user: octocat
+";
+        assert_eq!(
+            host_token(hosts, "github.com").as_deref(),
+            Some("gho_token")
+        );
+        assert_eq!(
+            host_token("github.com:\n    user: octocat\n", "github.com"),
+            None
+        );
+        assert_eq!(
+            host_token("gitlab.com:\n    oauth_token: x\n", "github.com"),
+            None
+        );
+    }
+}
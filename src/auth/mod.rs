@@ -15,6 +15,8 @@ impl<T: AuthProvider + ?Sized> AuthProvider for Box<T> {
     }
 }
 
+pub mod device;
 pub mod env;
+pub mod gh;
 pub mod keyring;
 pub mod token;
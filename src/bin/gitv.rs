@@ -2,21 +2,28 @@ use clap::Parser;
 use gitv_tui::{
     app::{
         App,
-        cli::{Cli, generate_man_pages},
+        cli::{Cli, Command, generate_completions, generate_man_pages},
+        headless,
     },
     auth::AuthProvider,
+    config::Config,
     errors::AppError,
     logging,
+    ui::keymap::set_keymap,
 };
 
 #[tokio::main]
 async fn main() -> anyhow::Result<(), AppError> {
-    let cli = Cli::parse();
+    let mut cli = Cli::parse();
     if cli.args.generate_man {
         let output_dir = generate_man_pages()?;
         println!("Man page generated at: {}", output_dir.display());
         return Ok(());
     }
+    if let Some(shell) = cli.args.generate_completions {
+        generate_completions(shell);
+        return Ok(());
+    }
     if cli.args.print_log_dir {
         println!("Log directory: {}", logging::get_data_dir().display());
         return Ok(());
@@ -27,6 +34,27 @@ async fn main() -> anyhow::Result<(), AppError> {
         auth.set_token(token)?;
         return Ok(());
     }
+    if let Some(Command::Auth(auth)) = &cli.command {
+        // Signing in doesn't need a repository, so it runs before one is resolved.
+        if let Err(err) = headless::login(auth).await {
+            eprintln!("error: {err}");
+            std::process::exit(1);
+        }
+        return Ok(());
+    }
+    let config = Config::load()?;
+    set_keymap(config.keymap.clone());
+    config.apply(&mut cli.args);
+    cli.args.detect_repository()?;
+
+    if let Some(command) = cli.command.clone() {
+        // Scripts only need the message, not the error's debug representation.
+        if let Err(err) = headless::run(cli.args, command).await {
+            eprintln!("error: {err}");
+            std::process::exit(1);
+        }
+        return Ok(());
+    }
 
     let mut app = App::new(cli).await?;
     app.run().await
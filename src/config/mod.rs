@@ -0,0 +1,200 @@
+use std::{
+    collections::HashMap,
+    env, fs,
+    io::ErrorKind,
+    path::{Path, PathBuf},
+    sync::LazyLock,
+};
+
+use anyhow::anyhow;
+use serde::Deserialize;
+
+use crate::{
+    app::cli::{Args, LogLevel},
+    errors::AppError,
+    github::ProxyConfig,
+    logging::{PROJECT_NAME, project_directory},
+    ui::keymap::Keymap,
+};
+
+pub static CONFIG_FOLDER: LazyLock<Option<PathBuf>> = LazyLock::new(|| {
+    env::var(format!("{}_CONFIG", &*PROJECT_NAME))
+        .ok()
+        .map(PathBuf::from)
+});
+
+const CONFIG_FILE: &str = "config.toml";
+
+/// Written on first run so the available options are discoverable.
+const DEFAULT_CONFIG: &str = r#"# gitv configuration. Arguments given on the command line take precedence over these values.
+
+# Repository opened when OWNER and REPO are not given on the command line.
+# default_owner = "rust-lang"
+# default_repo = "rust"
+
+# Log verbosity: trace, debug, info, warn, error or none.
+# log_level = "info"
+
+# API root to talk to instead of github.com, e.g. for GitHub Enterprise Server.
+# base_url = "https://github.example.com/api/v3"
+
+# HTTP proxy for GitHub traffic. HTTPS_PROXY is used when this isn't set, and hosts listed in
+# NO_PROXY are reached directly either way.
+# proxy = "http://proxy.corp:3128"
+
+# Issues fetched per search request, up to GitHub's maximum of 100.
+# per_page = 30
+
+# Search query the issue list starts with.
+# default_query = "is:open sort:updated"
+
+# Seconds to wait for GitHub to answer a request before giving up on it; 0 waits forever.
+# request_timeout = 30
+
+# Move through the issue, label and comment lists with j/k and jump to the first/last entry with
+# g/G. Set to false to leave these letters unbound.
+# vim_keys = true
+
+# Keys for conversation and label actions. Separate several keys with commas; modifiers are
+# written like `ctrl+enter` or `alt+e`. Unlisted actions keep the keys shown here.
+[keybindings]
+# send_comment = "ctrl+enter, alt+enter"
+# edit_comment = "e"
+# edit_issue = "E"
+# external_edit_comment = "alt+e"
+# delete_comment = "d"
+# quote_comment = ">"
+# add_reaction = "r"
+# remove_reaction = "R"
+# copy_message = "y"
+# open_in_browser = "o"
+# toggle_issue_state = "x"
+# edit_assignees = "a"
+# set_milestone = "m"
+# reload_comments = "ctrl+r"
+# cancel_loading = "ctrl+g"
+# toggle_details = "z"
+# toggle_preview = "ctrl+p"
+# add_label = "a"
+# remove_label = "d"
+# undo_remove_label = "u"
+# recolor_label = "c"
+# find_label = "f"
+# reload_labels = "R"
+# cycle_label_sort = "s"
+"#;
+
+/// Options read from `config.toml` in the config directory.
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Config {
+    pub default_owner: Option<String>,
+    pub default_repo: Option<String>,
+    pub log_level: Option<LogLevel>,
+    pub base_url: Option<String>,
+    pub proxy: Option<ProxyConfig>,
+    pub per_page: Option<u32>,
+    pub default_query: Option<String>,
+    pub request_timeout: Option<u64>,
+    pub vim_keys: Option<bool>,
+    pub keybindings: HashMap<String, String>,
+    /// `keybindings` resolved against the defaults.
+    #[serde(skip)]
+    pub keymap: Keymap,
+}
+
+impl Config {
+    /// Reads the config file, creating it with commented-out defaults when it doesn't exist yet.
+    pub fn load() -> Result<Self, AppError> {
+        Self::load_from(&config_file())
+    }
+
+    pub fn load_from(path: &Path) -> Result<Self, AppError> {
+        match fs::read_to_string(path) {
+            Ok(contents) => {
+                let mut config: Self = toml::from_str(&contents)
+                    .map_err(|err| anyhow!("invalid config file `{}`: {err}", path.display()))?;
+                config.keymap = Keymap::from_bindings(&config.keybindings)
+                    .map_err(|err| anyhow!("invalid [keybindings] in `{}`: {err}", path.display()))?
+                    .with_vim_keys(config.vim_keys.unwrap_or(true));
+                Ok(config)
+            }
+            Err(err) if err.kind() == ErrorKind::NotFound => {
+                // A read-only config directory shouldn't stop the app from starting.
+                if let Some(parent) = path.parent() {
+                    let _ = fs::create_dir_all(parent);
+                }
+                let _ = fs::write(path, DEFAULT_CONFIG);
+                Ok(Self::default())
+            }
+            Err(err) => {
+                Err(anyhow!("failed to read config file `{}`: {err}", path.display()).into())
+            }
+        }
+    }
+
+    /// Fills in the arguments that weren't given on the command line.
+    pub fn apply(self, args: &mut Args) {
+        args.owner = args.owner.take().or(self.default_owner);
+        args.repo = args.repo.take().or(self.default_repo);
+        args.log_level = args.log_level.take().or(self.log_level);
+        args.base_url = args.base_url.take().or(self.base_url);
+        args.proxy = args.proxy.take().or(self.proxy);
+        args.per_page = args.per_page.or(self.per_page);
+        args.default_query = args.default_query.take().or(self.default_query);
+        args.request_timeout = args.request_timeout.or(self.request_timeout);
+    }
+}
+
+pub fn config_file() -> PathBuf {
+    let dir = if let Some(dir) = CONFIG_FOLDER.clone() {
+        dir
+    } else if let Some(proj_dirs) = project_directory() {
+        proj_dirs.config_dir().to_path_buf()
+    } else {
+        PathBuf::from(".").join(".config")
+    };
+    dir.join(CONFIG_FILE)
+}
+
+#[cfg(test)]
+mod tests {
+    use clap::Parser;
+
+    use super::{Config, DEFAULT_CONFIG};
+    use crate::app::cli::{Cli, LogLevel};
+
+    #[test]
+    fn default_config_parses_to_defaults() {
+        let config: Config = toml::from_str(DEFAULT_CONFIG).unwrap();
+        assert!(config.default_owner.is_none() && config.log_level.is_none());
+    }
+
+    #[test]
+    fn command_line_overrides_config() {
+        let config: Config = toml::from_str(
+            "default_owner = \"rust-lang\"\ndefault_repo = \"rust\"\nlog_level = \"debug\"",
+        )
+        .unwrap();
+        let mut cli = Cli::parse_from(["gitv", "tokio-rs", "--log-level", "warn"]);
+        config.apply(&mut cli.args);
+
+        assert_eq!(cli.args.owner.as_deref(), Some("tokio-rs"));
+        assert_eq!(cli.args.repo.as_deref(), Some("rust"));
+        assert!(matches!(cli.args.log_level, Some(LogLevel::Warn)));
+    }
+
+    #[test]
+    fn search_defaults_fall_back_to_config() {
+        let config: Config =
+            toml::from_str("per_page = 50\ndefault_query = \"is:open sort:updated\"").unwrap();
+        let mut cli = Cli::parse_from(["gitv", "--per-page", "100"]);
+        config.apply(&mut cli.args);
+
+        assert_eq!(cli.args.per_page, Some(100));
+        assert_eq!(
+            cli.args.default_query.as_deref(),
+            Some("is:open sort:updated")
+        );
+    }
+}
@@ -1,6 +1,10 @@
 use thiserror::Error;
 use tokio::sync::mpsc::error::SendError;
 
+use std::time::Duration;
+
+use crate::github::connection_failure;
+
 #[derive(Debug, Error)]
 pub enum AppError {
     #[error("not implemented")]
@@ -21,10 +25,100 @@ pub enum AppError {
     InitLoggingError(#[from] tracing_subscriber::util::TryInitError),
     #[error("error setting global {0}")]
     ErrorSettingGlobal(&'static str),
+    #[error("GitHub client not initialized")]
+    ClientUninitialized,
+    #[error("request timed out after {}s", .0.as_secs())]
+    Timeout(Duration),
     #[error(transparent)]
     Other(#[from] anyhow::Error),
 }
 
+impl AppError {
+    /// A one-line message for the UI. GitHub errors go through [`describe_request_error`].
+    pub fn describe(&self, rate_limit_resets_in: Option<Duration>) -> String {
+        match self {
+            AppError::Octocrab(err) => describe_request_error(err, rate_limit_resets_in),
+            other => other.to_string().replace('\n', " "),
+        }
+    }
+}
+
+/// Broad cause of a failed GitHub request, so messages can say what to do about it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RequestErrorKind {
+    NotFound,
+    RateLimited,
+    /// The token is missing, invalid or lacks the permission for the request.
+    Auth,
+    /// No response arrived, e.g. DNS, TLS or connection failures.
+    Network,
+    Other,
+}
+
+impl RequestErrorKind {
+    pub fn of(err: &octocrab::Error) -> Self {
+        match err {
+            octocrab::Error::GitHub { source, .. } => {
+                Self::from_response(source.status_code.as_u16(), &source.message)
+            }
+            octocrab::Error::Hyper { .. } | octocrab::Error::Service { .. } => Self::Network,
+            _ => Self::Other,
+        }
+    }
+
+    /// GitHub answers an exhausted quota with 403 or 429 and says so in the message.
+    fn from_response(status: u16, message: &str) -> Self {
+        match status {
+            404 | 410 => Self::NotFound,
+            429 => Self::RateLimited,
+            403 if message.to_lowercase().contains("rate limit") => Self::RateLimited,
+            401 | 403 => Self::Auth,
+            _ => Self::Other,
+        }
+    }
+}
+
+/// Describes a failed GitHub request by its [`RequestErrorKind`] on a single line. Rate limit
+/// errors mention when the quota resets if the caller knows, via `rate_limit_resets_in`.
+pub fn describe_request_error(
+    err: &octocrab::Error,
+    rate_limit_resets_in: Option<Duration>,
+) -> String {
+    let kind = RequestErrorKind::of(err);
+    let (status, message) = match err {
+        octocrab::Error::GitHub { source, .. } => {
+            (Some(source.status_code.as_u16()), source.message.as_str())
+        }
+        _ => (None, ""),
+    };
+    match kind {
+        RequestErrorKind::NotFound => {
+            "Not found on GitHub; it may have been deleted or be hidden from this token."
+                .to_string()
+        }
+        RequestErrorKind::RateLimited => match rate_limit_resets_in {
+            Some(resets_in) => {
+                let minutes = resets_in.as_secs().div_ceil(60);
+                format!("GitHub rate limit exceeded; it resets in {minutes}m.")
+            }
+            None => "GitHub rate limit exceeded; try again later.".to_string(),
+        },
+        RequestErrorKind::Auth if status == Some(401) => {
+            "GitHub rejected the token; it may be invalid or expired.".to_string()
+        }
+        RequestErrorKind::Auth => format!("Permission denied by GitHub: {message}"),
+        RequestErrorKind::Network => format!(
+            "Network error: {}",
+            connection_failure(err).unwrap_or_else(|| err.to_string())
+        ),
+        RequestErrorKind::Other => match status {
+            Some(status) => format!("GitHub returned {status}: {message}"),
+            None => err.to_string(),
+        },
+    }
+    .replace('\n', " ")
+}
+
 impl<T> From<SendError<T>> for AppError {
     fn from(_: SendError<T>) -> Self {
         AppError::TokioMpsc
@@ -32,3 +126,28 @@ impl<T> From<SendError<T>> for AppError {
 }
 
 pub type Result<T, E = AppError> = std::result::Result<T, E>;
+
+#[cfg(test)]
+mod tests {
+    use super::RequestErrorKind;
+
+    #[test]
+    fn classifies_responses_by_status_and_message() {
+        assert_eq!(
+            RequestErrorKind::from_response(404, "Not Found"),
+            RequestErrorKind::NotFound
+        );
+        assert_eq!(
+            RequestErrorKind::from_response(403, "API rate limit exceeded for user ID 1."),
+            RequestErrorKind::RateLimited
+        );
+        assert_eq!(
+            RequestErrorKind::from_response(403, "Resource not accessible by integration"),
+            RequestErrorKind::Auth
+        );
+        assert_eq!(
+            RequestErrorKind::from_response(422, "Validation Failed"),
+            RequestErrorKind::Other
+        );
+    }
+}
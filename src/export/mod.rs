@@ -0,0 +1,97 @@
+use std::{
+    path::PathBuf,
+    sync::OnceLock,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use octocrab::models::{IssueState, issues::Issue};
+
+use crate::logging::get_data_dir;
+
+pub static EXPORT_DIR: OnceLock<PathBuf> = OnceLock::new();
+
+const CSV_HEADER: [&str; 7] = [
+    "number", "title", "state", "author", "labels", "created", "updated",
+];
+
+pub fn get_export_dir() -> &'static PathBuf {
+    EXPORT_DIR.get_or_init(|| get_data_dir().join("exports"))
+}
+
+/// Renders one row per issue, preceded by a header row. Labels are joined with `;`.
+pub fn issues_to_csv(issues: &[Issue]) -> String {
+    let mut out = csv_row(CSV_HEADER.iter().map(|field| field.to_string()));
+    for issue in issues {
+        let state = match issue.state {
+            IssueState::Open => "open",
+            _ => "closed",
+        };
+        let labels = issue
+            .labels
+            .iter()
+            .map(|label| label.name.as_str())
+            .collect::<Vec<_>>()
+            .join(";");
+        out.push_str(&csv_row([
+            issue.number.to_string(),
+            issue.title.clone(),
+            state.to_string(),
+            issue.user.login.clone(),
+            labels,
+            issue.created_at.to_rfc3339(),
+            issue.updated_at.to_rfc3339(),
+        ]));
+    }
+    out
+}
+
+/// Writes `issues` to a new timestamped file in the export directory and returns its path.
+pub fn write_issues_csv(owner: &str, repo: &str, issues: &[Issue]) -> std::io::Result<PathBuf> {
+    let dir = get_export_dir();
+    std::fs::create_dir_all(dir)?;
+    let stamp = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |elapsed| elapsed.as_secs());
+    let path = dir.join(format!("{owner}-{repo}-issues-{stamp}.csv"));
+    std::fs::write(&path, issues_to_csv(issues))?;
+    Ok(path)
+}
+
+fn csv_row(fields: impl IntoIterator<Item = String>) -> String {
+    let mut row = fields
+        .into_iter()
+        .map(|field| csv_field(&field))
+        .collect::<Vec<_>>()
+        .join(",");
+    row.push_str("\r\n");
+    row
+}
+
+/// Quotes a field when it contains a delimiter, quote or line break, doubling embedded quotes.
+fn csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{csv_field, csv_row};
+
+    #[test]
+    fn quotes_fields_that_need_it() {
+        assert_eq!(csv_field("plain"), "plain");
+        assert_eq!(csv_field("a, b"), "\"a, b\"");
+        assert_eq!(csv_field("say \"hi\""), "\"say \"\"hi\"\"\"");
+        assert_eq!(csv_field("two\nlines"), "\"two\nlines\"");
+    }
+
+    #[test]
+    fn joins_row_with_crlf() {
+        let row = csv_row(["1".to_string(), "x,y".to_string()]);
+
+        assert_eq!(row, "1,\"x,y\"\r\n");
+    }
+}
@@ -0,0 +1,388 @@
+use std::{
+    collections::HashMap,
+    path::{Path, PathBuf},
+};
+
+use bytes::Bytes;
+use http::{
+    HeaderMap, HeaderValue, StatusCode,
+    header::{ETAG, IF_NONE_MATCH, LINK},
+};
+use http_body_util::{BodyExt, Full};
+use octocrab::{FromResponse, Page};
+use serde::{Deserialize, Serialize, de::DeserializeOwned};
+use tracing::{error, trace};
+
+use crate::{github::GithubClient, storage::get_storage_dir};
+
+/// Combined size of cached response bodies before the least recently used ones are evicted.
+pub const MAX_ETAG_CACHE_BYTES: usize = 4 * 1024 * 1024;
+
+/// A response body GitHub can revalidate with `If-None-Match`. The `Link` header is kept so
+/// pagination still works when the body comes from the cache.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+struct CachedResponse {
+    etag: String,
+    link: Option<String>,
+    body: String,
+    last_used: u64,
+}
+
+/// ETag cache keyed by request URL. 304 responses don't count against the rate limit, so
+/// revalidating a cached page is free.
+#[derive(Serialize, Deserialize, Debug, Default)]
+pub struct EtagCache {
+    entries: HashMap<String, CachedResponse>,
+    clock: u64,
+    /// Where the cache is persisted; `None` keeps it in memory only.
+    #[serde(skip)]
+    path: Option<PathBuf>,
+    #[serde(skip)]
+    dirty: bool,
+}
+
+impl EtagCache {
+    /// Loads the cache persisted in the storage directory, starting empty if there is none.
+    pub fn load() -> Self {
+        let path = get_storage_dir().join("etag_cache.json");
+        let mut cache = std::fs::read_to_string(&path)
+            .ok()
+            .and_then(|contents| serde_json::from_str::<EtagCache>(&contents).ok())
+            .unwrap_or_default();
+        cache.path = Some(path);
+        cache
+    }
+
+    fn get(&mut self, url: &str) -> Option<CachedResponse> {
+        self.clock += 1;
+        let clock = self.clock;
+        let entry = self.entries.get_mut(url)?;
+        entry.last_used = clock;
+        Some(entry.clone())
+    }
+
+    fn insert(&mut self, url: String, etag: String, link: Option<String>, body: String) {
+        self.clock += 1;
+        let entry = CachedResponse {
+            etag,
+            link,
+            body,
+            last_used: self.clock,
+        };
+        self.entries.insert(url, entry);
+        self.evict();
+        self.dirty = true;
+    }
+
+    /// Drops least recently used entries until the bodies fit in [`MAX_ETAG_CACHE_BYTES`].
+    fn evict(&mut self) {
+        let mut size: usize = self.entries.values().map(|entry| entry.body.len()).sum();
+        while size > MAX_ETAG_CACHE_BYTES {
+            let Some(oldest) = self
+                .entries
+                .iter()
+                .min_by_key(|(_, entry)| entry.last_used)
+                .map(|(url, _)| url.clone())
+            else {
+                break;
+            };
+            if let Some(entry) = self.entries.remove(&oldest) {
+                size -= entry.body.len();
+            }
+        }
+    }
+
+    /// The serialized cache and where to write it, if it changed since the last save.
+    fn snapshot(&mut self) -> Result<Option<(PathBuf, Vec<u8>)>, serde_json::Error> {
+        let Some(path) = self.path.clone().filter(|_| self.dirty) else {
+            return Ok(None);
+        };
+        let contents = serde_json::to_vec(self)?;
+        self.dirty = false;
+        Ok(Some((path, contents)))
+    }
+}
+
+fn write_cache_file(path: &Path, contents: &[u8]) -> std::io::Result<()> {
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    std::fs::write(path, contents)
+}
+
+impl GithubClient {
+    /// Fetches one page from `url`, revalidating a cached copy with `If-None-Match` and reusing
+    /// it when GitHub answers `304 Not Modified`. A 304 with nothing cached to reuse is treated
+    /// as a miss and the page is requested again unconditionally.
+    pub async fn get_page_cached<T: DeserializeOwned>(
+        &self,
+        url: &str,
+    ) -> Result<Page<T>, octocrab::Error> {
+        let cached = self
+            .etag_cache
+            .lock()
+            .expect("etag cache lock poisoned")
+            .get(url);
+        let mut headers = HeaderMap::new();
+        if let Some(etag) = cached
+            .as_ref()
+            .and_then(|cached| HeaderValue::from_str(&cached.etag).ok())
+        {
+            headers.insert(IF_NONE_MATCH, etag);
+        }
+
+        let mut response = self.inner._get_with_headers(url, Some(headers)).await?;
+        if response.status() == StatusCode::NOT_MODIFIED {
+            match cached {
+                Some(cached) => {
+                    trace!(url, "Serving page from the etag cache");
+                    return page_from_parts(cached.link, cached.body).await;
+                }
+                None => {
+                    trace!(
+                        url,
+                        "Not modified but nothing cached; fetching the page again"
+                    );
+                    response = self.inner._get_with_headers(url, None).await?;
+                }
+            }
+        }
+
+        let response = octocrab::map_github_error(response).await?;
+        let header = |name| {
+            response
+                .headers()
+                .get(name)
+                .and_then(|value: &HeaderValue| value.to_str().ok())
+                .map(str::to_string)
+        };
+        let etag = header(ETAG);
+        let link = header(LINK);
+        let body = self.inner.body_to_string(response).await?;
+        if let Some(etag) = etag {
+            self.etag_cache
+                .lock()
+                .expect("etag cache lock poisoned")
+                .insert(url.to_string(), etag, link.clone(), body.clone());
+        }
+        page_from_parts(link, body).await
+    }
+
+    /// Persists pages cached since the last call. The cache can be several megabytes, so it is
+    /// written on the blocking pool.
+    pub async fn save_etag_cache(&self) {
+        let snapshot = self
+            .etag_cache
+            .lock()
+            .expect("etag cache lock poisoned")
+            .snapshot();
+        let (path, contents) = match snapshot {
+            Ok(Some(snapshot)) => snapshot,
+            Ok(None) => return,
+            Err(err) => {
+                error!("Failed to serialize etag cache: {err}");
+                return;
+            }
+        };
+        match tokio::task::spawn_blocking(move || write_cache_file(&path, &contents)).await {
+            Ok(Ok(())) => {}
+            Ok(Err(err)) => error!("Failed to save etag cache: {err}"),
+            Err(err) => error!("Failed to save etag cache: {err}"),
+        }
+    }
+}
+
+/// Rebuilds a page from a stored body and `Link` header using octocrab's own parsing.
+async fn page_from_parts<T: DeserializeOwned>(
+    link: Option<String>,
+    body: String,
+) -> Result<Page<T>, octocrab::Error> {
+    let mut response = http::Response::new(
+        Full::new(Bytes::from(body)).map_err(|never| -> octocrab::Error { match never {} }),
+    );
+    if let Some(link) = link.and_then(|link| HeaderValue::from_str(&link).ok()) {
+        response.headers_mut().insert(LINK, link);
+    }
+    Page::from_response(response).await
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        convert::Infallible,
+        future::{Ready, ready},
+        path::Path,
+        sync::{
+            Arc,
+            atomic::{AtomicUsize, Ordering},
+        },
+        task::{Context, Poll},
+    };
+
+    use bytes::Bytes;
+    use http::{
+        Request, Response, StatusCode, Uri,
+        header::{ETAG, IF_NONE_MATCH},
+    };
+    use http_body_util::Full;
+    use octocrab::{
+        AuthState, OctocrabBuilder, models::issues::Comment,
+        service::middleware::base_uri::BaseUriLayer,
+    };
+    use tower::Service;
+
+    use super::{EtagCache, MAX_ETAG_CACHE_BYTES};
+    use crate::github::{GithubClient, mock::MockFixtures};
+
+    /// Serves the mock fixtures with an ETag, answering `304 Not Modified` once a page is
+    /// revalidated.
+    #[derive(Clone)]
+    struct RevalidatingService {
+        fixtures: Arc<MockFixtures>,
+        not_modified: Arc<AtomicUsize>,
+    }
+
+    impl<B> Service<Request<B>> for RevalidatingService {
+        type Response = Response<Full<Bytes>>;
+        type Error = Infallible;
+        type Future = Ready<Result<Self::Response, Self::Error>>;
+
+        fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+            Poll::Ready(Ok(()))
+        }
+
+        fn call(&mut self, req: Request<B>) -> Self::Future {
+            let revalidating = req
+                .headers()
+                .get(IF_NONE_MATCH)
+                .is_some_and(|etag| etag == "\"v1\"");
+            let mut response = if revalidating {
+                self.not_modified.fetch_add(1, Ordering::Relaxed);
+                let mut response = Response::new(Full::new(Bytes::new()));
+                *response.status_mut() = StatusCode::NOT_MODIFIED;
+                response
+            } else {
+                let (_, body) = self.fixtures.respond(req.method(), req.uri());
+                Response::new(Full::new(Bytes::from(body.to_string())))
+            };
+            response
+                .headers_mut()
+                .insert(ETAG, http::HeaderValue::from_static("\"v1\""));
+            ready(Ok(response))
+        }
+    }
+
+    #[tokio::test]
+    async fn serves_the_cached_page_when_not_modified() {
+        let dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("fixtures/mock");
+        let service = RevalidatingService {
+            fixtures: Arc::new(MockFixtures::load(&dir, "owner", "repo").unwrap()),
+            not_modified: Arc::default(),
+        };
+        let not_modified = service.not_modified.clone();
+        let inner = OctocrabBuilder::new_empty()
+            .with_service(service)
+            .with_layer(&BaseUriLayer::new(Uri::from_static(
+                "https://api.github.com",
+            )))
+            .with_auth(AuthState::None)
+            .build()
+            .unwrap();
+        let client = GithubClient::with_cache(inner, EtagCache::default(), false);
+        let url = "/repos/owner/repo/issues/1/comments?per_page=100&page=1";
+
+        let fresh = client.get_page_cached::<Comment>(url).await.unwrap();
+        let cached = client.get_page_cached::<Comment>(url).await.unwrap();
+
+        assert_eq!(not_modified.load(Ordering::Relaxed), 1);
+        assert_eq!(fresh.items.len(), 2);
+        assert_eq!(
+            cached.items.iter().map(|c| c.id).collect::<Vec<_>>(),
+            fresh.items.iter().map(|c| c.id).collect::<Vec<_>>()
+        );
+    }
+
+    /// Answers the first request with `304 Not Modified` whatever it asks for, like a stale
+    /// intermediate cache would, then serves the mock fixtures.
+    #[derive(Clone)]
+    struct StaleFirstService {
+        fixtures: Arc<MockFixtures>,
+        requests: Arc<AtomicUsize>,
+    }
+
+    impl<B> Service<Request<B>> for StaleFirstService {
+        type Response = Response<Full<Bytes>>;
+        type Error = Infallible;
+        type Future = Ready<Result<Self::Response, Self::Error>>;
+
+        fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+            Poll::Ready(Ok(()))
+        }
+
+        fn call(&mut self, req: Request<B>) -> Self::Future {
+            if self.requests.fetch_add(1, Ordering::Relaxed) == 0 {
+                let mut response = Response::new(Full::new(Bytes::new()));
+                *response.status_mut() = StatusCode::NOT_MODIFIED;
+                return ready(Ok(response));
+            }
+            let (_, body) = self.fixtures.respond(req.method(), req.uri());
+            ready(Ok(Response::new(Full::new(Bytes::from(body.to_string())))))
+        }
+    }
+
+    #[tokio::test]
+    async fn refetches_when_not_modified_without_a_cached_page() {
+        let dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("fixtures/mock");
+        let service = StaleFirstService {
+            fixtures: Arc::new(MockFixtures::load(&dir, "owner", "repo").unwrap()),
+            requests: Arc::default(),
+        };
+        let requests = service.requests.clone();
+        let inner = OctocrabBuilder::new_empty()
+            .with_service(service)
+            .with_layer(&BaseUriLayer::new(Uri::from_static(
+                "https://api.github.com",
+            )))
+            .with_auth(AuthState::None)
+            .build()
+            .unwrap();
+        let client = GithubClient::with_cache(inner, EtagCache::default(), false);
+
+        let page = client
+            .get_page_cached::<Comment>("/repos/owner/repo/issues/1/comments?per_page=100&page=1")
+            .await
+            .unwrap();
+
+        assert_eq!(requests.load(Ordering::Relaxed), 2);
+        assert_eq!(page.items.len(), 2);
+    }
+
+    #[tokio::test]
+    async fn fetches_pages_through_the_client() {
+        let dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("fixtures/mock");
+        let client = GithubClient::mock(&dir, "owner", "repo").unwrap();
+
+        let page = client
+            .get_page_cached::<Comment>("/repos/owner/repo/issues/1/comments?per_page=100&page=1")
+            .await
+            .unwrap();
+
+        assert!(!page.items.is_empty());
+        assert!(page.next.is_none());
+    }
+
+    #[test]
+    fn evicts_least_recently_used_entries_over_the_size_cap() {
+        let mut cache = EtagCache::default();
+        let half = "x".repeat(MAX_ETAG_CACHE_BYTES / 2);
+        cache.insert("a".into(), "\"1\"".into(), None, half.clone());
+        cache.insert("b".into(), "\"2\"".into(), None, half.clone());
+        assert!(cache.get("a").is_some());
+
+        cache.insert("c".into(), "\"3\"".into(), None, half);
+
+        assert!(cache.get("a").is_some());
+        assert!(cache.get("b").is_none());
+        assert!(cache.get("c").is_some());
+    }
+}
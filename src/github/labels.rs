@@ -0,0 +1,55 @@
+use octocrab::models::Label;
+use serde_json::{Map, Value};
+
+use crate::{errors::AppError, github::GithubClient};
+
+impl GithubClient {
+    /// Changes the color and, when given, the description of an existing repository label.
+    /// `color` is six hex digits without the leading `#`.
+    pub async fn update_label(
+        &self,
+        owner: &str,
+        repo: &str,
+        name: &str,
+        color: &str,
+        description: Option<&str>,
+    ) -> Result<Label, AppError> {
+        let route = format!("/repos/{owner}/{repo}/labels/{}", encode_path_segment(name));
+        let mut body = Map::new();
+        body.insert("color".to_string(), Value::from(color));
+        if let Some(description) = description {
+            body.insert("description".to_string(), Value::from(description));
+        }
+        let label = self.inner.patch(route, Some(&body)).await?;
+        Ok(label)
+    }
+}
+
+/// Percent-encodes everything but RFC 3986 unreserved characters, so label names with spaces,
+/// colons or emoji survive as a single path segment.
+fn encode_path_segment(segment: &str) -> String {
+    let mut out = String::with_capacity(segment.len());
+    for byte in segment.bytes() {
+        if byte.is_ascii_alphanumeric() || matches!(byte, b'-' | b'.' | b'_' | b'~') {
+            out.push(byte as char);
+        } else {
+            out.push_str(&format!("%{byte:02X}"));
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::encode_path_segment;
+
+    #[test]
+    fn encodes_label_names_for_the_url_path() {
+        assert_eq!(
+            encode_path_segment("good first issue"),
+            "good%20first%20issue"
+        );
+        assert_eq!(encode_path_segment("area: ui/tui"), "area%3A%20ui%2Ftui");
+        assert_eq!(encode_path_segment("bug"), "bug");
+    }
+}
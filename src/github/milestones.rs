@@ -0,0 +1,42 @@
+use octocrab::models::{Milestone, issues::Issue};
+use serde_json::json;
+
+use crate::{errors::AppError, github::GithubClient};
+
+impl GithubClient {
+    /// The repository's open milestones, the one due soonest first.
+    pub async fn open_milestones(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> Result<Vec<Milestone>, AppError> {
+        let route = format!("/repos/{owner}/{repo}/milestones");
+        let params = [
+            ("state", "open"),
+            ("sort", "due_on"),
+            ("direction", "asc"),
+            ("per_page", "100"),
+        ];
+        let milestones = self.inner.get(route, Some(&params)).await?;
+        Ok(milestones)
+    }
+
+    /// Sets the milestone of an issue by its number, or clears it when `milestone` is `None`.
+    ///
+    /// `issues().update()` leaves the milestone out of the request when it isn't set, so it
+    /// can't send the `null` that clears one.
+    pub async fn set_issue_milestone(
+        &self,
+        owner: &str,
+        repo: &str,
+        number: u64,
+        milestone: Option<i64>,
+    ) -> Result<Issue, AppError> {
+        let route = format!("/repos/{owner}/{repo}/issues/{number}");
+        let issue = self
+            .inner
+            .patch(route, Some(&json!({ "milestone": milestone })))
+            .await?;
+        Ok(issue)
+    }
+}
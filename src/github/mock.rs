@@ -0,0 +1,547 @@
+//! An offline stand-in for the GitHub REST API, used by `--mock`.
+//!
+//! Octocrab is pointed at an in-process [`Service`] that answers requests from JSON fixtures, so
+//! the rest of the app keeps talking to a regular [`Octocrab`] client. Fixtures may be full API
+//! payloads (as saved from `gh api`) or trimmed down to the interesting fields; anything octocrab
+//! requires but the fixture leaves out is filled in with placeholder values.
+//!
+//! Expected layout of the fixture directory, every file being optional:
+//!
+//! ```text
+//! user.json          the authenticated user
+//! issues.json        array of issues
+//! labels.json        array of repository labels
+//! comments/<N>.json  array of comments on issue N
+//! ```
+
+use std::{
+    collections::HashMap,
+    convert::Infallible,
+    fs,
+    future::{Ready, ready},
+    path::Path,
+    sync::Arc,
+    task::{Context, Poll},
+};
+
+use anyhow::Context as _;
+use bytes::Bytes;
+use http::{Method, Request, Response, StatusCode, Uri, header::CONTENT_TYPE};
+use http_body_util::Full;
+use octocrab::{AuthState, Octocrab, OctocrabBuilder, service::middleware::base_uri::BaseUriLayer};
+use serde_json::{Map, Value, json};
+use tower::Service;
+
+use crate::errors::AppError;
+
+const MOCK_USER: &str = "octocat";
+const DEFAULT_TIMESTAMP: &str = "2024-01-01T00:00:00Z";
+pub const MOCK_WRITE_MESSAGE: &str = "Mock mode: changes are not sent to GitHub";
+
+#[derive(Debug, Default)]
+pub struct MockFixtures {
+    user: Value,
+    issues: Vec<Value>,
+    labels: Vec<Value>,
+    comments: HashMap<u64, Vec<Value>>,
+}
+
+impl MockFixtures {
+    pub fn load(dir: &Path, owner: &str, repo: &str) -> Result<Self, AppError> {
+        if !dir.is_dir() {
+            return Err(
+                anyhow::anyhow!("mock fixture directory `{}` not found", dir.display()).into(),
+            );
+        }
+        let repo_url = format!("https://api.github.com/repos/{owner}/{repo}");
+        let html_url = format!("https://github.com/{owner}/{repo}");
+
+        let user = read_fixture(&dir.join("user.json"))?
+            .map(complete_user)
+            .unwrap_or_else(|| complete_user(Value::from(MOCK_USER)));
+
+        let mut comments = HashMap::new();
+        let comments_dir = dir.join("comments");
+        if comments_dir.is_dir() {
+            for entry in fs::read_dir(&comments_dir)
+                .with_context(|| format!("failed to read `{}`", comments_dir.display()))?
+            {
+                let path = entry?.path();
+                let Some(number) = path
+                    .file_stem()
+                    .and_then(|stem| stem.to_str())
+                    .and_then(|stem| stem.parse::<u64>().ok())
+                else {
+                    continue;
+                };
+                let items = read_array(&path)?
+                    .into_iter()
+                    .enumerate()
+                    .map(|(idx, comment)| {
+                        complete_comment(
+                            comment,
+                            number * 1000 + idx as u64 + 1,
+                            &repo_url,
+                            &html_url,
+                        )
+                    })
+                    .collect();
+                comments.insert(number, items);
+            }
+        }
+
+        let labels = read_array(&dir.join("labels.json"))?
+            .into_iter()
+            .enumerate()
+            .map(|(idx, label)| complete_label(label, idx as u64 + 1, &repo_url))
+            .collect();
+
+        let issues = read_array(&dir.join("issues.json"))?
+            .into_iter()
+            .enumerate()
+            .map(|(idx, issue)| {
+                let number = issue
+                    .get("number")
+                    .and_then(Value::as_u64)
+                    .unwrap_or(idx as u64 + 1);
+                let comment_count = comments.get(&number).map_or(0, Vec::len);
+                complete_issue(issue, number, comment_count, &repo_url, &html_url)
+            })
+            .collect();
+
+        Ok(Self {
+            user,
+            issues,
+            labels,
+            comments,
+        })
+    }
+
+    /// Answers a single API request. Every non-`GET` request is rejected so nothing is ever
+    /// "written" in mock mode.
+    pub fn respond(&self, method: &Method, uri: &Uri) -> (StatusCode, Value) {
+        if method != Method::GET {
+            return (
+                StatusCode::FORBIDDEN,
+                json!({ "message": MOCK_WRITE_MESSAGE }),
+            );
+        }
+        let segments = uri.path().trim_matches('/').split('/').collect::<Vec<_>>();
+        match segments.as_slice() {
+            ["user"] => (StatusCode::OK, self.user.clone()),
+            ["search", "issues"] => {
+                let query = uri
+                    .query()
+                    .and_then(|query| query.split('&').find_map(|pair| pair.strip_prefix("q=")))
+                    .map(percent_decode)
+                    .unwrap_or_default();
+                let items = self
+                    .issues
+                    .iter()
+                    .filter(|issue| matches_query(issue, &query))
+                    .cloned()
+                    .collect::<Vec<_>>();
+                (
+                    StatusCode::OK,
+                    json!({
+                        "total_count": items.len(),
+                        "incomplete_results": false,
+                        "items": items,
+                    }),
+                )
+            }
+            ["repos", _, _, "issues"] => (StatusCode::OK, Value::from(self.issues.clone())),
+            ["repos", _, _, "issues", number] => match self.issue(number) {
+                Some(issue) => (StatusCode::OK, issue.clone()),
+                None => not_found(),
+            },
+            ["repos", _, _, "issues", number, "comments"] => {
+                let comments = number
+                    .parse::<u64>()
+                    .ok()
+                    .and_then(|number| self.comments.get(&number))
+                    .cloned()
+                    .unwrap_or_default();
+                (StatusCode::OK, Value::from(comments))
+            }
+            [
+                "repos",
+                _,
+                _,
+                "issues",
+                _,
+                "timeline" | "events" | "reactions",
+            ]
+            | ["repos", _, _, "issues", "comments", _, "reactions"]
+            | ["repos", _, _, "pulls", _, "files"]
+            | ["repos", _, _, "milestones"] => (StatusCode::OK, Value::Array(Vec::new())),
+            ["repos", _, _, "labels"] => (StatusCode::OK, Value::from(self.labels.clone())),
+            ["repos", _, _, "labels", name] => {
+                let name = percent_decode(name);
+                self.labels
+                    .iter()
+                    .find(|label| label.get("name").and_then(Value::as_str) == Some(name.as_str()))
+                    .map_or_else(not_found, |label| (StatusCode::OK, label.clone()))
+            }
+            _ => not_found(),
+        }
+    }
+
+    fn issue(&self, number: &str) -> Option<&Value> {
+        let number = number.parse::<u64>().ok()?;
+        self.issues
+            .iter()
+            .find(|issue| issue.get("number").and_then(Value::as_u64) == Some(number))
+    }
+}
+
+#[derive(Clone)]
+struct MockService {
+    fixtures: Arc<MockFixtures>,
+}
+
+impl<B> Service<Request<B>> for MockService {
+    type Response = Response<Full<Bytes>>;
+    type Error = Infallible;
+    type Future = Ready<Result<Self::Response, Self::Error>>;
+
+    fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn call(&mut self, req: Request<B>) -> Self::Future {
+        let (status, body) = self.fixtures.respond(req.method(), req.uri());
+        let mut response = Response::new(Full::new(Bytes::from(body.to_string())));
+        *response.status_mut() = status;
+        response.headers_mut().insert(
+            CONTENT_TYPE,
+            http::HeaderValue::from_static("application/json"),
+        );
+        ready(Ok(response))
+    }
+}
+
+pub(super) fn build_mock_octocrab(fixtures: MockFixtures) -> Result<Octocrab, AppError> {
+    let service = MockService {
+        fixtures: Arc::new(fixtures),
+    };
+    let octocrab = OctocrabBuilder::new_empty()
+        .with_service(service)
+        .with_layer(&BaseUriLayer::new(Uri::from_static(
+            "https://api.github.com",
+        )))
+        .with_auth(AuthState::None)
+        .build()
+        .map_err(|err| anyhow::anyhow!("failed to build mock GitHub client: {err}"))?;
+    Ok(octocrab)
+}
+
+fn not_found() -> (StatusCode, Value) {
+    (StatusCode::NOT_FOUND, json!({ "message": "Not Found" }))
+}
+
+fn read_fixture(path: &Path) -> Result<Option<Value>, AppError> {
+    if !path.exists() {
+        return Ok(None);
+    }
+    let contents =
+        fs::read_to_string(path).with_context(|| format!("failed to read `{}`", path.display()))?;
+    let value = serde_json::from_str(&contents)
+        .with_context(|| format!("invalid JSON in `{}`", path.display()))?;
+    Ok(Some(value))
+}
+
+fn read_array(path: &Path) -> Result<Vec<Value>, AppError> {
+    match read_fixture(path)? {
+        None => Ok(Vec::new()),
+        Some(Value::Array(items)) => Ok(items),
+        Some(_) => Err(anyhow::anyhow!("`{}` must contain a JSON array", path.display()).into()),
+    }
+}
+
+/// Matches the handful of qualifiers the app itself sends (`is:open`, `is:issue`, `label:`,
+/// `author:`) plus free text against the title and body. Everything else is ignored.
+fn matches_query(issue: &Value, query: &str) -> bool {
+    let field = |key: &str| issue.get(key).and_then(Value::as_str).unwrap_or_default();
+    let title = field("title").to_lowercase();
+    let body = field("body").to_lowercase();
+    let state = field("state");
+    let author = issue
+        .pointer("/user/login")
+        .and_then(Value::as_str)
+        .unwrap_or_default();
+    let has_label = |name: &str| {
+        issue
+            .get("labels")
+            .and_then(Value::as_array)
+            .is_some_and(|labels| {
+                labels.iter().any(|label| {
+                    label
+                        .get("name")
+                        .and_then(Value::as_str)
+                        .is_some_and(|label| label.eq_ignore_ascii_case(name))
+                })
+            })
+    };
+
+    query.split_whitespace().all(|term| {
+        let unquoted = |value: &str| value.trim_matches('"').to_string();
+        match term.split_once(':') {
+            Some(("is", "open")) => state == "open",
+            Some(("is", "closed")) => state == "closed",
+            Some(("is", "issue")) => issue.get("pull_request").is_none_or(Value::is_null),
+            Some(("is", "pr")) => issue.get("pull_request").is_some_and(|pr| !pr.is_null()),
+            Some(("label", name)) => has_label(&unquoted(name)),
+            Some(("author", login)) => author.eq_ignore_ascii_case(&unquoted(login)),
+            Some((_, _)) => true,
+            None => {
+                let term = term.to_lowercase();
+                title.contains(&term) || body.contains(&term)
+            }
+        }
+    })
+}
+
+fn percent_decode(input: &str) -> String {
+    let bytes = input.as_bytes();
+    let mut out = Vec::with_capacity(bytes.len());
+    let mut idx = 0;
+    while idx < bytes.len() {
+        match bytes[idx] {
+            b'+' => out.push(b' '),
+            b'%' if idx + 2 < bytes.len() => {
+                let hex = std::str::from_utf8(&bytes[idx + 1..idx + 3]).ok();
+                match hex.and_then(|hex| u8::from_str_radix(hex, 16).ok()) {
+                    Some(byte) => {
+                        out.push(byte);
+                        idx += 2;
+                    }
+                    None => out.push(b'%'),
+                }
+            }
+            byte => out.push(byte),
+        }
+        idx += 1;
+    }
+    String::from_utf8_lossy(&out).into_owned()
+}
+
+fn object(value: Value) -> Map<String, Value> {
+    match value {
+        Value::Object(map) => map,
+        _ => Map::new(),
+    }
+}
+
+fn fill(map: &mut Map<String, Value>, key: &str, value: impl FnOnce() -> Value) {
+    if !map.contains_key(key) {
+        map.insert(key.to_string(), value());
+    }
+}
+
+/// Accepts either a login string or a (partial) user object.
+fn complete_user(value: Value) -> Value {
+    let mut map = match value {
+        Value::String(login) => Map::from_iter([("login".to_string(), Value::from(login))]),
+        other => object(other),
+    };
+    let login = map
+        .get("login")
+        .and_then(Value::as_str)
+        .unwrap_or(MOCK_USER)
+        .to_string();
+    let api = format!("https://api.github.com/users/{login}");
+    let id = login.bytes().fold(0u64, |acc, byte| {
+        acc.wrapping_mul(31).wrapping_add(byte.into())
+    }) % 1_000_000;
+    fill(&mut map, "login", || Value::from(login.clone()));
+    fill(&mut map, "id", || Value::from(id));
+    fill(&mut map, "node_id", || Value::from(format!("MOCK_U_{id}")));
+    fill(&mut map, "avatar_url", || {
+        Value::from(format!("https://avatars.githubusercontent.com/u/{id}"))
+    });
+    fill(&mut map, "gravatar_id", || Value::from(""));
+    fill(&mut map, "url", || Value::from(api.clone()));
+    fill(&mut map, "html_url", || {
+        Value::from(format!("https://github.com/{login}"))
+    });
+    for (key, suffix) in [
+        ("followers_url", "followers"),
+        ("following_url", "following"),
+        ("gists_url", "gists"),
+        ("starred_url", "starred"),
+        ("subscriptions_url", "subscriptions"),
+        ("organizations_url", "orgs"),
+        ("repos_url", "repos"),
+        ("events_url", "events"),
+        ("received_events_url", "received_events"),
+    ] {
+        fill(&mut map, key, || Value::from(format!("{api}/{suffix}")));
+    }
+    fill(&mut map, "type", || Value::from("User"));
+    fill(&mut map, "site_admin", || Value::from(false));
+    fill(&mut map, "name", || Value::Null);
+    fill(&mut map, "patch_url", || Value::Null);
+    Value::Object(map)
+}
+
+/// Accepts either a label name or a (partial) label object.
+fn complete_label(value: Value, id: u64, repo_url: &str) -> Value {
+    let mut map = match value {
+        Value::String(name) => Map::from_iter([("name".to_string(), Value::from(name))]),
+        other => object(other),
+    };
+    let name = map
+        .get("name")
+        .and_then(Value::as_str)
+        .unwrap_or("label")
+        .to_string();
+    fill(&mut map, "id", || Value::from(id));
+    fill(&mut map, "node_id", || Value::from(format!("MOCK_L_{id}")));
+    fill(&mut map, "url", || {
+        Value::from(format!("{repo_url}/labels/{}", name.replace(' ', "%20")))
+    });
+    fill(&mut map, "name", || Value::from(name.clone()));
+    fill(&mut map, "color", || Value::from("ededed"));
+    fill(&mut map, "default", || Value::from(false));
+    Value::Object(map)
+}
+
+fn complete_comment(value: Value, id: u64, repo_url: &str, html_url: &str) -> Value {
+    let mut map = object(value);
+    let id = map.get("id").and_then(Value::as_u64).unwrap_or(id);
+    fill(&mut map, "id", || Value::from(id));
+    fill(&mut map, "node_id", || Value::from(format!("MOCK_C_{id}")));
+    fill(&mut map, "url", || {
+        Value::from(format!("{repo_url}/issues/comments/{id}"))
+    });
+    fill(&mut map, "html_url", || {
+        Value::from(format!("{html_url}/issues#issuecomment-{id}"))
+    });
+    fill(&mut map, "created_at", || Value::from(DEFAULT_TIMESTAMP));
+    let user = map.remove("user").unwrap_or_else(|| Value::from(MOCK_USER));
+    map.insert("user".to_string(), complete_user(user));
+    Value::Object(map)
+}
+
+fn complete_issue(
+    value: Value,
+    number: u64,
+    comment_count: usize,
+    repo_url: &str,
+    html_url: &str,
+) -> Value {
+    let mut map = object(value);
+    let issue_url = format!("{repo_url}/issues/{number}");
+    fill(&mut map, "id", || Value::from(number));
+    fill(&mut map, "node_id", || {
+        Value::from(format!("MOCK_I_{number}"))
+    });
+    fill(&mut map, "number", || Value::from(number));
+    fill(&mut map, "url", || Value::from(issue_url.clone()));
+    fill(&mut map, "repository_url", || Value::from(repo_url));
+    fill(&mut map, "labels_url", || {
+        Value::from(format!("{issue_url}/labels{{/name}}"))
+    });
+    fill(&mut map, "comments_url", || {
+        Value::from(format!("{issue_url}/comments"))
+    });
+    fill(&mut map, "events_url", || {
+        Value::from(format!("{issue_url}/events"))
+    });
+    fill(&mut map, "html_url", || {
+        Value::from(format!("{html_url}/issues/{number}"))
+    });
+    fill(&mut map, "state", || Value::from("open"));
+    fill(&mut map, "state_reason", || Value::Null);
+    fill(&mut map, "title", || {
+        Value::from(format!("Issue #{number}"))
+    });
+    fill(&mut map, "body", || Value::Null);
+    fill(&mut map, "locked", || Value::from(false));
+    fill(&mut map, "comments", || Value::from(comment_count));
+    fill(&mut map, "created_at", || Value::from(DEFAULT_TIMESTAMP));
+    let created_at = map["created_at"].clone();
+    fill(&mut map, "updated_at", || created_at);
+
+    let user = map.remove("user").unwrap_or_else(|| Value::from(MOCK_USER));
+    map.insert("user".to_string(), complete_user(user));
+    let labels = match map.remove("labels") {
+        Some(Value::Array(labels)) => labels,
+        _ => Vec::new(),
+    };
+    let labels = labels
+        .into_iter()
+        .enumerate()
+        .map(|(idx, label)| complete_label(label, number * 100 + idx as u64, repo_url))
+        .collect::<Vec<_>>();
+    map.insert("labels".to_string(), Value::from(labels));
+    let assignees = match map.remove("assignees") {
+        Some(Value::Array(assignees)) => assignees,
+        _ => Vec::new(),
+    };
+    let assignees = assignees.into_iter().map(complete_user).collect::<Vec<_>>();
+    map.insert("assignees".to_string(), Value::from(assignees));
+    Value::Object(map)
+}
+
+/// Builds an octocrab `Issue` from a fixture-style partial object, for unit tests elsewhere.
+#[cfg(test)]
+pub(crate) fn fixture_issue(value: Value, number: u64) -> octocrab::models::issues::Issue {
+    let repo_url = "https://api.github.com/repos/owner/repo";
+    let issue = complete_issue(value, number, 0, repo_url, "https://github.com/owner/repo");
+    serde_json::from_value(issue).expect("completed fixture issue deserializes")
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use http::{Method, StatusCode, Uri};
+
+    use super::{MOCK_WRITE_MESSAGE, MockFixtures, build_mock_octocrab};
+
+    fn fixtures() -> MockFixtures {
+        let dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("fixtures/mock");
+        MockFixtures::load(&dir, "owner", "repo").expect("bundled fixtures load")
+    }
+
+    #[tokio::test]
+    async fn serves_fixture_issues_and_comments_through_octocrab() {
+        let crab = build_mock_octocrab(fixtures()).unwrap();
+
+        let issue = crab.issues("owner", "repo").get(1).await.unwrap();
+        let comments = crab
+            .issues("owner", "repo")
+            .list_comments(1)
+            .send()
+            .await
+            .unwrap();
+        let page = crab
+            .search()
+            .issues_and_pull_requests("repo:owner/repo is:issue is:open")
+            .send()
+            .await
+            .unwrap();
+
+        assert_eq!(issue.number, 1);
+        assert!(!comments.items.is_empty());
+        assert!(!page.items.is_empty());
+        assert!(
+            page.items
+                .iter()
+                .all(|issue| issue.state == octocrab::models::IssueState::Open)
+        );
+    }
+
+    #[test]
+    fn rejects_writes() {
+        let (status, body) = fixtures().respond(
+            &Method::POST,
+            &Uri::from_static("https://api.github.com/repos/owner/repo/issues/1/comments"),
+        );
+
+        assert_eq!(status, StatusCode::FORBIDDEN);
+        assert_eq!(body["message"], MOCK_WRITE_MESSAGE);
+    }
+}
@@ -1,7 +1,32 @@
+use std::{path::Path, sync::Mutex};
+
 use crate::errors::AppError;
 
+pub mod connection;
+pub mod etag_cache;
+pub mod labels;
+pub mod milestones;
+pub mod mock;
+pub mod retry;
+pub mod subscription;
+pub mod templates;
+
+pub use connection::{BaseUrl, ConnectionOptions, ProxyConfig, connection_failure};
+pub use etag_cache::EtagCache;
+pub use retry::{with_connect_retry, with_retry, with_timeout};
+pub use subscription::SubscriptionState;
+pub use templates::IssueTemplate;
+
+/// Classic token scopes that grant write access to issues. Either one is enough.
+pub const REQUIRED_SCOPES: &[&str] = &["repo", "public_repo"];
+
 pub struct GithubClient {
     inner: octocrab::Octocrab,
+    etag_cache: Mutex<EtagCache>,
+    /// Whether requests carry credentials that [`GithubClient::verify`] can check.
+    authenticated: bool,
+    /// Proxy requests are tunnelled through, named in connection errors.
+    proxy: Option<http::Uri>,
 }
 
 impl std::ops::Deref for GithubClient {
@@ -13,16 +38,116 @@ impl std::ops::Deref for GithubClient {
 }
 
 impl GithubClient {
-    pub fn new(token: Option<String>) -> Result<Self, AppError> {
+    pub fn new(token: Option<String>, options: &ConnectionOptions) -> Result<Self, AppError> {
+        let authenticated = token.is_some();
+        if !options.is_default() {
+            let inner = connection::build_octocrab(token, options)?;
+            let mut client = Self::with_cache(inner, EtagCache::load(), authenticated);
+            client.proxy = options.proxy.as_ref().map(|proxy| proxy.uri.clone());
+            return Ok(client);
+        }
         let mut builder = octocrab::Octocrab::builder();
         if let Some(token) = token {
             builder = builder.personal_token(token);
         }
         let inner = builder.build()?;
-        Ok(Self { inner })
+        Ok(Self::with_cache(inner, EtagCache::load(), authenticated))
+    }
+
+    /// Builds a client that answers every request from the fixtures in `dir` instead of GitHub.
+    pub fn mock(dir: &Path, owner: &str, repo: &str) -> Result<Self, AppError> {
+        let fixtures = mock::MockFixtures::load(dir, owner, repo)?;
+        let inner = mock::build_mock_octocrab(fixtures)?;
+        // Fixtures never change, and they shouldn't leak into the real cache on disk. The
+        // fixtures answer `/user`, so the mock counts as signed in.
+        Ok(Self::with_cache(inner, EtagCache::default(), true))
+    }
+
+    fn with_cache(inner: octocrab::Octocrab, etag_cache: EtagCache, authenticated: bool) -> Self {
+        Self {
+            inner,
+            etag_cache: Mutex::new(etag_cache),
+            authenticated,
+            proxy: None,
+        }
     }
 
     pub fn inner(&self) -> &octocrab::Octocrab {
         &self.inner
     }
+
+    /// Checks the token against `/user` and returns the login it belongs to, so a bad or expired
+    /// token is reported before the UI starts. Returns `None` without a request when no token
+    /// is configured.
+    pub async fn verify(&self) -> Result<Option<String>, AppError> {
+        if !self.authenticated {
+            return Ok(None);
+        }
+        match self.inner.current().user().await {
+            Ok(user) => Ok(Some(user.login)),
+            Err(octocrab::Error::GitHub { source, .. }) => {
+                let hint = match source.status_code.as_u16() {
+                    401 => {
+                        "the token is invalid, expired or revoked; store a new one with --set-token"
+                    }
+                    403 => "the token is not allowed to read the signed-in user",
+                    _ => "GitHub rejected the request",
+                };
+                Err(anyhow::anyhow!(
+                    "could not verify the GitHub token ({} {}): {hint}",
+                    source.status_code.as_u16(),
+                    source.message
+                )
+                .into())
+            }
+            Err(err) => match connection::connection_failure(&err) {
+                Some(cause) => {
+                    let via = self
+                        .proxy
+                        .as_ref()
+                        .map(|proxy| format!(" through the proxy at {proxy}"))
+                        .unwrap_or_default();
+                    Err(anyhow::anyhow!("could not connect to GitHub{via}: {cause}").into())
+                }
+                None => Err(anyhow::anyhow!(
+                    "could not reach GitHub to verify the token: {}",
+                    err.to_string().replace('\n', " ")
+                )
+                .into()),
+            },
+        }
+    }
+
+    /// Returns the scopes granted to the token, as reported by the `X-OAuth-Scopes` header.
+    ///
+    /// Fine-grained tokens don't report scopes, in which case this returns `None`.
+    pub async fn token_scopes(&self) -> Result<Option<Vec<String>>, AppError> {
+        let response = self.inner._get("/user").await?;
+        let Some(header) = response.headers().get("x-oauth-scopes") else {
+            return Ok(None);
+        };
+        let scopes = header
+            .to_str()
+            .map_err(anyhow::Error::from)?
+            .split(',')
+            .map(str::trim)
+            .filter(|scope| !scope.is_empty())
+            .map(String::from)
+            .collect();
+        Ok(Some(scopes))
+    }
+}
+
+/// Builds a warning for tokens that can read but not write issues, comments and labels.
+pub fn missing_scopes_warning(scopes: &[String]) -> Option<String> {
+    if scopes
+        .iter()
+        .any(|scope| REQUIRED_SCOPES.contains(&scope.as_str()))
+    {
+        return None;
+    }
+    Some(format!(
+        "Token is missing the `{}` scope; commenting and editing labels will fail.",
+        REQUIRED_SCOPES.join("` or `")
+    ))
 }
@@ -0,0 +1,279 @@
+use std::{
+    future::Future,
+    sync::atomic::{AtomicU64, Ordering},
+    time::Duration,
+};
+
+use http::{Method, Request, Response, StatusCode};
+use octocrab::{Error as OctoError, service::middleware::retry::RetryConfig};
+use tower::retry::Policy;
+use tracing::debug;
+
+use crate::errors::AppError;
+
+pub const DEFAULT_REQUEST_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Seconds a request may take before [`with_timeout`] gives up on it; 0 waits forever.
+static REQUEST_TIMEOUT_SECS: AtomicU64 = AtomicU64::new(DEFAULT_REQUEST_TIMEOUT.as_secs());
+
+pub fn set_request_timeout(timeout: Duration) {
+    REQUEST_TIMEOUT_SECS.store(timeout.as_secs(), Ordering::Relaxed);
+}
+
+/// Runs `call`, failing with [`AppError::Timeout`] if it takes longer than the configured
+/// request timeout. Wrap the whole retried call so backoff counts towards the limit.
+pub async fn with_timeout<T, E, Fut>(call: Fut) -> Result<T, AppError>
+where
+    E: Into<AppError>,
+    Fut: Future<Output = Result<T, E>>,
+{
+    let timeout = Duration::from_secs(REQUEST_TIMEOUT_SECS.load(Ordering::Relaxed));
+    timeout_after(timeout, call).await
+}
+
+async fn timeout_after<T, E, Fut>(timeout: Duration, call: Fut) -> Result<T, AppError>
+where
+    E: Into<AppError>,
+    Fut: Future<Output = Result<T, E>>,
+{
+    if timeout.is_zero() {
+        return call.await.map_err(Into::into);
+    }
+    match tokio::time::timeout(timeout, call).await {
+        Ok(result) => result.map_err(Into::into),
+        Err(_) => Err(AppError::Timeout(timeout)),
+    }
+}
+
+/// Retries after the first failed attempt, so a call is tried at most `MAX_RETRIES + 1` times.
+pub const MAX_RETRIES: u32 = 3;
+const INITIAL_BACKOFF: Duration = Duration::from_millis(500);
+
+/// Runs `call`, retrying server errors and network failures with exponential backoff. Client
+/// errors (4xx) are returned straight away since repeating the request won't change the answer.
+pub async fn with_retry<T, F, Fut>(call: F) -> Result<T, OctoError>
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = Result<T, OctoError>>,
+{
+    retry(call, INITIAL_BACKOFF, is_transient).await
+}
+
+/// Like [`with_retry`], for requests that aren't safe to repeat such as creating a comment. Only
+/// attempts that never connected are retried: a 5xx or a dropped connection may arrive after
+/// GitHub already acted on the request.
+pub async fn with_connect_retry<T, F, Fut>(call: F) -> Result<T, OctoError>
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = Result<T, OctoError>>,
+{
+    retry(call, INITIAL_BACKOFF, is_connect_failure).await
+}
+
+/// Whether `err` happened while connecting, before any part of the request was sent.
+pub fn is_connect_failure(err: &OctoError) -> bool {
+    service_cause::<hyper_util::client::legacy::Error>(err).is_some_and(|err| err.is_connect())
+}
+
+/// Whether `err` is the client giving up after the request timeout. Retrying would only wait
+/// that long again, so these aren't [transient](is_transient).
+pub fn is_timed_out(err: &OctoError) -> bool {
+    service_cause::<tower::timeout::error::Elapsed>(err).is_some()
+}
+
+/// The first error of type `E` in the source chain of a failed request.
+fn service_cause<E: std::error::Error + 'static>(err: &OctoError) -> Option<&E> {
+    let OctoError::Service { source, .. } = err else {
+        return None;
+    };
+    let mut next: Option<&(dyn std::error::Error + 'static)> = Some(source.as_ref());
+    while let Some(cause) = next {
+        if let Some(err) = cause.downcast_ref::<E>() {
+            return Some(err);
+        }
+        next = cause.source();
+    }
+    None
+}
+
+/// Retry policy of the hand-built client, standing in for octocrab's [`RetryConfig`]. Like that
+/// one it resends a request up to `MAX_RETRIES` times straight away, but requests that aren't
+/// idempotent, such as posting a comment, are only resent when they never connected.
+#[derive(Clone)]
+pub struct RequestRetryPolicy {
+    remaining: u32,
+}
+
+impl Default for RequestRetryPolicy {
+    fn default() -> Self {
+        Self {
+            remaining: MAX_RETRIES,
+        }
+    }
+}
+
+impl<Body, ResBody> Policy<Request<Body>, Response<ResBody>, hyper_util::client::legacy::Error>
+    for RequestRetryPolicy
+where
+    Body: From<&'static str>,
+    RetryConfig: Policy<Request<Body>, Response<ResBody>, hyper_util::client::legacy::Error>,
+{
+    type Future = std::future::Ready<()>;
+
+    fn retry(
+        &mut self,
+        req: &mut Request<Body>,
+        result: &mut Result<Response<ResBody>, hyper_util::client::legacy::Error>,
+    ) -> Option<Self::Future> {
+        let idempotent = req.method().is_idempotent();
+        let retry = match result {
+            Ok(response) => {
+                idempotent
+                    && (response.status().is_server_error()
+                        || response.status() == StatusCode::TOO_MANY_REQUESTS)
+            }
+            Err(err) => idempotent || err.is_connect(),
+        };
+        if !retry || self.remaining == 0 {
+            return None;
+        }
+        self.remaining -= 1;
+        Some(std::future::ready(()))
+    }
+
+    fn clone_request(&mut self, req: &Request<Body>) -> Option<Request<Body>> {
+        // octocrab's request body type is private, so its policy does the copying. It can't copy
+        // the empty body of a GET, which is simply rebuilt.
+        if let Some(req) = RetryConfig::Simple(1).clone_request(req) {
+            return Some(req);
+        }
+        if !matches!(*req.method(), Method::GET | Method::HEAD) {
+            return None;
+        }
+        let mut copy = Request::builder()
+            .method(req.method())
+            .uri(req.uri())
+            .version(req.version())
+            .body(Body::from(""))
+            .ok()?;
+        *copy.headers_mut() = req.headers().clone();
+        Some(copy)
+    }
+}
+
+/// Whether `err` is worth retrying: a 5xx response or a failure before any response arrived.
+pub fn is_transient(err: &OctoError) -> bool {
+    match err {
+        OctoError::GitHub { source, .. } => source.status_code.is_server_error(),
+        OctoError::Service { .. } if is_timed_out(err) => false,
+        OctoError::Hyper { .. } | OctoError::Service { .. } => true,
+        _ => false,
+    }
+}
+
+async fn retry<T, E, F, Fut>(
+    mut call: F,
+    initial_backoff: Duration,
+    should_retry: impl Fn(&E) -> bool,
+) -> Result<T, E>
+where
+    E: std::fmt::Display,
+    F: FnMut() -> Fut,
+    Fut: Future<Output = Result<T, E>>,
+{
+    let mut backoff = initial_backoff;
+    let mut attempt = 0;
+    loop {
+        match call().await {
+            Err(err) if attempt < MAX_RETRIES && should_retry(&err) => {
+                attempt += 1;
+                debug!("Retrying request ({attempt}/{MAX_RETRIES}) after error: {err}");
+                tokio::time::sleep(backoff).await;
+                backoff *= 2;
+            }
+            result => return result,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{cell::Cell, time::Duration};
+
+    use super::{MAX_RETRIES, is_connect_failure, retry, timeout_after};
+    use crate::errors::AppError;
+
+    #[tokio::test]
+    async fn retries_transient_errors_until_exhausted() {
+        let calls = Cell::new(0);
+        let result: Result<(), u16> = retry(
+            || {
+                calls.set(calls.get() + 1);
+                async { Err(502) }
+            },
+            Duration::ZERO,
+            |status| *status >= 500,
+        )
+        .await;
+
+        assert_eq!(result, Err(502));
+        assert_eq!(calls.get(), MAX_RETRIES + 1);
+    }
+
+    #[tokio::test]
+    async fn returns_client_errors_and_successes_immediately() {
+        let calls = Cell::new(0);
+        let result: Result<(), u16> = retry(
+            || {
+                calls.set(calls.get() + 1);
+                async { Err(404) }
+            },
+            Duration::ZERO,
+            |status| *status >= 500,
+        )
+        .await;
+        assert_eq!(result, Err(404));
+        assert_eq!(calls.get(), 1);
+
+        let calls = Cell::new(0);
+        let result: Result<u32, u16> = retry(
+            || {
+                calls.set(calls.get() + 1);
+                let attempt = calls.get();
+                async move { if attempt < 3 { Err(503) } else { Ok(attempt) } }
+            },
+            Duration::ZERO,
+            |status| *status >= 500,
+        )
+        .await;
+        assert_eq!(result, Ok(3));
+    }
+
+    #[tokio::test]
+    async fn gives_up_on_slow_calls() {
+        let slow = timeout_after(Duration::from_millis(10), async {
+            tokio::time::sleep(Duration::from_secs(5)).await;
+            Ok::<_, AppError>(())
+        })
+        .await;
+        assert!(matches!(slow, Err(AppError::Timeout(_))));
+
+        let unlimited = timeout_after(Duration::ZERO, async { Ok::<_, AppError>(1) }).await;
+        assert_eq!(unlimited.ok(), Some(1));
+    }
+
+    #[tokio::test]
+    async fn refused_connections_count_as_unsent() {
+        // Nothing listens on port 1, so the request fails before anything is sent.
+        let crab = octocrab::Octocrab::builder()
+            .base_uri("http://127.0.0.1:1")
+            .unwrap()
+            .build()
+            .unwrap();
+        let err = crab
+            .get::<serde_json::Value, _, ()>("/rate_limit", None)
+            .await
+            .unwrap_err();
+        assert!(is_connect_failure(&err), "{err:?}");
+    }
+}
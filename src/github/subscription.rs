@@ -0,0 +1,157 @@
+use anyhow::anyhow;
+use serde_json::{Value, json};
+
+use crate::{errors::AppError, github::GithubClient};
+
+const SUBSCRIPTION_QUERY: &str = r#"
+query($owner: String!, $name: String!, $number: Int!) {
+  repository(owner: $owner, name: $name) {
+    issueOrPullRequest(number: $number) {
+      ... on Issue { id viewerSubscription }
+      ... on PullRequest { id viewerSubscription }
+    }
+  }
+}"#;
+
+const UPDATE_SUBSCRIPTION_MUTATION: &str = r#"
+mutation($id: ID!, $state: SubscriptionState!) {
+  updateSubscription(input: { subscribableId: $id, state: $state }) {
+    subscribable { viewerSubscription }
+  }
+}"#;
+
+/// Whether the authenticated user gets notifications for an issue.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SubscriptionState {
+    Subscribed,
+    Unsubscribed,
+    Ignored,
+}
+
+impl SubscriptionState {
+    fn from_graphql(value: &Value) -> Option<Self> {
+        match value.as_str()? {
+            "SUBSCRIBED" => Some(Self::Subscribed),
+            "UNSUBSCRIBED" => Some(Self::Unsubscribed),
+            "IGNORED" => Some(Self::Ignored),
+            _ => None,
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            SubscriptionState::Subscribed => "subscribed",
+            SubscriptionState::Unsubscribed => "unsubscribed",
+            SubscriptionState::Ignored => "ignored",
+        }
+    }
+}
+
+impl GithubClient {
+    /// Reads the viewer's subscription to an issue or pull request. `None` means GitHub didn't
+    /// report a state, e.g. because the viewer can't subscribe to it.
+    pub async fn issue_subscription(
+        &self,
+        owner: &str,
+        repo: &str,
+        number: u64,
+    ) -> Result<Option<SubscriptionState>, AppError> {
+        let (_, state) = self.lookup_subscribable(owner, repo, number).await?;
+        Ok(state)
+    }
+
+    /// Subscribes to or unsubscribes from an issue or pull request and returns the new state.
+    pub async fn set_issue_subscription(
+        &self,
+        owner: &str,
+        repo: &str,
+        number: u64,
+        subscribed: bool,
+    ) -> Result<Option<SubscriptionState>, AppError> {
+        let (id, _) = self.lookup_subscribable(owner, repo, number).await?;
+        let state = if subscribed {
+            "SUBSCRIBED"
+        } else {
+            "UNSUBSCRIBED"
+        };
+        let response: Value = self
+            .inner
+            .graphql(&json!({
+                "query": UPDATE_SUBSCRIPTION_MUTATION,
+                "variables": { "id": id, "state": state },
+            }))
+            .await?;
+        let data = graphql_data(response)?;
+        Ok(SubscriptionState::from_graphql(
+            &data["updateSubscription"]["subscribable"]["viewerSubscription"],
+        ))
+    }
+
+    async fn lookup_subscribable(
+        &self,
+        owner: &str,
+        repo: &str,
+        number: u64,
+    ) -> Result<(String, Option<SubscriptionState>), AppError> {
+        let response: Value = self
+            .inner
+            .graphql(&json!({
+                "query": SUBSCRIPTION_QUERY,
+                "variables": { "owner": owner, "name": repo, "number": number },
+            }))
+            .await?;
+        let data = graphql_data(response)?;
+        let node = &data["repository"]["issueOrPullRequest"];
+        let id = node["id"]
+            .as_str()
+            .ok_or_else(|| anyhow!("issue #{number} not found"))?
+            .to_string();
+        Ok((
+            id,
+            SubscriptionState::from_graphql(&node["viewerSubscription"]),
+        ))
+    }
+}
+
+/// Returns the `data` object of a GraphQL response, turning reported errors into an [`AppError`].
+fn graphql_data(mut response: Value) -> Result<Value, AppError> {
+    if let Some(errors) = response["errors"].as_array()
+        && let Some(first) = errors.first()
+    {
+        let message = first["message"].as_str().unwrap_or("unknown GraphQL error");
+        return Err(anyhow!("GitHub GraphQL error: {message}").into());
+    }
+    Ok(response["data"].take())
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::{SubscriptionState, graphql_data};
+
+    #[test]
+    fn reads_subscription_state() {
+        let data = graphql_data(json!({
+            "data": { "node": { "viewerSubscription": "SUBSCRIBED" } }
+        }))
+        .unwrap();
+
+        assert_eq!(
+            SubscriptionState::from_graphql(&data["node"]["viewerSubscription"]),
+            Some(SubscriptionState::Subscribed)
+        );
+        assert_eq!(SubscriptionState::from_graphql(&json!(null)), None);
+    }
+
+    #[test]
+    fn surfaces_graphql_errors() {
+        let err = graphql_data(json!({
+            "data": null,
+            "errors": [{ "message": "Resource not accessible by integration" }]
+        }))
+        .unwrap_err();
+
+        assert!(err.to_string().contains("Resource not accessible"));
+    }
+}
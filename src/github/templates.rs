@@ -0,0 +1,163 @@
+use octocrab::Error as OctoError;
+
+use crate::{errors::AppError, github::GithubClient};
+
+const TEMPLATE_DIR: &str = ".github/ISSUE_TEMPLATE";
+
+/// A markdown issue template from `.github/ISSUE_TEMPLATE`.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct IssueTemplate {
+    pub name: String,
+    pub about: Option<String>,
+    pub title: String,
+    pub labels: Vec<String>,
+    pub assignees: Vec<String>,
+    pub body: String,
+}
+
+impl GithubClient {
+    /// Fetches the repository's markdown issue templates. Issue forms (`.yml`) are skipped since
+    /// they describe form fields rather than a body. A missing template directory yields an empty
+    /// list.
+    pub async fn issue_templates(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> Result<Vec<IssueTemplate>, AppError> {
+        let handler = self.inner.repos(owner, repo);
+        let listing = match handler.get_content().path(TEMPLATE_DIR).send().await {
+            Ok(listing) => listing,
+            Err(OctoError::GitHub { source, .. }) if source.status_code.as_u16() == 404 => {
+                return Ok(Vec::new());
+            }
+            Err(err) => return Err(err.into()),
+        };
+
+        let mut templates = Vec::new();
+        for entry in listing.items {
+            if entry.r#type != "file" || !entry.name.to_ascii_lowercase().ends_with(".md") {
+                continue;
+            }
+            let file = handler.get_content().path(&entry.path).send().await?;
+            let Some(contents) = file.items.first().and_then(|item| item.decoded_content()) else {
+                continue;
+            };
+            templates.push(parse_issue_template(&entry.name, &contents));
+        }
+        templates.sort_by(|a, b| a.name.cmp(&b.name));
+        Ok(templates)
+    }
+}
+
+/// Splits a template into its front matter fields and body. `file_name` is used as the display
+/// name when the front matter has no `name`.
+pub fn parse_issue_template(file_name: &str, contents: &str) -> IssueTemplate {
+    let contents = contents.replace("\r\n", "\n");
+    let mut template = IssueTemplate {
+        name: file_name
+            .rsplit_once('.')
+            .map_or(file_name, |(stem, _)| stem)
+            .to_string(),
+        ..Default::default()
+    };
+
+    let Some((front_matter, body)) = split_front_matter(&contents) else {
+        template.body = contents.trim().to_string();
+        return template;
+    };
+    template.body = body.trim().to_string();
+
+    let mut list_key: Option<&str> = None;
+    for line in front_matter.lines() {
+        if let Some(item) = line.trim_start().strip_prefix("- ")
+            && let Some(key) = list_key
+        {
+            push_list_value(&mut template, key, unquote(item));
+            continue;
+        }
+        let Some((key, value)) = line.split_once(':') else {
+            continue;
+        };
+        let key = key.trim();
+        let value = value.trim();
+        list_key = value.is_empty().then_some(key);
+        match key {
+            "name" if !value.is_empty() => template.name = unquote(value).to_string(),
+            "about" if !value.is_empty() => template.about = Some(unquote(value).to_string()),
+            "title" => template.title = unquote(value).to_string(),
+            "labels" | "assignees" => {
+                let inline = value
+                    .strip_prefix('[')
+                    .and_then(|v| v.strip_suffix(']'))
+                    .unwrap_or(value);
+                for item in inline.split(',') {
+                    push_list_value(&mut template, key, unquote(item));
+                }
+            }
+            _ => {}
+        }
+    }
+    template
+}
+
+fn split_front_matter(contents: &str) -> Option<(&str, &str)> {
+    let rest = contents.strip_prefix("---\n")?;
+    if let Some(body) = rest.strip_prefix("---\n") {
+        return Some(("", body));
+    }
+    let end = rest.find("\n---")?;
+    let body = rest[end + 4..]
+        .split_once('\n')
+        .map_or("", |(_, body)| body);
+    Some((&rest[..end], body))
+}
+
+fn push_list_value(template: &mut IssueTemplate, key: &str, value: &str) {
+    if value.is_empty() {
+        return;
+    }
+    let list = match key {
+        "labels" => &mut template.labels,
+        "assignees" => &mut template.assignees,
+        _ => return,
+    };
+    list.push(value.to_string());
+}
+
+fn unquote(value: &str) -> &str {
+    let value = value.trim();
+    value
+        .strip_prefix('"')
+        .and_then(|v| v.strip_suffix('"'))
+        .or_else(|| value.strip_prefix('\'').and_then(|v| v.strip_suffix('\'')))
+        .unwrap_or(value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::parse_issue_template;
+
+    #[test]
+    fn parses_front_matter_and_body() {
+        let template = parse_issue_template(
+            "bug_report.md",
+            "---\nname: Bug report\nabout: Something broke\ntitle: \"[BUG] \"\nlabels: bug, triage\nassignees:\n  - alice\n---\n\n## Steps\n",
+        );
+
+        assert_eq!(template.name, "Bug report");
+        assert_eq!(template.about.as_deref(), Some("Something broke"));
+        assert_eq!(template.title, "[BUG] ");
+        assert_eq!(template.labels, vec!["bug", "triage"]);
+        assert_eq!(template.assignees, vec!["alice"]);
+        assert_eq!(template.body, "## Steps");
+    }
+
+    #[test]
+    fn template_without_front_matter_uses_file_name() {
+        let template = parse_issue_template("feature.md", "Describe the feature.\r\n");
+
+        assert_eq!(template.name, "feature");
+        assert!(template.title.is_empty());
+        assert_eq!(template.body, "Describe the feature.");
+    }
+}
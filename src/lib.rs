@@ -3,9 +3,12 @@ pub mod auth;
 #[cfg(feature = "benches")]
 pub mod bench_support;
 pub mod bookmarks;
+pub mod config;
 pub mod errors;
+pub mod export;
 pub mod github;
 pub mod logging;
+pub mod storage;
 pub mod ui;
 
 pub mod prelude;
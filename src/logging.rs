@@ -1,15 +1,24 @@
-use std::{env, path::PathBuf, sync::LazyLock};
+use std::{
+    env,
+    path::{Path, PathBuf},
+    sync::{LazyLock, OnceLock},
+};
 
 use directories::ProjectDirs;
+use tracing_appender::rolling::{RollingFileAppender, Rotation};
 use tracing_error::ErrorLayer;
 use tracing_subscriber::{
-    EnvFilter, Layer,
+    EnvFilter, Layer, Registry,
     fmt::{self},
     layer::SubscriberExt,
+    reload,
     util::SubscriberInitExt,
 };
 
-use crate::{app::cli::LogLevel, errors::AppError};
+use crate::{
+    app::cli::{LogLevel, LogRotation},
+    errors::AppError,
+};
 
 pub static PROJECT_NAME: LazyLock<String> =
     LazyLock::new(|| env!("CARGO_CRATE_NAME").to_uppercase().to_string());
@@ -21,6 +30,10 @@ pub static DATA_FOLDER: LazyLock<Option<PathBuf>> = LazyLock::new(|| {
 
 pub static LOG_ENV: LazyLock<String> = LazyLock::new(|| format!("{}_LOG_LEVEL", &*PROJECT_NAME));
 pub static LOG_FILE: LazyLock<String> = LazyLock::new(|| format!("{}.log", env!("CARGO_PKG_NAME")));
+pub const DEFAULT_MAX_LOG_FILES: usize = 7;
+
+/// Swaps the log file's filter while the app runs. Set once logging is initialized.
+static FILTER_HANDLE: OnceLock<reload::Handle<EnvFilter, Registry>> = OnceLock::new();
 
 pub fn get_data_dir() -> PathBuf {
     if let Some(s) = DATA_FOLDER.clone() {
@@ -40,8 +53,7 @@ pub fn init(cfg: LoggingConfig) -> Result<(), AppError> {
     //TODO: Add proper directory for logs
     let directory = get_data_dir();
     std::fs::create_dir_all(directory.clone())?;
-    let log_path = directory.join(&*LOG_FILE);
-    let log_file = std::fs::File::create(log_path)?;
+    let log_file = file_appender(&directory, &cfg)?;
 
     let env_filter = EnvFilter::builder()
         .with_default_directive(cfg.level.try_into().map_err(anyhow::Error::from)?);
@@ -52,6 +64,7 @@ pub fn init(cfg: LoggingConfig) -> Result<(), AppError> {
         .try_from_env()
         .or_else(|_| env_filter.with_env_var(LOG_ENV.clone()).from_env())
         .map_err(Into::<anyhow::Error>::into)?;
+    let (env_filter, handle) = reload::Layer::new(env_filter);
     let file_subscriber = fmt::layer()
         .with_file(true)
         .with_line_number(true)
@@ -63,17 +76,107 @@ pub fn init(cfg: LoggingConfig) -> Result<(), AppError> {
         .with(file_subscriber)
         .with(ErrorLayer::default())
         .try_init()?;
+    let _ = FILTER_HANDLE.set(handle);
+
+    Ok(())
+}
 
+/// Rotated files are named `<prefix>.<date>.log`. On unix `LOG_FILE` is kept as a symlink to the
+/// active one so there is still a stable path to tail; creating symlinks on Windows needs elevated
+/// privileges, so it is skipped there. Without rotation the active file already is `LOG_FILE`,
+/// and linking it would replace the log with a symlink to itself.
+fn file_appender(directory: &Path, cfg: &LoggingConfig) -> Result<RollingFileAppender, AppError> {
+    let builder = RollingFileAppender::builder()
+        .rotation(cfg.rotation.into())
+        .filename_prefix(env!("CARGO_PKG_NAME"))
+        .filename_suffix("log")
+        .max_log_files(cfg.max_log_files.max(1));
+    #[cfg(unix)]
+    let builder = if matches!(cfg.rotation, LogRotation::Never) {
+        builder
+    } else {
+        builder.latest_symlink(&*LOG_FILE)
+    };
+    Ok(builder.build(directory).map_err(anyhow::Error::from)?)
+}
+
+/// Replaces the log filter with `level`, overriding any directives from `RUST_LOG` or
+/// [`LOG_ENV`].
+pub fn set_level(level: LogLevel) -> Result<(), AppError> {
+    let handle = FILTER_HANDLE
+        .get()
+        .ok_or(AppError::ErrorSettingGlobal("log filter"))?;
+    let filter = EnvFilter::builder()
+        .with_default_directive(level.try_into().map_err(anyhow::Error::from)?)
+        .parse_lossy("");
+    handle.reload(filter).map_err(anyhow::Error::from)?;
     Ok(())
 }
 
 #[derive(Clone, Debug)]
 pub struct LoggingConfig {
     level: LogLevel,
+    rotation: LogRotation,
+    max_log_files: usize,
 }
 
 impl LoggingConfig {
     pub fn new(level: LogLevel) -> Self {
-        Self { level }
+        Self {
+            level,
+            rotation: LogRotation::Daily,
+            max_log_files: DEFAULT_MAX_LOG_FILES,
+        }
+    }
+
+    /// How often a new log file is started.
+    pub fn with_rotation(mut self, rotation: LogRotation) -> Self {
+        self.rotation = rotation;
+        self
+    }
+
+    /// Number of rotated log files to keep on disk; older files are deleted on rotation.
+    pub fn with_max_log_files(mut self, max_log_files: usize) -> Self {
+        self.max_log_files = max_log_files;
+        self
+    }
+}
+
+impl From<LogRotation> for Rotation {
+    fn from(value: LogRotation) -> Self {
+        match value {
+            LogRotation::Hourly => Rotation::HOURLY,
+            LogRotation::Daily => Rotation::DAILY,
+            LogRotation::Weekly => Rotation::WEEKLY,
+            LogRotation::Never => Rotation::NEVER,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Write;
+
+    use super::{LOG_FILE, LoggingConfig, file_appender};
+    use crate::app::cli::{LogLevel, LogRotation};
+
+    #[test]
+    fn never_rotating_log_stays_a_regular_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let cfg = LoggingConfig::new(LogLevel::Info).with_rotation(LogRotation::Never);
+
+        // The second start must reopen what the first one wrote.
+        for line in ["first run\n", "second run\n"] {
+            let mut appender = file_appender(dir.path(), &cfg).unwrap();
+            appender.write_all(line.as_bytes()).unwrap();
+            appender.flush().unwrap();
+        }
+
+        let path = dir.path().join(&*LOG_FILE);
+        assert!(path.symlink_metadata().unwrap().file_type().is_file());
+        assert_eq!(
+            std::fs::read_to_string(path).unwrap(),
+            "first run\nsecond run\n"
+        );
     }
 }
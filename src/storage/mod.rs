@@ -0,0 +1,331 @@
+use std::{
+    collections::HashMap,
+    path::{Path, PathBuf},
+    sync::OnceLock,
+};
+
+use serde::{Deserialize, Serialize, de::DeserializeOwned};
+use serde_json::Value;
+
+use crate::logging::get_data_dir;
+
+pub static STORAGE_DIR: OnceLock<PathBuf> = OnceLock::new();
+
+/// How many past searches are remembered.
+pub const MAX_SEARCH_HISTORY: usize = 50;
+
+const READ_MARKERS_FILE: &str = "read_markers.json";
+const DRAFTS_FILE: &str = "drafts.json";
+const SEEN_ISSUES_FILE: &str = "seen_issues.json";
+const SEARCH_HISTORY_FILE: &str = "search_history.json";
+
+/// The newest comment the user has seen in a conversation.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ReadMarker {
+    pub comment_id: u64,
+    pub created_ts: i64,
+}
+
+/// Read markers of one repository's issues.
+#[derive(Serialize, Deserialize, Debug, Default)]
+pub struct ReadMarkers(HashMap<u64, ReadMarker>);
+
+impl ReadMarkers {
+    pub fn get(&self, issue_number: u64) -> Option<ReadMarker> {
+        self.0.get(&issue_number).copied()
+    }
+
+    /// Records `marker` for the issue, never moving an existing marker backwards.
+    pub fn set(&mut self, issue_number: u64, marker: ReadMarker) -> bool {
+        match self.0.get(&issue_number) {
+            Some(existing) if existing.created_ts >= marker.created_ts => false,
+            _ => {
+                self.0.insert(issue_number, marker);
+                true
+            }
+        }
+    }
+
+    pub fn write_to_file(&self, owner: &str, repo: &str) -> std::io::Result<()> {
+        write_repo_file(get_storage_dir(), owner, repo, READ_MARKERS_FILE, self)
+    }
+}
+
+/// Unsent comment text per issue of one repository, kept across navigation and restarts.
+#[derive(Serialize, Deserialize, Debug, Default)]
+pub struct Drafts(HashMap<u64, String>);
+
+impl Drafts {
+    pub fn get(&self, issue_number: u64) -> Option<&str> {
+        self.0.get(&issue_number).map(String::as_str)
+    }
+
+    /// Stores `text` as the draft for the issue, dropping the draft when `text` is blank.
+    /// Returns whether anything changed.
+    pub fn set(&mut self, issue_number: u64, text: &str) -> bool {
+        if text.trim().is_empty() {
+            return self.remove(issue_number);
+        }
+        if self.0.get(&issue_number).is_some_and(|draft| draft == text) {
+            return false;
+        }
+        self.0.insert(issue_number, text.to_string());
+        true
+    }
+
+    pub fn remove(&mut self, issue_number: u64) -> bool {
+        self.0.remove(&issue_number).is_some()
+    }
+
+    pub fn write_to_file(&self, owner: &str, repo: &str) -> std::io::Result<()> {
+        write_repo_file(get_storage_dir(), owner, repo, DRAFTS_FILE, self)
+    }
+}
+
+/// The `updated_at` timestamp of each of one repository's issues when the user last opened it.
+#[derive(Serialize, Deserialize, Debug, Default)]
+pub struct SeenIssues(HashMap<u64, i64>);
+
+impl SeenIssues {
+    pub fn get(&self, issue_number: u64) -> Option<i64> {
+        self.0.get(&issue_number).copied()
+    }
+
+    /// Whether the issue changed since it was last opened. Issues that were never opened don't
+    /// count as updated.
+    pub fn is_updated(&self, issue_number: u64, updated_ts: i64) -> bool {
+        self.get(issue_number).is_some_and(|seen| updated_ts > seen)
+    }
+
+    /// Records that the issue was opened at `updated_ts`, never moving the stored value
+    /// backwards. Returns whether anything changed.
+    pub fn mark_seen(&mut self, issue_number: u64, updated_ts: i64) -> bool {
+        match self.0.get(&issue_number) {
+            Some(seen) if *seen >= updated_ts => false,
+            _ => {
+                self.0.insert(issue_number, updated_ts);
+                true
+            }
+        }
+    }
+
+    pub fn write_to_file(&self, owner: &str, repo: &str) -> std::io::Result<()> {
+        write_repo_file(get_storage_dir(), owner, repo, SEEN_ISSUES_FILE, self)
+    }
+}
+
+/// Searches recently run in one repository, oldest first.
+#[derive(Serialize, Deserialize, Debug, Default, Clone, PartialEq, Eq)]
+pub struct SearchHistory(Vec<String>);
+
+impl SearchHistory {
+    /// Records `search`, skipping blanks and repeats of the newest entry. Returns whether the
+    /// history changed.
+    pub fn push(&mut self, search: &str) -> bool {
+        let search = search.trim();
+        if search.is_empty() || self.0.last().is_some_and(|last| last == search) {
+            return false;
+        }
+        self.0.push(search.to_string());
+        if self.0.len() > MAX_SEARCH_HISTORY {
+            let excess = self.0.len() - MAX_SEARCH_HISTORY;
+            self.0.drain(..excess);
+        }
+        true
+    }
+
+    /// The entry `steps_back` searches before the newest one.
+    pub fn recall(&self, steps_back: usize) -> Option<&str> {
+        self.0
+            .len()
+            .checked_sub(steps_back + 1)
+            .map(|idx| self.0[idx].as_str())
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    pub fn write_to_file(&self, owner: &str, repo: &str) -> std::io::Result<()> {
+        write_repo_file(get_storage_dir(), owner, repo, SEARCH_HISTORY_FILE, self)
+    }
+}
+
+pub fn get_storage_dir() -> &'static PathBuf {
+    STORAGE_DIR.get_or_init(|| get_data_dir().join("storage"))
+}
+
+/// Directory holding the state of `owner/repo`, so working on several repositories never mixes
+/// their drafts, read markers or searches.
+pub fn repo_state_dir(owner: &str, repo: &str) -> PathBuf {
+    repo_state_dir_in(get_storage_dir(), owner, repo)
+}
+
+fn repo_state_dir_in(root: &Path, owner: &str, repo: &str) -> PathBuf {
+    root.join(owner).join(repo)
+}
+
+pub fn read_read_markers(owner: &str, repo: &str) -> ReadMarkers {
+    read_repo_file(get_storage_dir(), owner, repo, READ_MARKERS_FILE)
+}
+
+pub fn read_drafts(owner: &str, repo: &str) -> Drafts {
+    read_repo_file(get_storage_dir(), owner, repo, DRAFTS_FILE)
+}
+
+pub fn read_seen_issues(owner: &str, repo: &str) -> SeenIssues {
+    read_repo_file(get_storage_dir(), owner, repo, SEEN_ISSUES_FILE)
+}
+
+pub fn read_search_history(owner: &str, repo: &str) -> SearchHistory {
+    read_repo_file(get_storage_dir(), owner, repo, SEARCH_HISTORY_FILE)
+}
+
+/// Reads `name` from the repository's state directory. When it doesn't exist yet, the
+/// repository's share of the file of the same name directly in `root`, where state used to be
+/// kept for every repository together, is moved over instead.
+fn read_repo_file<T>(root: &Path, owner: &str, repo: &str, name: &str) -> T
+where
+    T: Serialize + DeserializeOwned + Default,
+{
+    let path = repo_state_dir_in(root, owner, repo).join(name);
+    match std::fs::read_to_string(path) {
+        Ok(contents) => serde_json::from_str(&contents).unwrap_or_default(),
+        Err(_) => migrate_flat_file(root, owner, repo, name).unwrap_or_default(),
+    }
+}
+
+fn write_repo_file<T: Serialize>(
+    root: &Path,
+    owner: &str,
+    repo: &str,
+    name: &str,
+    value: &T,
+) -> std::io::Result<()> {
+    let dir = repo_state_dir_in(root, owner, repo);
+    std::fs::create_dir_all(&dir)?;
+    let contents = serde_json::to_vec(value)?;
+    std::fs::write(dir.join(name), contents)
+}
+
+/// Moves the repository's entry out of the flat file `root/name`. The flat file is removed once
+/// no entries are left, and left untouched when the entry can't be written to its new place.
+fn migrate_flat_file<T>(root: &Path, owner: &str, repo: &str, name: &str) -> Option<T>
+where
+    T: Serialize + DeserializeOwned,
+{
+    let flat_path = root.join(name);
+    let contents = std::fs::read_to_string(&flat_path).ok()?;
+    let (value, rest) = take_flat_entry::<T>(&contents, owner, repo)?;
+    write_repo_file(root, owner, repo, name, &value).ok()?;
+    let _ = match rest {
+        // The search history used to be shared by every repository, so it moves as a whole to
+        // the first repository opened.
+        None => std::fs::remove_file(&flat_path),
+        Some(rest) if rest.is_empty() => std::fs::remove_file(&flat_path),
+        Some(rest) => serde_json::to_vec(&rest)
+            .map_err(std::io::Error::from)
+            .and_then(|contents| std::fs::write(&flat_path, contents)),
+    };
+    Some(value)
+}
+
+/// Parses a flat state file. Files keyed by `owner/repo` yield the repository's entry and the
+/// entries of every other repository; anything else is taken whole, with no remainder.
+fn take_flat_entry<T: DeserializeOwned>(
+    contents: &str,
+    owner: &str,
+    repo: &str,
+) -> Option<(T, Option<HashMap<String, Value>>)> {
+    let parsed: Value = serde_json::from_str(contents).ok()?;
+    let Value::Object(map) = parsed else {
+        return serde_json::from_value(parsed)
+            .ok()
+            .map(|value| (value, None));
+    };
+    let mut rest = map.into_iter().collect::<HashMap<_, _>>();
+    let entry = rest.remove(&format!("{owner}/{repo}"))?;
+    let value = serde_json::from_value(entry).ok()?;
+    Some((value, Some(rest)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        Drafts, MAX_SEARCH_HISTORY, ReadMarker, ReadMarkers, SearchHistory, SeenIssues,
+        take_flat_entry,
+    };
+
+    #[test]
+    fn drafts_are_kept_per_issue_and_dropped_when_blank() {
+        let mut drafts = Drafts::default();
+        assert!(drafts.set(1, "half a thought"));
+        assert!(!drafts.set(1, "half a thought"));
+        assert!(drafts.set(2, "elsewhere"));
+
+        assert_eq!(drafts.get(1), Some("half a thought"));
+        assert_eq!(drafts.get(3), None);
+
+        assert!(drafts.set(1, "  \n"));
+        assert_eq!(drafts.get(1), None);
+        assert_eq!(drafts.get(2), Some("elsewhere"));
+        assert!(!drafts.remove(1));
+    }
+
+    #[test]
+    fn seen_issues_flag_later_updates_only() {
+        let mut seen = SeenIssues::default();
+        assert!(!seen.is_updated(1, 100));
+
+        assert!(seen.mark_seen(1, 100));
+        assert!(!seen.mark_seen(1, 50));
+        assert!(!seen.is_updated(1, 100));
+        assert!(seen.is_updated(1, 101));
+        assert!(!seen.is_updated(2, 101));
+    }
+
+    #[test]
+    fn takes_the_repository_entry_out_of_flat_files() {
+        let flat = r#"{"o/r":{"7":{"comment_id":3,"created_ts":10}},"o/other":{}}"#;
+        let (markers, rest) = take_flat_entry::<ReadMarkers>(flat, "o", "r").unwrap();
+
+        assert_eq!(
+            markers.get(7),
+            Some(ReadMarker {
+                comment_id: 3,
+                created_ts: 10
+            })
+        );
+        let rest = rest.unwrap();
+        assert_eq!(rest.keys().collect::<Vec<_>>(), vec!["o/other"]);
+        assert!(take_flat_entry::<ReadMarkers>(flat, "o", "missing").is_none());
+
+        let (history, rest) = take_flat_entry::<SearchHistory>(r#"["crash"]"#, "o", "r").unwrap();
+        assert_eq!(history.recall(0), Some("crash"));
+        assert!(rest.is_none());
+    }
+
+    #[test]
+    fn search_history_skips_consecutive_duplicates_and_caps_length() {
+        let mut history = SearchHistory::default();
+        assert!(history.push("crash"));
+        assert!(!history.push(" crash "));
+        assert!(!history.push(""));
+        assert!(history.push("panic"));
+        assert!(history.push("crash"));
+
+        assert_eq!(history.recall(0), Some("crash"));
+        assert_eq!(history.recall(1), Some("panic"));
+        assert_eq!(history.recall(3), None);
+
+        for idx in 0..MAX_SEARCH_HISTORY {
+            history.push(&idx.to_string());
+        }
+        assert_eq!(history.len(), MAX_SEARCH_HISTORY);
+        assert_eq!(history.recall(MAX_SEARCH_HISTORY - 1), Some("0"));
+    }
+}
@@ -0,0 +1,484 @@
+use ratatui::{
+    buffer::Buffer,
+    crossterm::event::{Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers},
+    layout::{Constraint, Position, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, BorderType, Clear, Widget},
+};
+use textwrap::core::display_width;
+
+use crate::{
+    app::cli::LogLevel,
+    ui::{
+        Action,
+        components::{help::HelpElementKind, issue_list::MainScreen},
+        keymap::{KeyAction, keymap},
+    },
+};
+
+/// What running a palette entry does.
+#[derive(Debug, Clone)]
+pub enum PaletteCommandKind {
+    /// Send these actions on the app channel, in order.
+    Dispatch(Vec<Action>),
+    /// Focus the component registered under this index (the same as pressing the digit).
+    Focus(u8),
+    /// Toggle the help overlay.
+    ToggleHelp,
+    /// Replay a key press, as if the user had typed the binding themselves.
+    Key(KeyEvent),
+}
+
+#[derive(Debug, Clone)]
+pub struct PaletteCommand {
+    pub label: String,
+    pub binding: String,
+    pub kind: PaletteCommandKind,
+}
+
+impl PaletteCommand {
+    fn new(label: impl Into<String>, binding: impl Into<String>, kind: PaletteCommandKind) -> Self {
+        Self {
+            label: label.into(),
+            binding: binding.into(),
+            kind,
+        }
+    }
+}
+
+pub enum PaletteOutcome {
+    Continue,
+    Close,
+    Run(PaletteCommandKind),
+}
+
+pub struct CommandPalette {
+    query: String,
+    commands: Vec<PaletteCommand>,
+    filtered: Vec<usize>,
+    selected: usize,
+}
+
+impl CommandPalette {
+    /// Builds a palette from the global commands plus every key binding in `help` that maps to a
+    /// single key press.
+    pub fn new(help: Option<&'static [HelpElementKind]>) -> Self {
+        let mut commands = global_commands();
+        if let Some(help) = help {
+            commands.extend(help_commands(help));
+        }
+        let mut palette = Self {
+            query: String::new(),
+            filtered: Vec::new(),
+            commands,
+            selected: 0,
+        };
+        palette.refilter();
+        palette
+    }
+
+    /// Whether `event` should open the palette.
+    pub fn is_trigger(event: &Event) -> bool {
+        let Event::Key(key) = event else {
+            return false;
+        };
+        if key.kind != KeyEventKind::Press {
+            return false;
+        }
+        let ctrl_shift_p = matches!(key.code, KeyCode::Char('p' | 'P'))
+            && key
+                .modifiers
+                .contains(KeyModifiers::CONTROL | KeyModifiers::SHIFT);
+        let colon = key.code == KeyCode::Char(':')
+            && !key
+                .modifiers
+                .intersects(KeyModifiers::CONTROL | KeyModifiers::ALT);
+        ctrl_shift_p || colon
+    }
+
+    pub fn handle_event(&mut self, event: &Event) -> PaletteOutcome {
+        match event {
+            Event::Key(key) if key.kind == KeyEventKind::Press => self.handle_key(key),
+            Event::Paste(text) => {
+                self.query.push_str(text.trim());
+                self.refilter();
+                PaletteOutcome::Continue
+            }
+            _ => PaletteOutcome::Continue,
+        }
+    }
+
+    fn handle_key(&mut self, key: &KeyEvent) -> PaletteOutcome {
+        match key.code {
+            KeyCode::Esc => return PaletteOutcome::Close,
+            KeyCode::Enter => {
+                return match self.selected_command() {
+                    Some(command) => PaletteOutcome::Run(command.kind.clone()),
+                    None => PaletteOutcome::Continue,
+                };
+            }
+            KeyCode::Up => self.select_prev(),
+            KeyCode::Down | KeyCode::Tab => self.select_next(),
+            KeyCode::BackTab => self.select_prev(),
+            KeyCode::Char('p') if key.modifiers == KeyModifiers::CONTROL => self.select_prev(),
+            KeyCode::Char('n') if key.modifiers == KeyModifiers::CONTROL => self.select_next(),
+            KeyCode::Backspace => {
+                self.query.pop();
+                self.refilter();
+            }
+            KeyCode::Char(c)
+                if !key
+                    .modifiers
+                    .intersects(KeyModifiers::CONTROL | KeyModifiers::ALT) =>
+            {
+                self.query.push(c);
+                self.refilter();
+            }
+            _ => {}
+        }
+        PaletteOutcome::Continue
+    }
+
+    fn select_next(&mut self) {
+        if !self.filtered.is_empty() {
+            self.selected = (self.selected + 1) % self.filtered.len();
+        }
+    }
+
+    fn select_prev(&mut self) {
+        if !self.filtered.is_empty() {
+            self.selected = self
+                .selected
+                .checked_sub(1)
+                .unwrap_or(self.filtered.len() - 1);
+        }
+    }
+
+    fn selected_command(&self) -> Option<&PaletteCommand> {
+        self.filtered
+            .get(self.selected)
+            .and_then(|idx| self.commands.get(*idx))
+    }
+
+    fn refilter(&mut self) {
+        let mut scored = self
+            .commands
+            .iter()
+            .enumerate()
+            .filter_map(|(idx, command)| {
+                let label_score = fuzzy_score(&self.query, &command.label);
+                let binding_score = fuzzy_score(&self.query, &command.binding);
+                label_score.max(binding_score).map(|score| (score, idx))
+            })
+            .collect::<Vec<_>>();
+        // Stable sort keeps the declaration order for equal scores.
+        scored.sort_by_key(|(score, _)| std::cmp::Reverse(*score));
+        self.filtered = scored.into_iter().map(|(_, idx)| idx).collect();
+        self.selected = 0;
+    }
+
+    fn popup_area(area: Rect) -> Rect {
+        area.centered(Constraint::Percentage(60), Constraint::Percentage(60))
+    }
+
+    pub fn cursor(&self, area: Rect) -> Position {
+        let popup = Self::popup_area(area);
+        Position {
+            x: (popup.x + 3 + display_width(&self.query) as u16)
+                .min(popup.right().saturating_sub(2)),
+            y: popup.y + 1,
+        }
+    }
+
+    pub fn render(&self, area: Rect, buf: &mut Buffer) {
+        let popup = Self::popup_area(area);
+        Clear.render(popup, buf);
+        let block = Block::bordered()
+            .border_type(BorderType::Rounded)
+            .title("Command Palette")
+            .title_bottom("Enter: run | Up/Down: select | Esc: close");
+        let inner = block.inner(popup);
+        block.render(popup, buf);
+        if inner.height == 0 {
+            return;
+        }
+
+        Line::from(vec![
+            Span::styled("> ", Style::new().fg(Color::Cyan).bold()),
+            Span::raw(self.query.clone()),
+        ])
+        .render(Rect { height: 1, ..inner }, buf);
+
+        let list_area = Rect {
+            y: inner.y + 1,
+            height: inner.height.saturating_sub(1),
+            ..inner
+        };
+        if self.filtered.is_empty() {
+            Line::from(Span::styled("No matching commands", Style::new().dim())).render(
+                Rect {
+                    height: 1,
+                    ..list_area
+                },
+                buf,
+            );
+            return;
+        }
+
+        let visible = list_area.height as usize;
+        let offset = self.selected.saturating_sub(visible.saturating_sub(1));
+        for (row, idx) in self.filtered.iter().enumerate().skip(offset).take(visible) {
+            let Some(command) = self.commands.get(*idx) else {
+                continue;
+            };
+            let y = list_area.y + (row - offset) as u16;
+            let binding_width = display_width(&command.binding);
+            let label_width = (list_area.width as usize).saturating_sub(binding_width + 1);
+            let padding = label_width.saturating_sub(display_width(&command.label));
+            let mut line = Line::from(vec![
+                Span::raw(command.label.clone()),
+                Span::raw(" ".repeat(padding + 1)),
+                Span::styled(
+                    command.binding.clone(),
+                    Style::new().fg(Color::Cyan).add_modifier(Modifier::BOLD),
+                ),
+            ]);
+            if row == self.selected {
+                line = line.style(Style::new().add_modifier(Modifier::REVERSED));
+            }
+            line.render(
+                Rect {
+                    y,
+                    height: 1,
+                    ..list_area
+                },
+                buf,
+            );
+        }
+    }
+}
+
+fn global_commands() -> Vec<PaletteCommand> {
+    use PaletteCommandKind::*;
+    let log_levels = [
+        LogLevel::Trace,
+        LogLevel::Debug,
+        LogLevel::Info,
+        LogLevel::Warn,
+        LogLevel::Error,
+        LogLevel::None,
+    ]
+    .map(|level| {
+        PaletteCommand::new(
+            format!("Log level: {level}"),
+            "",
+            Dispatch(vec![Action::SetLogLevel(level)]),
+        )
+    });
+    let mut commands = vec![
+        PaletteCommand::new("Focus Search Bar", "1", Focus(1)),
+        PaletteCommand::new("Focus Issue List", "2", Focus(2)),
+        PaletteCommand::new("Focus Issue Conversation", "3", Focus(3)),
+        PaletteCommand::new("Focus Label List", "4", Focus(4)),
+        PaletteCommand::new("Focus Issue Create", "5", Focus(5)),
+        PaletteCommand::new(
+            "New issue",
+            "n",
+            Dispatch(vec![
+                Action::EnterIssueCreate,
+                Action::ChangeIssueScreen(MainScreen::CreateIssue),
+            ]),
+        ),
+        PaletteCommand::new(
+            "Back to issue list",
+            "Esc",
+            Dispatch(vec![Action::ChangeIssueScreen(MainScreen::List)]),
+        ),
+        PaletteCommand::new(
+            "Refresh issue list",
+            "",
+            Dispatch(vec![Action::RefreshIssueList]),
+        ),
+        PaletteCommand::new(
+            "Reload repository labels",
+            keymap().describe(KeyAction::ReloadLabels),
+            Dispatch(vec![Action::ReloadRepoLabels]),
+        ),
+        PaletteCommand::new(
+            "Export search results to CSV",
+            "Alt+E",
+            Dispatch(vec![Action::ExportSearchCsv]),
+        ),
+        PaletteCommand::new("Toggle help", "?", ToggleHelp),
+        PaletteCommand::new("Quit", "q / Ctrl+C", Dispatch(vec![Action::Quit])),
+    ];
+    commands.extend(log_levels);
+    commands
+}
+
+fn help_commands(help: &'static [HelpElementKind]) -> impl Iterator<Item = PaletteCommand> {
+    help.iter().filter_map(|element| {
+        let key = match *element {
+            HelpElementKind::Keybind(binding, _) => parse_binding(binding)?,
+            HelpElementKind::Action(action, _) => keymap().keys(action).first()?.key_event(),
+            HelpElementKind::Text(_) => return None,
+        };
+        let (binding, description) = element.keybind()?;
+        let mut label = description.to_string();
+        if let Some(first) = label.get_mut(0..1) {
+            first.make_ascii_uppercase();
+        }
+        Some(PaletteCommand::new(
+            label,
+            binding,
+            PaletteCommandKind::Key(key),
+        ))
+    })
+}
+
+/// Parses the first alternative of a help binding such as `Ctrl+Enter / Alt+Enter` into a key
+/// press. Bindings that describe several keys (`Up/Down`) or free-form input (`Type`) are
+/// rejected.
+fn parse_binding(binding: &str) -> Option<KeyEvent> {
+    let first = binding.split(" / ").next()?.trim();
+    if first.contains(' ') {
+        return None;
+    }
+    let mut modifiers = KeyModifiers::NONE;
+    let mut rest = first;
+    loop {
+        if let Some(stripped) = rest.strip_prefix("Ctrl+") {
+            modifiers |= KeyModifiers::CONTROL;
+            rest = stripped;
+        } else if let Some(stripped) = rest.strip_prefix("Alt+") {
+            modifiers |= KeyModifiers::ALT;
+            rest = stripped;
+        } else if let Some(stripped) = rest.strip_prefix("Shift+") {
+            modifiers |= KeyModifiers::SHIFT;
+            rest = stripped;
+        } else {
+            break;
+        }
+    }
+    let code = match rest {
+        "Enter" => KeyCode::Enter,
+        "Esc" => KeyCode::Esc,
+        "Tab" => KeyCode::Tab,
+        "Backspace" => KeyCode::Backspace,
+        "Delete" => KeyCode::Delete,
+        "Home" => KeyCode::Home,
+        "End" => KeyCode::End,
+        "PageUp" => KeyCode::PageUp,
+        "PageDown" => KeyCode::PageDown,
+        "Up" => KeyCode::Up,
+        "Down" => KeyCode::Down,
+        "Left" => KeyCode::Left,
+        "Right" => KeyCode::Right,
+        other => {
+            let mut chars = other.chars();
+            let c = chars.next()?;
+            if chars.next().is_some() {
+                return None;
+            }
+            if modifiers.contains(KeyModifiers::CONTROL) {
+                KeyCode::Char(c.to_ascii_lowercase())
+            } else {
+                if c.is_ascii_uppercase() {
+                    modifiers |= KeyModifiers::SHIFT;
+                }
+                KeyCode::Char(c)
+            }
+        }
+    };
+    Some(KeyEvent::new(code, modifiers))
+}
+
+/// Case-insensitive subsequence match. Consecutive matches and matches at word starts score
+/// higher; `None` means `query` is not a subsequence of `candidate`.
+fn fuzzy_score(query: &str, candidate: &str) -> Option<i64> {
+    if query.is_empty() {
+        return Some(0);
+    }
+    let mut score = 0_i64;
+    let mut query_chars = query.chars().flat_map(char::to_lowercase).peekable();
+    let mut prev_matched = false;
+    let mut prev_char: Option<char> = None;
+    for c in candidate.chars() {
+        let Some(&wanted) = query_chars.peek() else {
+            break;
+        };
+        let lower = c.to_lowercase().next().unwrap_or(c);
+        if lower == wanted {
+            query_chars.next();
+            score += 1;
+            if prev_matched {
+                score += 3;
+            }
+            if prev_char.is_none_or(|p| !p.is_alphanumeric()) {
+                score += 5;
+            }
+            prev_matched = true;
+        } else {
+            prev_matched = false;
+        }
+        prev_char = Some(c);
+    }
+    if query_chars.peek().is_some() {
+        return None;
+    }
+    Some(score)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{PaletteCommandKind, fuzzy_score, help_commands, parse_binding};
+    use crate::ui::components::help::HelpElementKind;
+    use ratatui::crossterm::event::{KeyCode, KeyModifiers};
+
+    #[test]
+    fn fuzzy_score_requires_subsequence() {
+        assert!(fuzzy_score("nwi", "New issue").is_some());
+        assert!(fuzzy_score("issue new", "New issue").is_none());
+        assert!(fuzzy_score("NEW", "new issue").is_some());
+    }
+
+    #[test]
+    fn fuzzy_score_prefers_word_starts() {
+        let word_start = fuzzy_score("tt", "toggle timeline").unwrap();
+        let inner = fuzzy_score("tt", "attach").unwrap();
+        assert!(word_start > inner);
+    }
+
+    #[test]
+    fn parses_single_key_bindings() {
+        let key = parse_binding("Ctrl+Enter / Alt+Enter").unwrap();
+        assert_eq!(key.code, KeyCode::Enter);
+        assert_eq!(key.modifiers, KeyModifiers::CONTROL);
+
+        let key = parse_binding("C").unwrap();
+        assert_eq!(key.code, KeyCode::Char('C'));
+        assert_eq!(key.modifiers, KeyModifiers::SHIFT);
+
+        assert!(parse_binding("Up/Down").is_none());
+        assert!(parse_binding("Enter (popup)").is_none());
+        assert!(parse_binding("Type hex").is_none());
+    }
+
+    #[test]
+    fn action_entries_replay_the_keymap_binding() {
+        const HELP: &[HelpElementKind] = &[
+            crate::help_text!("Help"),
+            crate::help_action!(SendComment, "send comment"),
+        ];
+        let commands: Vec<_> = help_commands(HELP).collect();
+        assert_eq!(commands.len(), 1);
+        assert_eq!(commands[0].label, "Send comment");
+        assert_eq!(commands[0].binding, "Ctrl+Enter / Alt+Enter");
+        let PaletteCommandKind::Key(key) = commands[0].kind else {
+            panic!("expected a key replay");
+        };
+        assert_eq!(key.code, KeyCode::Enter);
+        assert_eq!(key.modifiers, KeyModifiers::CONTROL);
+    }
+}
@@ -0,0 +1,128 @@
+use std::error::Error;
+
+use ratatui::{
+    buffer::Buffer,
+    layout::{Constraint, Rect},
+    style::{Modifier, Style},
+    text::{Line, Text},
+    widgets::{Block, BorderType, Clear, Padding, Widget},
+};
+
+use crate::ui::Action;
+
+/// Overlay showing an error together with the chain of errors that caused it, centered over
+/// the UI the same way as the help overlay.
+#[derive(Debug, Clone)]
+pub struct ErrorPanel {
+    message: String,
+    causes: Vec<String>,
+    retry: Option<Action>,
+}
+
+impl ErrorPanel {
+    /// `retry` is sent again when the user asks to retry from the panel.
+    pub fn new(error: &(dyn Error + 'static), retry: Option<Action>) -> Self {
+        let (message, causes) = error_chain(error);
+        Self {
+            message,
+            causes,
+            retry,
+        }
+    }
+
+    pub fn from_message(message: impl Into<String>) -> Self {
+        Self {
+            message: message.into(),
+            causes: Vec::new(),
+            retry: None,
+        }
+    }
+
+    /// Takes the action to rerun, if the failure can be retried.
+    pub fn take_retry(&mut self) -> Option<Action> {
+        self.retry.take()
+    }
+
+    fn text(&self, width: usize) -> Text<'static> {
+        let mut lines: Vec<Line<'static>> = textwrap::wrap(&self.message, width)
+            .into_iter()
+            .map(|line| Line::from(line.into_owned()))
+            .collect();
+        if !self.causes.is_empty() {
+            lines.push(Line::default());
+            lines.push(Line::styled(
+                "Caused by:",
+                Style::new().add_modifier(Modifier::BOLD | Modifier::DIM),
+            ));
+            let options = textwrap::Options::new(width)
+                .initial_indent("  - ")
+                .subsequent_indent("    ");
+            for cause in &self.causes {
+                lines.extend(
+                    textwrap::wrap(cause, &options)
+                        .into_iter()
+                        .map(|line| Line::styled(line.into_owned(), Style::new().dim())),
+                );
+            }
+        }
+        Text::from(lines)
+    }
+}
+
+impl Widget for &ErrorPanel {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let hint = if self.retry.is_some() {
+            "r: retry · Esc/Enter: dismiss"
+        } else {
+            "Esc/Enter: dismiss"
+        };
+        let block = Block::bordered()
+            .title("Error")
+            .title_bottom(hint)
+            .padding(Padding::horizontal(1))
+            .border_type(BorderType::Rounded);
+        let width = area.width * 6 / 10;
+        // Borders and padding take two columns on each side.
+        let text = self.text(width.saturating_sub(4).max(1) as usize);
+        let height = (text.height() as u16 + 2).min(area.height);
+        let popup_area = area.centered(Constraint::Length(width), Constraint::Length(height));
+        let inner = block.inner(popup_area);
+        Clear.render(popup_area, buf);
+        block.render(popup_area, buf);
+        text.render(inner, buf);
+    }
+}
+
+/// Splits `error` into its own message and the messages of its sources, dropping sources that
+/// repeat the message above them, as transparent wrapper errors do.
+fn error_chain(error: &(dyn Error + 'static)) -> (String, Vec<String>) {
+    let message = error.to_string();
+    let mut causes: Vec<String> = Vec::new();
+    let mut source = error.source();
+    while let Some(err) = source {
+        let cause = err.to_string();
+        if causes.last().unwrap_or(&message) != &cause {
+            causes.push(cause);
+        }
+        source = err.source();
+    }
+    (message, causes)
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::anyhow;
+
+    use super::error_chain;
+    use crate::errors::AppError;
+
+    #[test]
+    fn lists_each_distinct_cause() {
+        let error: AppError = anyhow!("connection refused")
+            .context("failed to load issues")
+            .into();
+        let (message, causes) = error_chain(&error);
+        assert_eq!(message, "failed to load issues");
+        assert_eq!(causes, vec!["connection refused".to_string()]);
+    }
+}
@@ -3,14 +3,32 @@ use ratatui::{
     text::{Line, Span, Text},
     widgets::{BlockExt, Clear, Widget},
 };
+use std::borrow::Cow;
 use tracing::trace;
 
+use crate::ui::keymap::{KeyAction, keymap};
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum HelpElementKind {
     Keybind(&'static str, &'static str),
+    /// A rebindable action, listed with the keys the `[keybindings]` config gives it.
+    Action(KeyAction, &'static str),
     Text(&'static str),
 }
 
+impl HelpElementKind {
+    /// The keys and description of a key binding, or `None` for text.
+    pub fn keybind(&self) -> Option<(Cow<'static, str>, &'static str)> {
+        match *self {
+            HelpElementKind::Keybind(key, description) => Some((Cow::Borrowed(key), description)),
+            HelpElementKind::Action(action, description) => {
+                Some((Cow::Owned(keymap().describe(action)), description))
+            }
+            HelpElementKind::Text(_) => None,
+        }
+    }
+}
+
 #[macro_export]
 macro_rules! help_keybind {
     ($key:expr, $description:expr) => {
@@ -18,6 +36,16 @@ macro_rules! help_keybind {
     };
 }
 
+#[macro_export]
+macro_rules! help_action {
+    ($action:ident, $description:expr) => {
+        $crate::ui::components::help::HelpElementKind::Action(
+            $crate::ui::keymap::KeyAction::$action,
+            $description,
+        )
+    };
+}
+
 #[macro_export]
 macro_rules! help_text {
     ($text:expr) => {
@@ -28,8 +56,8 @@ macro_rules! help_text {
 pub fn help_elements_to_text(elements: &[HelpElementKind], width: u16) -> Text<'static> {
     let mut lines = Vec::with_capacity(elements.len());
     for element in elements {
-        match element {
-            HelpElementKind::Keybind(key, description) => {
+        match element.keybind() {
+            Some((key, description)) => {
                 let total_length = (key.len() + description.len()) as u16; // +1 for the space between
                 let padding = if total_length < width {
                     width - total_length
@@ -38,14 +66,17 @@ pub fn help_elements_to_text(elements: &[HelpElementKind], width: u16) -> Text<'
                 };
                 lines.push(Line::from(vec![
                     Span::styled(
-                        *key,
+                        key,
                         Style::new().fg(Color::Cyan).add_modifier(Modifier::BOLD),
                     ),
                     Span::raw(" ".repeat(padding as usize)),
-                    Span::raw(*description),
+                    Span::raw(description),
                 ]));
             }
-            HelpElementKind::Text(text) => {
+            None => {
+                let HelpElementKind::Text(text) = element else {
+                    continue;
+                };
                 let wrapped = textwrap::wrap(text, width as usize);
                 lines.extend(wrapped.into_iter().map(|line| Line::from(line).centered()));
             }
@@ -54,10 +85,35 @@ pub fn help_elements_to_text(elements: &[HelpElementKind], width: u16) -> Text<'
     Text::from(lines)
 }
 
+/// Formats `elements` as fixed lines for [`Component::help_lines`](super::Component::help_lines),
+/// with descriptions aligned after the longest key.
+pub fn keybind_lines(elements: &[HelpElementKind]) -> Vec<Line<'static>> {
+    let key_width = elements
+        .iter()
+        .filter_map(|element| element.keybind().map(|(key, _)| key.len()))
+        .max()
+        .unwrap_or(0);
+    elements
+        .iter()
+        .map(|element| match (element.keybind(), element) {
+            (Some((key, description)), _) => Line::from(vec![
+                Span::styled(
+                    format!("{key:<key_width$}  "),
+                    Style::new().fg(Color::Cyan).add_modifier(Modifier::BOLD),
+                ),
+                Span::raw(description),
+            ]),
+            (None, HelpElementKind::Text(text)) => Line::from(*text).centered(),
+            (None, _) => Line::default(),
+        })
+        .collect()
+}
+
 /// A simple component to display help information. It can be centered within its parent area using the `set_constraints` method.
 pub struct HelpComponent<'a> {
     constraint: u16,
     content: &'a [HelpElementKind],
+    lines: Option<Vec<Line<'a>>>,
     block: Option<ratatui::widgets::Block<'a>>,
     width: u16,
 }
@@ -67,6 +123,7 @@ impl<'a> HelpComponent<'a> {
     pub fn new(content: &'a [HelpElementKind]) -> Self {
         Self {
             content,
+            lines: None,
             width: 0,
             constraint: 0,
             block: None,
@@ -76,6 +133,13 @@ impl<'a> HelpComponent<'a> {
     pub fn set_constraint(self, constraint: u16) -> Self {
         Self { constraint, ..self }
     }
+    /// Shows `lines` as they are instead of the help elements.
+    pub fn lines(self, lines: Vec<Line<'a>>) -> Self {
+        Self {
+            lines: Some(lines),
+            ..self
+        }
+    }
     /// Sets a block around the component. This can be used to visually separate the help content from other UI elements.
     pub fn block(self, block: ratatui::widgets::Block<'a>) -> Self {
         Self {
@@ -97,7 +161,10 @@ impl<'a> Widget for HelpComponent<'a> {
         };
         let mut inner = self.block.inner_if_some(centered_area);
         self.width = inner.width;
-        let text = help_elements_to_text(self.content, self.width);
+        let text = match self.lines.take() {
+            Some(lines) => Text::from(lines),
+            None => help_elements_to_text(self.content, self.width),
+        };
         let text_height = text.height() as u16;
         let y_offset = |h: u16| {
             if text_height < h {
@@ -1,12 +1,19 @@
 use async_trait::async_trait;
-use crossterm::event;
+use crossterm::{
+    event::{self, DisableMouseCapture, EnableMouseCapture},
+    execute,
+};
 use futures::{StreamExt, stream};
 use octocrab::models::{
-    CommentId, Event as IssueEvent, IssueState, issues::Comment as ApiComment,
-    reactions::ReactionContent, timelines::TimelineEvent,
+    CommentId, Event as IssueEvent, IssueState,
+    issues::Comment as ApiComment,
+    reactions::ReactionContent,
+    repos::{DiffEntry, DiffEntryStatus},
+    timelines::TimelineEvent,
 };
 use pulldown_cmark::{
-    BlockQuoteKind, CodeBlockKind, Event as MdEvent, Options, Parser, Tag, TagEnd, TextMergeStream,
+    Alignment, BlockQuoteKind, CodeBlockKind, Event as MdEvent, HeadingLevel, Options, Parser, Tag,
+    TagEnd, TextMergeStream,
 };
 use rat_cursor::HasScreenCursor;
 use rat_widget::{
@@ -14,19 +21,31 @@ use rat_widget::{
     focus::{FocusBuilder, FocusFlag, HasFocus, Navigation},
     list::{ListState, selection::RowSelection},
     paragraph::{Paragraph, ParagraphState},
+    text::TextPosition,
+    text_input::{TextInput, TextInputState},
     textarea::{TextArea, TextAreaState, TextWrap},
 };
 use ratatui::{
     buffer::Buffer,
+    layout::Constraint,
     layout::Rect,
     style::{Color, Modifier, Style, Stylize},
     text::{Line, Span, Text},
-    widgets::{self, Block, ListItem, StatefulWidget, Widget},
+    widgets::{
+        self, Block, Clear, List as TuiList, ListItem, ListState as TuiListState, StatefulWidget,
+        Widget,
+    },
 };
 use ratatui_macros::{horizontal, line, span, vertical};
 use std::{
+    borrow::Cow,
     collections::{HashMap, HashSet},
-    sync::{Arc, OnceLock, RwLock},
+    io::stdout,
+    sync::{
+        Arc, Mutex, OnceLock, RwLock,
+        atomic::{AtomicUsize, Ordering},
+    },
+    time::{Duration, Instant},
 };
 use syntect::{
     easy::HighlightLines,
@@ -35,22 +54,26 @@ use syntect::{
 };
 use textwrap::{core::display_width, wrap};
 use throbber_widgets_tui::{BRAILLE_SIX_DOUBLE, Throbber, ThrobberState, WhichUse};
-use tracing::trace;
+use tracing::{debug, error, trace};
 
 use crate::{
-    app::GITHUB_CLIENT,
-    errors::AppError,
+    app::client,
+    errors::{AppError, describe_request_error},
+    github::{SubscriptionState, with_connect_retry, with_retry},
+    storage::{Drafts, ReadMarker, ReadMarkers, read_drafts, read_read_markers},
     ui::{
-        Action,
+        Action, LockIssueReason,
         components::{
             Component,
-            help::HelpElementKind,
+            help::{HelpElementKind, keybind_lines},
             issue_list::{IssueClosePopupState, MainScreen, render_issue_close_popup},
+            status_bar::rate_limit_resets_in,
         },
         issue_data::{UiIssue, UiIssuePool},
+        keymap::{KeyAction, list_motion, pressed},
         layout::Layout,
         toast_action,
-        utils::get_border_style,
+        utils::{get_border_style, normalize_paste, relative_time, unix_now},
     },
 };
 use anyhow::anyhow;
@@ -59,21 +82,104 @@ use ratatui_toaster::{ToastPosition, ToastType};
 
 pub const HELP: &[HelpElementKind] = &[
     crate::help_text!("Issue Conversation Help"),
-    crate::help_keybind!("Up/Down", "select issue body/comment entry"),
+    crate::help_keybind!("Up/Down, j/k", "select issue body/comment entry"),
+    crate::help_keybind!(
+        "Enter/Space",
+        "expand a long comment (Space collapses it again)"
+    ),
+    crate::help_action!(
+        ToggleDetails,
+        "fold or unfold the selected message's <details> sections"
+    ),
+    crate::help_keybind!("g/G", "select the issue body / last comment"),
     crate::help_keybind!("PageUp/PageDown/Home/End", "scroll message body pane"),
     crate::help_keybind!("t", "toggle timeline events"),
+    crate::help_keybind!("T", "toggle relative/absolute timestamps"),
     crate::help_keybind!("f", "toggle fullscreen body view"),
     crate::help_keybind!("C", "close selected issue"),
+    crate::help_action!(
+        ToggleIssueState,
+        "close or reopen the issue (asks to confirm)"
+    ),
+    crate::help_action!(
+        EditAssignees,
+        "edit assignees (type logins + Enter adds, Del on empty input removes)"
+    ),
+    crate::help_action!(SetMilestone, "set or clear the milestone"),
+    crate::help_action!(
+        OpenInBrowser,
+        "open the selected comment or the issue in a browser"
+    ),
+    crate::help_action!(
+        CopyMessage,
+        "copy the selected comment's or issue's markdown"
+    ),
+    crate::help_keybind!("L", "lock conversation (pick a reason)"),
+    crate::help_keybind!("Alt+L", "unlock conversation"),
     crate::help_keybind!("l", "copy link to selected message"),
     crate::help_keybind!("Enter (popup)", "confirm close reason"),
-    crate::help_keybind!("Ctrl+P", "toggle comment input/preview"),
-    crate::help_keybind!("e", "edit selected comment in external editor"),
-    crate::help_keybind!("r", "add reaction to selected comment"),
-    crate::help_keybind!("R", "remove reaction from selected comment"),
-    crate::help_keybind!("Ctrl+Enter / Alt+Enter", "send comment"),
+    crate::help_action!(
+        TogglePreview,
+        "show/hide a rendered preview beside the comment input"
+    ),
+    crate::help_action!(EditComment, "edit your selected comment in the input"),
+    crate::help_action!(
+        EditIssue,
+        "edit the issue title (first line) and body in the input"
+    ),
+    crate::help_action!(
+        ExternalEditComment,
+        "edit selected comment in external editor"
+    ),
+    crate::help_action!(
+        DeleteComment,
+        "delete your selected comment (y/n to confirm)"
+    ),
+    crate::help_action!(AddReaction, "add reaction to selected comment"),
+    crate::help_action!(QuoteComment, "quote the selected comment into the reply"),
+    crate::help_action!(RemoveReaction, "remove reaction from selected comment"),
+    crate::help_keybind!("u", "filter comments by author (Tab: only/hide)"),
+    crate::help_keybind!("U", "clear author filter"),
+    crate::help_keybind!("n", "go to oldest unread comment"),
+    crate::help_action!(ReloadComments, "reload comments"),
+    crate::help_keybind!("s", "subscribe to / unsubscribe from the issue"),
+    crate::help_keybind!(
+        "p",
+        "pin/unpin selected issue body or comment above the list"
+    ),
+    crate::help_keybind!(
+        "@",
+        "suggest participants (Up/Down, Enter/Tab inserts, Esc dismisses)"
+    ),
+    crate::help_action!(SendComment, "send comment"),
     crate::help_keybind!("Esc", "exit fullscreen / return to issue list"),
 ];
 
+/// Keys that apply while the comment input has focus.
+const COMPOSER_HELP: &[HelpElementKind] = &[
+    crate::help_text!("Comment Input Help"),
+    crate::help_action!(SendComment, "send comment"),
+    crate::help_action!(
+        TogglePreview,
+        "show/hide a rendered preview beside the comment input"
+    ),
+    crate::help_keybind!(
+        "@",
+        "suggest participants (Up/Down, Enter/Tab inserts, Esc dismisses)"
+    ),
+    crate::help_keybind!("Tab / Shift+Tab", "leave the input"),
+    crate::help_keybind!("Esc", "return to issue list"),
+];
+
+pub const DEFAULT_TAB_WIDTH: usize = 4;
+/// Composer length past which the character count turns red.
+pub const DEFAULT_COMMENT_WARN_CHARS: usize = 60_000;
+/// Longest comment or issue body GitHub accepts.
+const MAX_COMMENT_CHARS: usize = 65_536;
+
+/// Number of columns a tab advances to inside fenced and indented code blocks.
+pub static CODE_TAB_WIDTH: AtomicUsize = AtomicUsize::new(DEFAULT_TAB_WIDTH);
+
 struct SyntectAssets {
     syntaxes: SyntaxSet,
     theme: Theme,
@@ -101,8 +207,15 @@ pub struct IssueConversationSeed {
     pub author: Arc<str>,
     pub created_at: Arc<str>,
     pub created_ts: i64,
+    pub updated_ts: i64,
     pub body: Option<Arc<str>>,
     pub title: Option<Arc<str>>,
+    pub is_pull_request: bool,
+    pub locked: bool,
+    pub state: IssueState,
+    pub assignees: Vec<Arc<str>>,
+    pub milestone: Option<Arc<str>>,
+    pub html_url: Arc<str>,
 }
 
 impl IssueConversationSeed {
@@ -112,8 +225,22 @@ impl IssueConversationSeed {
             author: Arc::<str>::from(issue.user.login.as_str()),
             created_at: Arc::<str>::from(issue.created_at.format("%Y-%m-%d %H:%M").to_string()),
             created_ts: issue.created_at.timestamp(),
+            updated_ts: issue.updated_at.timestamp(),
             body: issue.body.as_ref().map(|b| Arc::<str>::from(b.as_str())),
             title: Some(Arc::<str>::from(issue.title.as_str())),
+            is_pull_request: issue.pull_request.is_some(),
+            locked: issue.locked,
+            state: issue.state.clone(),
+            assignees: issue
+                .assignees
+                .iter()
+                .map(|a| Arc::<str>::from(a.login.as_str()))
+                .collect(),
+            milestone: issue
+                .milestone
+                .as_ref()
+                .map(|m| Arc::<str>::from(m.title.as_str())),
+            html_url: Arc::<str>::from(issue.html_url.as_str()),
         }
     }
 
@@ -123,20 +250,114 @@ impl IssueConversationSeed {
             author: Arc::<str>::from(pool.author_login(issue.author)),
             created_at: Arc::<str>::from(pool.resolve_str(issue.created_at_short)),
             created_ts: issue.created_ts,
+            updated_ts: issue.updated_ts,
             body: issue
                 .body
                 .map(|body| Arc::<str>::from(pool.resolve_str(body))),
             title: Some(Arc::<str>::from(pool.resolve_str(issue.title))),
+            is_pull_request: issue.is_pull_request,
+            locked: issue.locked,
+            state: issue.state.clone(),
+            assignees: issue
+                .assignees
+                .iter()
+                .map(|assignee| Arc::<str>::from(pool.author_login(*assignee)))
+                .collect(),
+            milestone: issue
+                .milestone
+                .map(|milestone| Arc::<str>::from(pool.resolve_str(milestone))),
+            html_url: Arc::<str>::from(pool.resolve_str(issue.html_url)),
+        }
+    }
+}
+
+/// Appends `body` as a GitHub-style quote to the reply `draft`, keeping whatever was already
+/// typed and leaving an empty line to write the answer on.
+fn append_quote(draft: &str, body: &str) -> String {
+    let mut reply = draft.trim_end().to_string();
+    if !reply.is_empty() {
+        reply.push_str("\n\n");
+    }
+    for line in body.trim_end().lines() {
+        reply.push('>');
+        if !line.is_empty() {
+            reply.push(' ');
+            reply.push_str(line);
         }
+        reply.push('\n');
+    }
+    reply.push('\n');
+    reply
+}
+
+/// One-line summary of the issue's state, assignees and milestone shown under its title.
+fn issue_meta_line(seed: &IssueConversationSeed) -> Line<'static> {
+    let (state, style) = match seed.state {
+        IssueState::Open => ("● Open", Style::new().green()),
+        _ => ("● Closed", Style::new().magenta()),
+    };
+    let assignees = if seed.assignees.is_empty() {
+        "unassigned".to_string()
+    } else {
+        seed.assignees
+            .iter()
+            .map(|login| format!("@{login}"))
+            .collect::<Vec<_>>()
+            .join(", ")
+    };
+    let mut spans = vec![
+        Span::styled(state, style.add_modifier(Modifier::BOLD)),
+        Span::raw("  "),
+        Span::styled(assignees, Style::new().cyan()),
+    ];
+    if let Some(milestone) = &seed.milestone {
+        spans.push(Span::raw("  "));
+        spans.push(Span::styled(
+            format!("⚑ {milestone}"),
+            Style::new().yellow(),
+        ));
+    }
+    Line::from(spans)
+}
+
+const EDITED_THRESHOLD_SECS: i64 = 5;
+/// Rendered lines of a comment shown before the rest is folded behind "show more".
+const COLLAPSED_COMMENT_LINES: usize = 30;
+const INSUFFICIENT_LOCK_PERMISSIONS: &str =
+    "Insufficient permissions: locking requires push access to the repository.";
+/// Page size used when following the comment pages of an issue.
+const COMMENTS_PER_PAGE: u8 = 100;
+const MAX_MENTION_SUGGESTIONS: usize = 6;
+const EMPTY_COMMENT_ERROR: &str = "Comment cannot be empty.";
+/// How long the empty-comment error stays in the composer title when nothing is typed.
+const EMPTY_COMMENT_ERROR_TTL: Duration = Duration::from_secs(3);
+/// The comment poll stops hitting the API after this long without input.
+const POLL_IDLE_AFTER: Duration = Duration::from_secs(10 * 60);
+/// How often an open conversation is redrawn so relative timestamps move on while idle.
+const RELATIVE_TIME_REFRESH: Duration = Duration::from_secs(60);
+
+/// Conditions the background comment poll checks before each request.
+#[derive(Debug)]
+struct PollGate {
+    viewing: bool,
+    focused: bool,
+    last_input: Instant,
+}
+
+impl PollGate {
+    fn is_open(&self) -> bool {
+        self.viewing && self.focused && self.last_input.elapsed() < POLL_IDLE_AFTER
     }
 }
 
 #[derive(Debug, Clone)]
 pub struct CommentView {
     pub id: u64,
+    pub html_url: Arc<str>,
     pub author: Arc<str>,
     pub created_at: Arc<str>,
     pub created_ts: i64,
+    pub updated_ts: Option<i64>,
     pub body: Arc<str>,
     pub reactions: Option<Vec<(ReactionContent, u64)>>,
     pub my_reactions: Option<Vec<ReactionContent>>,
@@ -147,14 +368,50 @@ impl CommentView {
         let body = comment.body.unwrap_or_default();
         Self {
             id: comment.id.0,
+            html_url: Arc::<str>::from(comment.html_url.as_str()),
             author: Arc::<str>::from(comment.user.login.as_str()),
             created_at: Arc::<str>::from(comment.created_at.format("%Y-%m-%d %H:%M").to_string()),
             created_ts: comment.created_at.timestamp(),
+            updated_ts: comment.updated_at.map(|ts| ts.timestamp()),
             body: Arc::<str>::from(body),
             reactions: None,
             my_reactions: None,
         }
     }
+
+    /// Whether the comment was changed after it was posted. GitHub bumps `updated_at` by a
+    /// second or so on creation, so small differences are ignored.
+    pub fn is_edited(&self) -> bool {
+        self.updated_ts
+            .is_some_and(|updated| updated - self.created_ts > EDITED_THRESHOLD_SECS)
+    }
+}
+
+/// One changed file of a pull request, as shown in the files summary above the conversation.
+#[derive(Debug, Clone)]
+pub struct PrFileView {
+    pub filename: Arc<str>,
+    pub status: char,
+    pub additions: u64,
+    pub deletions: u64,
+}
+
+impl PrFileView {
+    fn from_api(entry: DiffEntry) -> Self {
+        let status = match entry.status {
+            DiffEntryStatus::Added => 'A',
+            DiffEntryStatus::Removed => 'D',
+            DiffEntryStatus::Renamed => 'R',
+            DiffEntryStatus::Copied => 'C',
+            _ => 'M',
+        };
+        Self {
+            filename: Arc::<str>::from(entry.filename),
+            status,
+            additions: entry.additions,
+            deletions: entry.deletions,
+        }
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -217,24 +474,65 @@ pub struct IssueConversation {
     timeline_cache_number: Option<u64>,
     cache_timeline: Vec<TimelineEventView>,
     markdown_cache: HashMap<u64, MarkdownRender>,
+    /// Long comments the user chose to read in full.
+    expanded_comments: HashSet<u64>,
+    /// Messages whose `<details>` sections are unfolded.
+    expanded_details: HashSet<MessageKey>,
     body_cache: Option<MarkdownRender>,
     body_cache_number: Option<u64>,
     markdown_width: usize,
     loading: HashSet<u64>,
     timeline_loading: HashSet<u64>,
+    pr_files_number: Option<u64>,
+    pr_files: Vec<PrFileView>,
+    pr_files_truncated: bool,
+    pr_files_loading: HashSet<u64>,
+    pr_files_error: Option<String>,
+    subscription: Option<SubscriptionState>,
+    subscription_number: Option<u64>,
+    subscription_pending: Option<u64>,
+    poll_interval: Option<Duration>,
+    poll_gate: Arc<Mutex<PollGate>>,
+    poll_task: Option<tokio::task::JoinHandle<()>>,
+    new_comments: usize,
     posting: bool,
     error: Option<String>,
     post_error: Option<String>,
+    /// When [`EMPTY_COMMENT_ERROR`] was last shown in `post_error`.
+    empty_error_at: Option<Instant>,
+    /// Comment whose body is loaded into the input for editing. Sending saves it in place of
+    /// posting a new comment.
+    editing_comment: Option<u64>,
+    /// The composer holds the issue's title and body instead of a comment.
+    editing_issue: bool,
+    /// Unsent composer text put aside while a comment or the issue is edited, restored once the
+    /// edit is saved, cancelled or its comment deleted.
+    edit_stash: Option<String>,
+    /// Comment awaiting a y/n answer before it is deleted.
+    delete_confirm: Option<u64>,
+    /// State the issue is about to be moved to, waiting for a y/n answer.
+    state_confirm: Option<IssueState>,
+    state_pending: Option<u64>,
+    /// Highlighted row of the @-mention popup.
+    mention_selected: usize,
+    /// Position just after the `@` whose popup was dismissed with Esc, so it stays closed until
+    /// a new mention is started.
+    mention_dismissed: Option<TextPosition>,
     reaction_error: Option<String>,
     close_error: Option<String>,
     timeline_error: Option<String>,
     owner: String,
     repo: String,
     current_user: String,
+    self_logins: Vec<String>,
+    /// Character count at which the composer's counter turns red.
+    comment_warn_chars: usize,
     issue_pool: Arc<RwLock<UiIssuePool>>,
     list_state: ListState<RowSelection>,
     message_keys: Vec<MessageKey>,
     show_timeline: bool,
+    /// Show comment times as `%Y-%m-%d %H:%M` instead of "3 hours ago".
+    absolute_times: bool,
     input_state: TextAreaState,
     throbber_state: ThrobberState,
     post_throbber_state: ThrobberState,
@@ -245,10 +543,102 @@ pub struct IssueConversation {
     paragraph_state: ParagraphState,
     body_paragraph_state: ParagraphState,
     reaction_mode: Option<ReactionMode>,
+    author_filter: Option<AuthorFilter>,
+    author_filter_prompt: Option<AuthorFilterPrompt>,
+    read_markers: ReadMarkers,
+    drafts: Drafts,
+    read_marker: Option<ReadMarker>,
+    pinned: Option<MessageKey>,
+    jump_to_unread: bool,
+    /// Select the newest message on the next render, e.g. after posting a comment.
+    jump_to_latest: bool,
     close_popup: Option<IssueClosePopupState>,
+    lock_popup: Option<LockPopupState>,
+    lock_pending: Option<u64>,
+    lock_error: Option<String>,
+    assignee_popup: Option<AssigneePopupState>,
+    milestone_popup: Option<MilestonePopupState>,
     index: usize,
 }
 
+/// Reason picker shown before locking the conversation.
+#[derive(Debug)]
+struct LockPopupState {
+    issue_number: u64,
+    reason_state: TuiListState,
+}
+
+impl LockPopupState {
+    fn new(issue_number: u64) -> Self {
+        let mut reason_state = TuiListState::default();
+        reason_state.select(Some(0));
+        Self {
+            issue_number,
+            reason_state,
+        }
+    }
+
+    fn selected_reason(&self) -> LockIssueReason {
+        self.reason_state
+            .selected()
+            .and_then(|idx| LockIssueReason::ALL.get(idx).copied())
+            .unwrap_or(LockIssueReason::OffTopic)
+    }
+}
+
+/// Lists the issue's assignees and takes logins to assign.
+#[derive(Debug)]
+struct AssigneePopupState {
+    issue_number: u64,
+    selected: TuiListState,
+    input: TextInputState,
+    pending: bool,
+    error: Option<String>,
+}
+
+impl AssigneePopupState {
+    fn new(issue_number: u64) -> Self {
+        let mut selected = TuiListState::default();
+        selected.select(Some(0));
+        Self {
+            issue_number,
+            selected,
+            input: TextInputState::new_focused(),
+            pending: false,
+            error: None,
+        }
+    }
+}
+
+/// Picker over the repository's open milestones. The first row clears the milestone.
+#[derive(Debug)]
+struct MilestonePopupState {
+    issue_number: u64,
+    /// Number and title of each open milestone, `None` while they load.
+    milestones: Option<Vec<(i64, String)>>,
+    selected: TuiListState,
+    pending: bool,
+    error: Option<String>,
+}
+
+impl MilestonePopupState {
+    fn new(issue_number: u64) -> Self {
+        Self {
+            issue_number,
+            milestones: None,
+            selected: TuiListState::default(),
+            pending: false,
+            error: None,
+        }
+    }
+}
+
+#[derive(Debug)]
+enum AssigneeChange {
+    Add(Vec<String>),
+    Remove(String),
+}
+
 #[derive(Debug, Default, PartialEq, Eq)]
 enum InputState {
     #[default]
@@ -256,7 +646,7 @@ enum InputState {
     Preview,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 enum MessageKey {
     IssueBody(u64),
     Comment(u64),
@@ -267,6 +657,8 @@ enum MessageKey {
 struct MarkdownRender {
     lines: Vec<Line<'static>>,
     links: Vec<RenderedLink>,
+    /// Number of `<details>` sections, shown folded or unfolded.
+    details: usize,
 }
 
 #[derive(Debug, Clone)]
@@ -291,6 +683,51 @@ enum ReactionMode {
     },
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum AuthorFilterKind {
+    Only,
+    Hide,
+}
+
+#[derive(Debug, Clone)]
+struct AuthorFilter {
+    author: Arc<str>,
+    kind: AuthorFilterKind,
+}
+
+#[derive(Debug, Clone)]
+struct AuthorFilterPrompt {
+    selected: usize,
+    options: Vec<Arc<str>>,
+    kind: AuthorFilterKind,
+}
+
+impl AuthorFilter {
+    fn matches(&self, author: &str) -> bool {
+        let same = self.author.as_ref().eq_ignore_ascii_case(author);
+        match self.kind {
+            AuthorFilterKind::Only => same,
+            AuthorFilterKind::Hide => !same,
+        }
+    }
+
+    fn describe(&self) -> String {
+        match self.kind {
+            AuthorFilterKind::Only => format!("Only: {}", self.author),
+            AuthorFilterKind::Hide => format!("Hiding: {}", self.author),
+        }
+    }
+}
+
+impl AuthorFilterKind {
+    fn toggle(self) -> Self {
+        match self {
+            AuthorFilterKind::Only => AuthorFilterKind::Hide,
+            AuthorFilterKind::Hide => AuthorFilterKind::Only,
+        }
+    }
+}
+
 impl InputState {
     fn toggle(&mut self) {
         *self = match self {
@@ -309,6 +746,9 @@ impl IssueConversation {
     }
 
     pub fn new(app_state: crate::ui::AppState, issue_pool: Arc<RwLock<UiIssuePool>>) -> Self {
+        let self_logins = app_state.self_logins();
+        let read_markers = read_read_markers(&app_state.owner, &app_state.repo);
+        let drafts = read_drafts(&app_state.owner, &app_state.repo);
         Self {
             title: None,
             action_tx: None,
@@ -318,25 +758,55 @@ impl IssueConversation {
             timeline_cache_number: None,
             cache_timeline: Vec::new(),
             markdown_cache: HashMap::new(),
+            expanded_comments: HashSet::new(),
+            expanded_details: HashSet::new(),
             paragraph_state: Default::default(),
             body_cache: None,
             body_cache_number: None,
             markdown_width: 0,
             loading: HashSet::new(),
             timeline_loading: HashSet::new(),
+            pr_files_number: None,
+            pr_files: Vec::new(),
+            pr_files_truncated: false,
+            pr_files_loading: HashSet::new(),
+            pr_files_error: None,
+            subscription: None,
+            subscription_number: None,
+            subscription_pending: None,
+            poll_interval: app_state.poll_interval,
+            poll_gate: Arc::new(Mutex::new(PollGate {
+                viewing: false,
+                focused: true,
+                last_input: Instant::now(),
+            })),
+            poll_task: None,
+            new_comments: 0,
             posting: false,
             error: None,
             post_error: None,
+            empty_error_at: None,
+            editing_comment: None,
+            editing_issue: false,
+            edit_stash: None,
+            delete_confirm: None,
+            state_confirm: None,
+            state_pending: None,
+            mention_selected: 0,
+            mention_dismissed: None,
             reaction_error: None,
             close_error: None,
             timeline_error: None,
             owner: app_state.owner,
             repo: app_state.repo,
+            self_logins,
+            comment_warn_chars: app_state.comment_warn_chars,
             current_user: app_state.current_user,
             issue_pool,
             list_state: ListState::default(),
             message_keys: Vec::new(),
             show_timeline: false,
+            absolute_times: false,
             input_state: TextAreaState::new(),
             textbox_state: InputState::default(),
             throbber_state: ThrobberState::default(),
@@ -346,7 +816,20 @@ impl IssueConversation {
             area: Rect::default(),
             body_paragraph_state: ParagraphState::default(),
             reaction_mode: None,
+            author_filter: None,
+            author_filter_prompt: None,
+            read_markers,
+            drafts,
+            read_marker: None,
+            pinned: None,
+            jump_to_unread: false,
+            jump_to_latest: false,
             close_popup: None,
+            lock_popup: None,
+            assignee_popup: None,
+            milestone_popup: None,
+            lock_pending: None,
+            lock_error: None,
             index: 0,
         }
     }
@@ -376,18 +859,34 @@ impl IssueConversation {
             })
             .unwrap_or_else(|| Line::from(""));
         let wrapped_title_len = wrapped_title.len() as u16;
+        let meta_line = self.current.as_ref().map(issue_meta_line);
+        let title_para_height = title_para_height + u16::from(meta_line.is_some());
         let title_para = Text::from_iter(
             wrapped_title
                 .into_iter()
                 .take(wrapped_title_len as usize - 1)
                 .map(Line::from)
-                .chain(std::iter::once(last_line)),
+                .chain(std::iter::once(last_line))
+                .chain(meta_line),
         );
 
         let areas = vertical![==title_para_height, *=1, ==5].split(area.main_content);
         let title_area = areas[0];
-        let content_area = areas[1];
+        let mut content_area = areas[1];
         let input_area = areas[2];
+        let mut files_area = None;
+        if let Some(height) = self.pr_files_height(content_area.height) {
+            let [files, rest] = vertical![==height, *=1].areas(content_area);
+            files_area = Some(files);
+            content_area = rest;
+        }
+        let mut pinned_area = None;
+        if self.pinned_render().is_some() {
+            let height = (content_area.height / 3).clamp(4, 12);
+            let [pin, rest] = vertical![==height, *=1].areas(content_area);
+            pinned_area = Some(pin);
+            content_area = rest;
+        }
         let content_split = horizontal![*=1, *=1].split(content_area);
         let list_area = content_split[0];
         let body_area = content_split[1];
@@ -404,20 +903,42 @@ impl IssueConversation {
 
         if !self.is_loading_current() {
             let mut title = format!("[{}] Conversation", self.index);
+            if self.is_locked() {
+                title.push_str(" 🔒 locked");
+            }
             title.push_str(if self.show_timeline {
                 " | Timeline: ON"
             } else {
                 " | Timeline: OFF"
             });
+            if let Some(filter) = &self.author_filter {
+                title.push_str(" | ");
+                title.push_str(&filter.describe());
+            }
+            title.push_str(" | ");
+            title.push_str(self.subscription_label());
+            if self.new_comments > 0 {
+                let plural = if self.new_comments == 1 { "" } else { "s" };
+                title.push_str(&format!(
+                    " | {} new comment{plural} — Ctrl+R to load",
+                    self.new_comments
+                ));
+            }
             if let Some(prompt) = self.reaction_mode_prompt() {
                 title.push_str(" | ");
                 title.push_str(&prompt);
+            } else if let Some(prompt) = self.author_filter_prompt_text() {
+                title.push_str(" | ");
+                title.push_str(&prompt);
             } else if let Some(err) = &self.reaction_error {
                 title.push_str(" | ");
                 title.push_str(err);
             } else if let Some(err) = &self.close_error {
                 title.push_str(" | ");
                 title.push_str(err);
+            } else if let Some(err) = &self.lock_error {
+                title.push_str(" | ");
+                title.push_str(err);
             } else if let Some(err) = &self.timeline_error {
                 title.push_str(" | ");
                 title.push_str(err);
@@ -432,6 +953,12 @@ impl IssueConversation {
             .select_style(Style::default().add_modifier(Modifier::BOLD));
         list.render(list_area, buf, &mut self.list_state);
         self.render_body(body_area, buf);
+        if let Some(files_area) = files_area {
+            self.render_pr_files(files_area, buf);
+        }
+        if let Some(pinned_area) = pinned_area {
+            self.render_pinned(pinned_area, buf);
+        }
         if self.is_loading_current() {
             let title_area = Rect {
                 x: list_area.x + 1,
@@ -448,38 +975,49 @@ impl IssueConversation {
         }
 
         match self.textbox_state {
-            InputState::Input => {
-                let input_title = if let Some(err) = &self.post_error {
-                    format!("Comment (Ctrl+Enter to send) | {err}")
-                } else {
-                    "Comment (Ctrl+Enter to send)".to_string()
+            _ if let Some(state) = &self.state_confirm => {
+                let number = self.current.as_ref().map_or(0, |seed| seed.number);
+                let prompt = match state {
+                    IssueState::Closed => format!("Close issue #{number}? (y/n)"),
+                    _ => format!("Reopen issue #{number}? (y/n)"),
                 };
-                let mut input_block = Block::bordered()
-                    .border_type(ratatui::widgets::BorderType::Rounded)
-                    .border_style(get_border_style(&self.input_state));
-                if !self.posting {
-                    input_block = input_block.title(input_title);
-                }
-                let input_widget = TextArea::new()
-                    .block(input_block)
-                    .text_wrap(TextWrap::Word(4));
-                input_widget.render(input_area, buf, &mut self.input_state);
+                widgets::Paragraph::new(prompt)
+                    .block(
+                        Block::bordered()
+                            .border_type(ratatui::widgets::BorderType::Rounded)
+                            .border_style(Style::default().yellow())
+                            .title("Confirm [y/n]"),
+                    )
+                    .render(input_area, buf);
             }
+            _ if self.delete_confirm.is_some() => {
+                widgets::Paragraph::new("Delete this comment? This cannot be undone. (y/n)")
+                    .block(
+                        Block::bordered()
+                            .border_type(ratatui::widgets::BorderType::Rounded)
+                            .border_style(Style::default().yellow())
+                            .title("Confirm [y/n]"),
+                    )
+                    .render(input_area, buf);
+            }
+            InputState::Input => self.render_composer(input_area, buf),
             InputState::Preview => {
-                let rendered =
-                    render_markdown_lines(&self.input_state.text(), self.markdown_width, 2);
+                let [editor_area, preview_area] = horizontal![*=1, *=1].areas(input_area);
+                self.render_composer(editor_area, buf);
+                let preview_width = preview_area.width.saturating_sub(2) as usize;
+                let rendered = render_markdown_lines(&self.input_state.text(), preview_width, 2);
                 let para = Paragraph::new(rendered)
                     .block(
                         Block::bordered()
                             .border_type(ratatui::widgets::BorderType::Rounded)
                             .border_style(get_border_style(&self.paragraph_state))
-                            .title("Preview"),
+                            .title("Preview (Ctrl+P to close)"),
                     )
                     .focus_style(Style::default())
                     .hide_focus(true)
                     .wrap(ratatui::widgets::Wrap { trim: true });
 
-                para.render(input_area, buf, &mut self.paragraph_state);
+                para.render(preview_area, buf, &mut self.paragraph_state);
             }
         }
 
@@ -497,7 +1035,117 @@ impl IssueConversation {
                 .use_type(WhichUse::Spin);
             StatefulWidget::render(throbber, title_area, buf, &mut self.post_throbber_state);
         }
+        self.render_mention_popup(input_area, buf);
         self.render_close_popup(area.main_content, buf);
+        self.render_lock_popup(area.main_content, buf);
+        self.render_assignee_popup(area.main_content, buf);
+        self.render_milestone_popup(area.main_content, buf);
+    }
+
+    /// The partial login being typed after an `@` left of the cursor, with the position just
+    /// after the `@`.
+    fn mention_context(&self) -> Option<(TextPosition, String)> {
+        let cursor = self.input_state.cursor();
+        let before = self
+            .input_state
+            .str_slice((0, cursor.y)..(cursor.x, cursor.y));
+        let query = mention_query(&before)?;
+        let start = cursor.x - query.chars().count() as u32;
+        Some((TextPosition::new(start, cursor.y), query.to_string()))
+    }
+
+    /// Issue participants matching the mention under the cursor, or nothing when no popup
+    /// should be shown.
+    fn mention_suggestions(&self) -> Vec<Arc<str>> {
+        if !self.input_state.is_focused() || self.posting {
+            return Vec::new();
+        }
+        let Some((start, query)) = self.mention_context() else {
+            return Vec::new();
+        };
+        if self.mention_dismissed == Some(start) {
+            return Vec::new();
+        }
+        let participants = self
+            .current
+            .iter()
+            .map(|seed| &seed.author)
+            .chain(self.cache_comments.iter().map(|comment| &comment.author));
+        suggest_mentions(participants, &query)
+    }
+
+    fn handle_mention_event(&mut self, event: &crossterm::event::Event) -> bool {
+        let suggestions = self.mention_suggestions();
+        if suggestions.is_empty() {
+            self.mention_selected = 0;
+            return false;
+        }
+        let selected = self.mention_selected.min(suggestions.len() - 1);
+        match event {
+            ct_event!(keycode press Up) => {
+                self.mention_selected = selected.checked_sub(1).unwrap_or(suggestions.len() - 1);
+            }
+            ct_event!(keycode press Down) => {
+                self.mention_selected = (selected + 1) % suggestions.len();
+            }
+            ct_event!(keycode press Enter) | ct_event!(keycode press Tab) => {
+                let Some((start, _)) = self.mention_context() else {
+                    return false;
+                };
+                let cursor = self.input_state.cursor();
+                self.input_state.delete_range(start..cursor);
+                self.input_state
+                    .insert_str(format!("{} ", suggestions[selected]));
+                self.mention_selected = 0;
+            }
+            ct_event!(keycode press Esc) => {
+                self.mention_dismissed = self.mention_context().map(|(start, _)| start);
+                self.mention_selected = 0;
+            }
+            _ => return false,
+        }
+        true
+    }
+
+    fn render_mention_popup(&self, anchor: Rect, buf: &mut Buffer) {
+        let suggestions = self.mention_suggestions();
+        if suggestions.is_empty() {
+            return;
+        }
+        let selected = self.mention_selected.min(suggestions.len() - 1);
+        let width = suggestions
+            .iter()
+            .map(|login| login.chars().count() as u16 + 5)
+            .max()
+            .unwrap_or_default()
+            .max(20)
+            .min(anchor.width);
+        let height = (suggestions.len() as u16 + 2).min(anchor.y);
+        let area = Rect {
+            x: anchor.x,
+            y: anchor.y.saturating_sub(height),
+            width,
+            height,
+        };
+        let items = suggestions
+            .iter()
+            .enumerate()
+            .map(|(idx, login)| {
+                let item = ListItem::new(format!("@{login}"));
+                if idx == selected {
+                    item.style(Style::new().bold().reversed())
+                } else {
+                    item
+                }
+            })
+            .collect::<Vec<_>>();
+        Clear.render(area, buf);
+        let list = TuiList::new(items).block(
+            Block::bordered()
+                .border_type(ratatui::widgets::BorderType::Rounded)
+                .title("Mention"),
+        );
+        Widget::render(list, area, buf);
     }
 
     fn build_items(&mut self, list_area: Rect, body_area: Rect) -> Vec<ListItem<'static>> {
@@ -529,27 +1177,44 @@ impl IssueConversation {
             return items;
         };
 
+        // Relative labels are worked out on every draw. Ticks stop once nothing animates, so the
+        // clock task started in `register_action_tx` redraws once a minute to keep them current.
+        let now = unix_now();
+        let absolute_times = self.absolute_times;
+        let timestamp = |absolute: &str, ts: i64| match relative_time(ts, now) {
+            Some(relative) if !absolute_times => relative,
+            _ => absolute.to_string(),
+        };
+        let author_filter = self.author_filter.clone();
+        let shows_author = |author: &str| author_filter.as_ref().is_none_or(|f| f.matches(author));
+
         if let Some(body) = seed
             .body
             .as_ref()
             .map(|b| b.as_ref())
             .filter(|b| !b.trim().is_empty())
+            .filter(|_| shows_author(seed.author.as_ref()))
         {
             if self.body_cache_number != Some(seed.number) {
                 self.body_cache_number = Some(seed.number);
                 self.body_cache = None;
             }
+            let expand_details = self
+                .expanded_details
+                .contains(&MessageKey::IssueBody(seed.number));
             let body_lines = self
                 .body_cache
-                .get_or_insert_with(|| render_markdown(body, width, 2));
-            items.push(build_comment_preview_item(
+                .get_or_insert_with(|| render_message_markdown(body, width, expand_details));
+            let item = build_comment_preview_item(
                 seed.author.as_ref(),
-                seed.created_at.as_ref(),
+                &timestamp(seed.created_at.as_ref(), seed.created_ts),
                 &body_lines.lines,
                 preview_width,
-                seed.author.as_ref() == self.current_user,
+                is_self_login(&self.self_logins, seed.author.as_ref()),
+                false,
                 None,
-            ));
+            );
+            items.push(dim_if_read(item, self.read_marker.is_some()));
             self.message_keys.push(MessageKey::IssueBody(seed.number));
         }
 
@@ -562,6 +1227,7 @@ impl IssueConversation {
             let mut merged = self
                 .cache_comments
                 .iter()
+                .filter(|comment| shows_author(comment.author.as_ref()))
                 .map(|comment| (comment.created_ts, MessageKey::Comment(comment.id)))
                 .collect::<Vec<_>>();
 
@@ -582,16 +1248,26 @@ impl IssueConversation {
                         {
                             let body_lines =
                                 self.markdown_cache.entry(comment.id).or_insert_with(|| {
-                                    render_markdown(comment.body.as_ref(), width, 2)
+                                    render_message_markdown(
+                                        comment.body.as_ref(),
+                                        width,
+                                        self.expanded_details
+                                            .contains(&MessageKey::Comment(comment.id)),
+                                    )
                                 });
-                            items.push(build_comment_preview_item(
+                            let item = build_comment_preview_item(
                                 comment.author.as_ref(),
-                                comment.created_at.as_ref(),
+                                &timestamp(comment.created_at.as_ref(), comment.created_ts),
                                 &body_lines.lines,
                                 preview_width,
-                                comment.author.as_ref() == self.current_user,
+                                is_self_login(&self.self_logins, comment.author.as_ref()),
+                                comment.is_edited(),
                                 comment.reactions.as_deref(),
-                            ));
+                            );
+                            let read = self
+                                .read_marker
+                                .is_some_and(|marker| comment.created_ts <= marker.created_ts);
+                            items.push(dim_if_read(item, read));
                             self.message_keys.push(MessageKey::Comment(comment.id));
                         }
                     }
@@ -606,6 +1282,25 @@ impl IssueConversation {
             }
         }
 
+        // Wait for every page so the newest comment is really the last one.
+        if self.jump_to_unread
+            && self.cache_number == Some(seed.number)
+            && !self.loading.contains(&seed.number)
+        {
+            self.jump_to_unread = false;
+            match self.oldest_unread_index() {
+                Some(index) => {
+                    let _ = self.list_state.select(Some(index));
+                }
+                None => self.jump_to_latest = true,
+            }
+        }
+        if self.jump_to_latest && !items.is_empty() {
+            self.jump_to_latest = false;
+            let _ = self.list_state.select(Some(items.len() - 1));
+            self.list_state.scroll_to_selected();
+        }
+
         if items.is_empty() {
             self.list_state.clear_selection();
         } else {
@@ -617,10 +1312,47 @@ impl IssueConversation {
         items
     }
 
+    fn oldest_unread_index(&self) -> Option<usize> {
+        let marker = self.read_marker?;
+        self.message_keys.iter().position(|key| match key {
+            MessageKey::Comment(id) => self
+                .cache_comments
+                .iter()
+                .find(|c| c.id == *id)
+                .is_some_and(|c| c.created_ts > marker.created_ts),
+            _ => false,
+        })
+    }
+
+    /// Moves the read marker for the open issue up to its newest loaded comment and persists it.
+    fn mark_current_read(&mut self) {
+        let Some(seed) = &self.current else {
+            return;
+        };
+        if self.cache_number != Some(seed.number) {
+            return;
+        }
+        let Some(newest) = self.cache_comments.iter().max_by_key(|c| c.created_ts) else {
+            return;
+        };
+        let marker = ReadMarker {
+            comment_id: newest.id,
+            created_ts: newest.created_ts,
+        };
+        if self.read_markers.set(seed.number, marker)
+            && let Err(err) = self.read_markers.write_to_file(&self.owner, &self.repo)
+        {
+            error!("Failed to save read markers: {err}");
+        }
+    }
+
     fn render_body(&mut self, body_area: Rect, buf: &mut Buffer) {
         let selected_body = self.selected_body_render().cloned();
         let selected_timeline = self.selected_timeline().cloned();
-        let body_lines: Vec<Line<'static>> = if let Some(entry) = selected_timeline.as_ref() {
+        // Fullscreen is for reading a message in full, and the list can't be used to expand it.
+        let collapsed = self.screen != MainScreen::DetailsFullscreen
+            && self.selected_collapsible_comment().is_some();
+        let mut body_lines: Vec<Line<'static>> = if let Some(entry) = selected_timeline.as_ref() {
             build_timeline_body_lines(entry)
         } else {
             selected_body
@@ -634,6 +1366,12 @@ impl IssueConversation {
                 })
         };
 
+        let visible_lines = if collapsed {
+            collapse_lines(&mut body_lines, COLLAPSED_COMMENT_LINES)
+        } else {
+            body_lines.len()
+        };
+
         let body = Paragraph::new(body_lines)
             .block(
                 Block::bordered()
@@ -651,7 +1389,219 @@ impl IssueConversation {
         body.render(body_area, buf, &mut self.body_paragraph_state);
 
         if let Some(render) = selected_body.as_ref() {
-            self.render_body_links(body_area, buf, render);
+            self.render_body_links(body_area, buf, render, visible_lines);
+        }
+    }
+
+    /// The selected comment when it is too long to show in full and hasn't been expanded.
+    fn selected_collapsible_comment(&self) -> Option<u64> {
+        let selected = self.list_state.selected_checked()?;
+        let MessageKey::Comment(id) = *self.message_keys.get(selected)? else {
+            return None;
+        };
+        let render = self.markdown_cache.get(&id)?;
+        (render.lines.len() > COLLAPSED_COMMENT_LINES && !self.expanded_comments.contains(&id))
+            .then_some(id)
+    }
+
+    /// Expands the selected long comment, or collapses it again when `collapse` is set. Returns
+    /// whether anything changed.
+    fn toggle_selected_comment(&mut self, collapse: bool) -> bool {
+        if let Some(id) = self.selected_collapsible_comment() {
+            self.expanded_comments.insert(id);
+            return true;
+        }
+        if !collapse {
+            return false;
+        }
+        let Some(MessageKey::Comment(id)) = self
+            .list_state
+            .selected_checked()
+            .and_then(|idx| self.message_keys.get(idx))
+            .copied()
+        else {
+            return false;
+        };
+        if self.expanded_comments.remove(&id) {
+            self.body_paragraph_state.set_line_offset(0);
+            return true;
+        }
+        false
+    }
+
+    /// Folds or unfolds the `<details>` sections of the selected message. Returns whether it
+    /// had any.
+    fn toggle_selected_details(&mut self) -> bool {
+        let Some(key) = self
+            .list_state
+            .selected_checked()
+            .and_then(|idx| self.message_keys.get(idx))
+            .copied()
+        else {
+            return false;
+        };
+        let render = match key {
+            MessageKey::IssueBody(_) => self.body_cache.as_ref(),
+            MessageKey::Comment(id) => self.markdown_cache.get(&id),
+            MessageKey::Timeline(_) => None,
+        };
+        if render.is_none_or(|render| render.details == 0) {
+            return false;
+        }
+        if !self.expanded_details.remove(&key) {
+            self.expanded_details.insert(key);
+        }
+        match key {
+            MessageKey::IssueBody(_) => self.body_cache = None,
+            MessageKey::Comment(id) => {
+                self.markdown_cache.remove(&id);
+            }
+            MessageKey::Timeline(_) => {}
+        }
+        self.body_paragraph_state.set_line_offset(0);
+        true
+    }
+
+    /// Height of the pull request files summary, or `None` when the current issue isn't a PR.
+    fn pr_files_height(&self, available: u16) -> Option<u16> {
+        let seed = self.current.as_ref().filter(|seed| seed.is_pull_request)?;
+        if self.pr_files_number != Some(seed.number) || self.pr_files.is_empty() {
+            return Some(3);
+        }
+        let max_rows = (available / 4).clamp(1, 8);
+        Some(self.pr_files.len().min(max_rows as usize) as u16 + 2)
+    }
+
+    fn render_pr_files(&self, area: Rect, buf: &mut Buffer) {
+        let Some(seed) = self.current.as_ref() else {
+            return;
+        };
+        let loaded = self.pr_files_number == Some(seed.number);
+        let mut title = String::from("Files changed");
+        if loaded {
+            let additions: u64 = self.pr_files.iter().map(|f| f.additions).sum();
+            let deletions: u64 = self.pr_files.iter().map(|f| f.deletions).sum();
+            let more = if self.pr_files_truncated { "+" } else { "" };
+            title = format!(
+                "Files changed: {}{more} (+{additions} -{deletions})",
+                self.pr_files.len()
+            );
+        }
+
+        let rows = area.height.saturating_sub(2) as usize;
+        let mut lines = Vec::with_capacity(rows);
+        if let Some(err) = &self.pr_files_error {
+            lines.push(Line::styled(err.clone(), Style::new().red()));
+        } else if !loaded {
+            lines.push(Line::styled("Loading...", Style::new().dim()));
+        } else if self.pr_files.is_empty() {
+            lines.push(Line::styled("No files changed.", Style::new().dim()));
+        } else {
+            let overflow = self.pr_files.len() > rows;
+            let shown = if overflow {
+                rows.saturating_sub(1)
+            } else {
+                rows
+            };
+            let num_width = self
+                .pr_files
+                .iter()
+                .map(|f| f.additions.max(f.deletions).to_string().len())
+                .max()
+                .unwrap_or(1);
+            for file in self.pr_files.iter().take(shown) {
+                let status_style = match file.status {
+                    'A' => Style::new().green(),
+                    'D' => Style::new().red(),
+                    'R' | 'C' => Style::new().cyan(),
+                    _ => Style::new().yellow(),
+                };
+                lines.push(line![
+                    span!(file.status).style(status_style),
+                    span!(" +{:<num_width$}", file.additions).green(),
+                    span!(" -{:<num_width$} ", file.deletions).red(),
+                    span!(file.filename.as_ref()),
+                ]);
+            }
+            if overflow {
+                let hidden = self.pr_files.len() - shown;
+                lines.push(Line::styled(
+                    format!("… and {hidden} more"),
+                    Style::new().dim(),
+                ));
+            }
+        }
+
+        widgets::Paragraph::new(lines)
+            .block(
+                Block::bordered()
+                    .border_type(ratatui::widgets::BorderType::Rounded)
+                    .title(title),
+            )
+            .render(area, buf);
+    }
+
+    fn render_pinned(&self, area: Rect, buf: &mut Buffer) {
+        let Some((author, render)) = self.pinned_render() else {
+            return;
+        };
+        let lines = render
+            .map(|render| render.lines.clone())
+            .unwrap_or_else(|| vec![Line::styled("Loading...", Style::new().dim())]);
+        let title = match self.pinned {
+            Some(MessageKey::IssueBody(_)) => format!("Pinned: issue body by @{author} (p: unpin)"),
+            _ => format!("Pinned: comment by @{author} (p: unpin)"),
+        };
+        widgets::Paragraph::new(lines)
+            .block(
+                Block::bordered()
+                    .border_type(ratatui::widgets::BorderType::Rounded)
+                    .border_style(Style::new().fg(Color::Yellow))
+                    .title(title),
+            )
+            .render(area, buf);
+    }
+
+    fn pinned_render(&self) -> Option<(&str, Option<&MarkdownRender>)> {
+        match self.pinned? {
+            MessageKey::IssueBody(number) => {
+                let seed = self.current.as_ref().filter(|seed| seed.number == number)?;
+                let render = self
+                    .body_cache
+                    .as_ref()
+                    .filter(|_| self.body_cache_number == Some(number));
+                Some((seed.author.as_ref(), render))
+            }
+            MessageKey::Comment(id) => {
+                let comment = self.cache_comments.iter().find(|c| c.id == id)?;
+                Some((comment.author.as_ref(), self.markdown_cache.get(&id)))
+            }
+            MessageKey::Timeline(_) => None,
+        }
+    }
+
+    async fn toggle_pin(&mut self) {
+        let selected = self
+            .list_state
+            .selected_checked()
+            .and_then(|idx| self.message_keys.get(idx))
+            .copied();
+        match selected {
+            Some(key) if self.pinned == Some(key) => self.pinned = None,
+            Some(key @ (MessageKey::IssueBody(_) | MessageKey::Comment(_))) => {
+                self.pinned = Some(key)
+            }
+            Some(MessageKey::Timeline(_)) => {
+                if let Some(tx) = self.action_tx.clone() {
+                    let _ = tx
+                        .send(toast_action(
+                            "Only the issue body or a comment can be pinned",
+                            ToastType::Info,
+                        ))
+                        .await;
+                }
+            }
+            None => self.pinned = None,
         }
     }
 
@@ -680,7 +1630,14 @@ impl IssueConversation {
         }
     }
 
-    fn render_body_links(&self, body_area: Rect, buf: &mut Buffer, render: &MarkdownRender) {
+    /// Draws the links of `render` that fall within its first `visible_lines` lines.
+    fn render_body_links(
+        &self,
+        body_area: Rect,
+        buf: &mut Buffer,
+        render: &MarkdownRender,
+        visible_lines: usize,
+    ) {
         if render.links.is_empty() {
             return;
         }
@@ -708,7 +1665,7 @@ impl IssueConversation {
                 continue;
             }
 
-            if link.line < line_offset {
+            if link.line < line_offset || link.line >= visible_lines {
                 continue;
             }
 
@@ -771,9 +1728,12 @@ impl IssueConversation {
 
         tokio::spawn(async move {
             let result = tokio::task::spawn_blocking(move || {
+                // The editor would otherwise receive mouse reports as keystrokes.
+                let _ = execute!(stdout(), DisableMouseCapture);
                 ratatui::restore();
                 let edited = edit::edit(&initial_body).map_err(|err| err.to_string());
                 let _ = ratatui::init();
+                let _ = execute!(stdout(), EnableMouseCapture);
                 edited
             })
             .await
@@ -792,6 +1752,161 @@ impl IssueConversation {
         });
     }
 
+    async fn handle_delete_confirm_event(&mut self, event: &event::Event) -> bool {
+        let Some(comment_id) = self.delete_confirm else {
+            return false;
+        };
+        match event {
+            ct_event!(key press 'y') | ct_event!(key press 'Y') => {
+                self.delete_confirm = None;
+                if let Some(number) = self.current.as_ref().map(|seed| seed.number) {
+                    self.delete_comment(number, comment_id).await;
+                }
+            }
+            ct_event!(key press 'n') | ct_event!(key press 'N') | ct_event!(keycode press Esc) => {
+                self.delete_confirm = None;
+            }
+            _ => {}
+        }
+        if let Some(tx) = self.action_tx.clone() {
+            let _ = tx.send(Action::ForceRender).await;
+        }
+        true
+    }
+
+    /// Opens the selected comment, or the issue itself for the body and timeline entries, in the
+    /// default browser.
+    async fn open_in_browser(&mut self) {
+        let url = match self.selected_comment() {
+            Some(comment) => comment.html_url.clone(),
+            None => match self.current.as_ref() {
+                Some(seed) => seed.html_url.clone(),
+                None => return,
+            },
+        };
+        // Fails without a desktop session (e.g. over SSH); say so instead of erroring out.
+        let toast = match open::that_detached(url.as_ref()) {
+            Ok(()) => toast_action("Opened in browser", ToastType::Info),
+            Err(err) => {
+                debug!("Failed to open {url}: {err}");
+                toast_action(
+                    format!("Couldn't open a browser for {url}"),
+                    ToastType::Warning,
+                )
+            }
+        };
+        if let Some(tx) = self.action_tx.clone() {
+            let _ = tx.send(toast).await;
+        }
+    }
+
+    /// Asks to close an open issue or reopen a closed one.
+    fn confirm_state_toggle(&mut self) {
+        let Some(number) = self.current.as_ref().map(|seed| seed.number) else {
+            self.close_error = Some("No issue selected.".to_string());
+            return;
+        };
+        if self.state_pending == Some(number) {
+            return;
+        }
+        let state = self.issue_pool.read().ok().and_then(|pool| {
+            pool.issue_id_by_number(number)
+                .map(|issue_id| pool.get_issue(issue_id).state.clone())
+        });
+        self.close_error = None;
+        self.state_confirm = Some(match state {
+            Some(IssueState::Closed) => IssueState::Open,
+            _ => IssueState::Closed,
+        });
+    }
+
+    async fn handle_state_confirm_event(&mut self, event: &event::Event) -> bool {
+        let Some(state) = self.state_confirm.clone() else {
+            return false;
+        };
+        match event {
+            ct_event!(key press 'y') | ct_event!(key press 'Y') => {
+                self.state_confirm = None;
+                if let Some(number) = self.current.as_ref().map(|seed| seed.number) {
+                    self.set_issue_state(number, state);
+                }
+            }
+            ct_event!(key press 'n') | ct_event!(key press 'N') | ct_event!(keycode press Esc) => {
+                self.state_confirm = None;
+            }
+            _ => {}
+        }
+        if let Some(tx) = self.action_tx.clone() {
+            let _ = tx.send(Action::ForceRender).await;
+        }
+        true
+    }
+
+    fn set_issue_state(&mut self, number: u64, state: IssueState) {
+        let Some(action_tx) = self.action_tx.clone() else {
+            return;
+        };
+        self.state_pending = Some(number);
+        let owner = self.owner.clone();
+        let repo = self.repo.clone();
+
+        tokio::spawn(async move {
+            let Ok(client) = client() else {
+                let _ = action_tx
+                    .send(Action::IssueStateChangeError {
+                        number,
+                        message: "GitHub client not initialized.".to_string(),
+                    })
+                    .await;
+                return;
+            };
+            let issues = client.inner().issues(owner, repo);
+            let action = match issues.update(number).state(state).send().await {
+                Ok(issue) => Action::IssueStateChanged {
+                    number,
+                    state: issue.state,
+                },
+                Err(err) => Action::IssueStateChangeError {
+                    number,
+                    message: err.to_string().replace('\n', " "),
+                },
+            };
+            let _ = action_tx.send(action).await;
+        });
+    }
+
+    async fn delete_comment(&mut self, number: u64, id: u64) {
+        let Some(action_tx) = self.action_tx.clone() else {
+            return;
+        };
+        let owner = self.owner.clone();
+        let repo = self.repo.clone();
+        self.post_error = None;
+
+        tokio::spawn(async move {
+            let Ok(client) = client() else {
+                let _ = action_tx
+                    .send(Action::IssueCommentDeleteError {
+                        number,
+                        id,
+                        message: "GitHub client not initialized.".to_string(),
+                    })
+                    .await;
+                return;
+            };
+            let handler = client.inner().issues(owner, repo);
+            let action = match handler.delete_comment(CommentId(id)).await {
+                Ok(()) => Action::IssueCommentDeleted { number, id },
+                Err(err) => Action::IssueCommentDeleteError {
+                    number,
+                    id,
+                    message: err.to_string().replace('\n', " "),
+                },
+            };
+            let _ = action_tx.send(action).await;
+        });
+    }
+
     async fn patch_comment(&mut self, issue_number: u64, comment_id: u64, body: String) {
         let Some(action_tx) = self.action_tx.clone() else {
             return;
@@ -800,7 +1915,7 @@ impl IssueConversation {
         let repo = self.repo.clone();
 
         tokio::spawn(async move {
-            let Some(client) = GITHUB_CLIENT.get() else {
+            let Ok(client) = client() else {
                 let _ = action_tx
                     .send(Action::IssueCommentEditFinished {
                         issue_number,
@@ -815,8 +1930,8 @@ impl IssueConversation {
             match handler.update_comment(CommentId(comment_id), body).await {
                 Ok(comment) => {
                     let _ = action_tx
-                        .send(Action::IssueCommentPatched {
-                            issue_number,
+                        .send(Action::IssueCommentEdited {
+                            number: issue_number,
                             comment: CommentView::from_api(comment),
                         })
                         .await;
@@ -834,6 +1949,90 @@ impl IssueConversation {
         });
     }
 
+    /// Saves the composer text as the draft for issue `number`. While a comment or the issue is
+    /// being edited, the text put aside for the edit is saved instead.
+    fn stash_draft(&mut self, number: u64) {
+        let text = if self.editing_comment.is_some() || self.editing_issue {
+            match &self.edit_stash {
+                Some(text) => text.clone(),
+                None => return,
+            }
+        } else {
+            self.input_state.text()
+        };
+        if self.drafts.set(number, &text) {
+            let _ = self.drafts.write_to_file(&self.owner, &self.repo);
+        }
+    }
+
+    /// Loads `text` into the composer for editing, putting the unsent comment aside first unless
+    /// another edit already did.
+    fn enter_edit_mode(&mut self, text: &str) {
+        if self.editing_comment.is_none() && !self.editing_issue {
+            self.edit_stash = Some(self.input_state.text());
+        }
+        self.input_state.set_text(text);
+        self.input_state.move_to_end(false);
+    }
+
+    /// Ends the current edit and brings back the comment that was being written before it.
+    fn leave_edit_mode(&mut self) {
+        self.editing_comment = None;
+        self.editing_issue = false;
+        let draft = self.edit_stash.take().unwrap_or_default();
+        self.input_state.set_text(draft);
+        self.input_state.move_to_end(false);
+    }
+
+    fn patch_issue(&mut self, number: u64, title: String, body: String) {
+        let Some(action_tx) = self.action_tx.clone() else {
+            return;
+        };
+        let owner = self.owner.clone();
+        let repo = self.repo.clone();
+
+        tokio::spawn(async move {
+            let Ok(client) = client() else {
+                let _ = action_tx
+                    .send(Action::IssueEditError {
+                        number,
+                        message: "GitHub client not initialized.".to_string(),
+                    })
+                    .await;
+                return;
+            };
+            let result = client
+                .inner()
+                .issues(owner, repo)
+                .update(number)
+                .title(&title)
+                .body(&body)
+                .send()
+                .await;
+            let action = match result {
+                Ok(issue) => Action::IssueEdited {
+                    number,
+                    title: issue.title,
+                    body: issue.body,
+                },
+                Err(octocrab::Error::GitHub { source, .. })
+                    if matches!(source.status_code.as_u16(), 403 | 404) =>
+                {
+                    Action::IssueEditError {
+                        number,
+                        message: "only the author or collaborators with write access can edit it"
+                            .to_string(),
+                    }
+                }
+                Err(err) => Action::IssueEditError {
+                    number,
+                    message: err.to_string().replace('\n', " "),
+                },
+            };
+            let _ = action_tx.send(action).await;
+        });
+    }
+
     fn reaction_mode_prompt(&self) -> Option<String> {
         let mode = self.reaction_mode.as_ref()?;
         match mode {
@@ -850,6 +2049,134 @@ impl IssueConversation {
         }
     }
 
+    fn author_filter_prompt_text(&self) -> Option<String> {
+        let prompt = self.author_filter_prompt.as_ref()?;
+        let labels = prompt
+            .options
+            .iter()
+            .map(|author| author.as_ref())
+            .collect::<Vec<_>>();
+        let verb = match prompt.kind {
+            AuthorFilterKind::Only => "Show only",
+            AuthorFilterKind::Hide => "Hide",
+        };
+        Some(format!(
+            "{verb}: {}",
+            format_picker(prompt.selected, &labels)
+        ))
+    }
+
+    fn conversation_authors(&self) -> Vec<Arc<str>> {
+        let mut authors: Vec<Arc<str>> = Vec::new();
+        let seed_author = self.current.as_ref().map(|seed| seed.author.clone());
+        let comment_authors = self
+            .cache_comments
+            .iter()
+            .map(|comment| comment.author.clone());
+        for author in seed_author.into_iter().chain(comment_authors) {
+            if !authors
+                .iter()
+                .any(|a| a.as_ref().eq_ignore_ascii_case(author.as_ref()))
+            {
+                authors.push(author);
+            }
+        }
+        authors
+    }
+
+    fn start_author_filter_prompt(&mut self) {
+        let options = self.conversation_authors();
+        if options.is_empty() {
+            self.reaction_error = Some("No authors to filter by.".to_string());
+            return;
+        }
+        let selected = self
+            .author_filter
+            .as_ref()
+            .and_then(|filter| {
+                options
+                    .iter()
+                    .position(|a| a.as_ref().eq_ignore_ascii_case(filter.author.as_ref()))
+            })
+            .or_else(|| {
+                let author = self.selected_author()?;
+                options
+                    .iter()
+                    .position(|a| a.as_ref().eq_ignore_ascii_case(author.as_ref()))
+            })
+            .unwrap_or(0);
+        let kind = self
+            .author_filter
+            .as_ref()
+            .map_or(AuthorFilterKind::Only, |filter| filter.kind);
+        self.reaction_error = None;
+        self.author_filter_prompt = Some(AuthorFilterPrompt {
+            selected,
+            options,
+            kind,
+        });
+    }
+
+    fn selected_author(&self) -> Option<Arc<str>> {
+        let selected = self.list_state.selected_checked()?;
+        match self.message_keys.get(selected)? {
+            MessageKey::IssueBody(_) => self.current.as_ref().map(|seed| seed.author.clone()),
+            MessageKey::Comment(id) => self
+                .cache_comments
+                .iter()
+                .find(|c| c.id == *id)
+                .map(|c| c.author.clone()),
+            MessageKey::Timeline(_) => None,
+        }
+    }
+
+    fn set_author_filter(&mut self, filter: Option<AuthorFilter>) {
+        self.author_filter = filter;
+        let _ = self.list_state.select(Some(0));
+        self.body_paragraph_state.set_line_offset(0);
+    }
+
+    fn handle_author_filter_prompt_event(&mut self, event: &event::Event) -> bool {
+        let Some(prompt) = self.author_filter_prompt.as_mut() else {
+            return false;
+        };
+        let len = prompt.options.len();
+        match event {
+            ct_event!(keycode press Esc) => {
+                self.author_filter_prompt = None;
+            }
+            ct_event!(keycode press Up) | ct_event!(keycode press Left) => {
+                prompt.selected = if prompt.selected == 0 {
+                    len.saturating_sub(1)
+                } else {
+                    prompt.selected - 1
+                };
+            }
+            ct_event!(keycode press Down) | ct_event!(keycode press Right) => {
+                if len > 0 {
+                    prompt.selected = (prompt.selected + 1) % len;
+                }
+            }
+            ct_event!(keycode press Tab) | ct_event!(keycode press SHIFT-BackTab) => {
+                prompt.kind = prompt.kind.toggle();
+            }
+            ct_event!(keycode press Enter) => {
+                let filter = prompt
+                    .options
+                    .get(prompt.selected)
+                    .map(|author| AuthorFilter {
+                        author: author.clone(),
+                        kind: prompt.kind,
+                    });
+                self.author_filter_prompt = None;
+                self.set_author_filter(filter);
+            }
+            event::Event::Key(_) => {}
+            _ => return false,
+        }
+        true
+    }
+
     fn open_close_popup(&mut self) {
         let Some(seed) = &self.current else {
             self.close_error = Some("No issue selected.".to_string());
@@ -859,6 +2186,68 @@ impl IssueConversation {
         self.close_popup = Some(IssueClosePopupState::new(seed.number));
     }
 
+    /// Drops the empty-comment error once the composer has text again, or once it has been shown
+    /// for [`EMPTY_COMMENT_ERROR_TTL`] when `expire` is set.
+    fn clear_empty_error(&mut self, expire: bool) {
+        let Some(shown_at) = self.empty_error_at else {
+            return;
+        };
+        let stale = self.post_error.as_deref() != Some(EMPTY_COMMENT_ERROR);
+        let typed = !self.input_state.text().trim().is_empty();
+        let expired = expire && shown_at.elapsed() > EMPTY_COMMENT_ERROR_TTL;
+        if stale || typed || expired {
+            if !stale {
+                self.post_error = None;
+            }
+            self.empty_error_at = None;
+        }
+    }
+
+    /// Draws the comment editor with its status in the block title.
+    fn render_composer(&mut self, area: Rect, buf: &mut Buffer) {
+        let input_label = if self.editing_issue {
+            "Editing issue: title on the first line (Ctrl+Enter to save, Esc to cancel)"
+        } else if self.editing_comment.is_some() {
+            "Editing comment (Ctrl+Enter to save, Esc to cancel)"
+        } else {
+            "Comment (Ctrl+Enter to send)"
+        };
+        let input_title = if let Some(err) = &self.post_error {
+            format!("{input_label} | {err}")
+        } else {
+            input_label.to_string()
+        };
+        let chars = self.input_state.text().chars().count();
+        let count_style = if chars > self.comment_warn_chars {
+            Style::new().fg(Color::Red).add_modifier(Modifier::BOLD)
+        } else {
+            Style::new().dim()
+        };
+        let mut input_block = Block::bordered()
+            .border_type(ratatui::widgets::BorderType::Rounded)
+            .border_style(get_border_style(&self.input_state))
+            .title(Line::styled(format!(" {chars} chars "), count_style).right_aligned());
+        let mut input_style = Style::default();
+        if self.posting {
+            input_block = input_block.title_bottom(
+                Line::styled("locked until the comment is sent", Style::new().dim())
+                    .right_aligned(),
+            );
+            input_style = input_style.dim();
+        } else if self.is_locked() && !self.editing_issue {
+            input_block =
+                input_block.title("Conversation locked — comments are disabled (Alt+L to unlock)");
+            input_style = input_style.dim();
+        } else {
+            input_block = input_block.title(input_title);
+        }
+        let input_widget = TextArea::new()
+            .block(input_block)
+            .style(input_style)
+            .text_wrap(TextWrap::Word(4));
+        input_widget.render(area, buf, &mut self.input_state);
+    }
+
     fn render_close_popup(&mut self, area: Rect, buf: &mut Buffer) {
         let Some(popup) = self.close_popup.as_mut() else {
             return;
@@ -887,7 +2276,7 @@ impl IssueConversation {
         let repo = self.repo.clone();
         let issue_pool = self.issue_pool.clone();
         tokio::spawn(async move {
-            let Some(client) = GITHUB_CLIENT.get() else {
+            let Ok(client) = client() else {
                 let _ = action_tx
                     .send(Action::IssueCloseError {
                         number,
@@ -1097,7 +2486,7 @@ impl IssueConversation {
         let repo = self.repo.clone();
         let current_user = self.current_user.clone();
         tokio::spawn(async move {
-            let Some(client) = GITHUB_CLIENT.get() else {
+            let Ok(client) = client() else {
                 let _ = action_tx
                     .send(Action::IssueReactionEditError {
                         comment_id,
@@ -1152,7 +2541,7 @@ impl IssueConversation {
         let repo = self.repo.clone();
         let current_user = self.current_user.clone();
         tokio::spawn(async move {
-            let Some(client) = GITHUB_CLIENT.get() else {
+            let Ok(client) = client() else {
                 let _ = action_tx
                     .send(Action::IssueReactionEditError {
                         comment_id,
@@ -1248,7 +2637,7 @@ impl IssueConversation {
         self.error = None;
 
         tokio::spawn(async move {
-            let Some(client) = GITHUB_CLIENT.get() else {
+            let Ok(client) = client() else {
                 let _ = action_tx
                     .send(Action::IssueCommentsError {
                         number,
@@ -1257,24 +2646,63 @@ impl IssueConversation {
                     .await;
                 return;
             };
+            let first_page = format!(
+                "/repos/{owner}/{repo}/issues/{number}/comments?per_page={COMMENTS_PER_PAGE}&page=1"
+            );
             let handler = client.inner().issues(owner, repo);
-            let page = handler
-                .list_comments(number)
-                .per_page(100u8)
-                .page(1u32)
-                .send()
-                .await;
+            let page = with_retry(|| client.get_page_cached::<ApiComment>(&first_page)).await;
 
             match page {
                 Ok(mut p) => {
-                    let comments = std::mem::take(&mut p.items);
-                    let comment_ids = comments.iter().map(|c| c.id.0).collect::<Vec<_>>();
-                    let comments: Vec<CommentView> =
-                        comments.into_iter().map(CommentView::from_api).collect();
-                    trace!("Loaded {} comments for issue {}", comments.len(), number);
-                    let _ = action_tx
-                        .send(Action::IssueCommentsLoaded { number, comments })
-                        .await;
+                    let mut comment_ids = Vec::new();
+                    let mut first = true;
+                    // Each page is shown as soon as it arrives; the throbber keeps spinning until
+                    // the last one is in.
+                    loop {
+                        let comments = std::mem::take(&mut p.items);
+                        comment_ids.extend(comments.iter().map(|c| c.id.0));
+                        let comments: Vec<CommentView> =
+                            comments.into_iter().map(CommentView::from_api).collect();
+                        trace!("Loaded {} comments for issue {}", comments.len(), number);
+                        let more = p.next.is_some();
+                        let action = if first {
+                            Action::IssueCommentsLoaded {
+                                number,
+                                comments,
+                                more,
+                            }
+                        } else {
+                            Action::IssueCommentsPageLoaded {
+                                number,
+                                comments,
+                                more,
+                            }
+                        };
+                        let _ = action_tx.send(action).await;
+                        first = false;
+                        if !more {
+                            break;
+                        }
+                        let Some(next_url) = p.next.as_ref().map(|next| next.to_string()) else {
+                            break;
+                        };
+                        match with_retry(|| client.get_page_cached::<ApiComment>(&next_url)).await {
+                            Ok(next) => p = next,
+                            Err(err) => {
+                                let _ = action_tx
+                                    .send(Action::IssueCommentsError {
+                                        number,
+                                        message: describe_request_error(
+                                            &err,
+                                            rate_limit_resets_in(),
+                                        ),
+                                    })
+                                    .await;
+                                return;
+                            }
+                        }
+                    }
+                    client.save_etag_cache().await;
                     let refer = &handler;
                     let current_user = current_user.clone();
                     let reaction_snapshots = stream::iter(comment_ids)
@@ -1311,7 +2739,7 @@ impl IssueConversation {
                     let _ = action_tx
                         .send(Action::IssueCommentsError {
                             number,
-                            message: err.to_string().replace('\n', " "),
+                            message: describe_request_error(&err, rate_limit_resets_in()),
                         })
                         .await;
                 }
@@ -1332,7 +2760,7 @@ impl IssueConversation {
         self.timeline_error = None;
 
         tokio::spawn(async move {
-            let Some(client) = GITHUB_CLIENT.get() else {
+            let Ok(client) = client() else {
                 let _ = action_tx
                     .send(Action::IssueTimelineError {
                         number,
@@ -1373,69 +2801,711 @@ impl IssueConversation {
         });
     }
 
-    async fn send_comment(&mut self, number: u64, body: String) {
+    async fn fetch_pr_files(&mut self, number: u64) {
+        if self.pr_files_loading.contains(&number) {
+            return;
+        }
         let Some(action_tx) = self.action_tx.clone() else {
             return;
         };
         let owner = self.owner.clone();
         let repo = self.repo.clone();
-        self.posting = true;
-        self.post_error = None;
+        self.pr_files_loading.insert(number);
+        self.pr_files_error = None;
 
         tokio::spawn(async move {
-            let Some(client) = GITHUB_CLIENT.get() else {
+            let Ok(client) = client() else {
                 let _ = action_tx
-                    .send(Action::IssueCommentPostError {
+                    .send(Action::PullRequestFilesError {
                         number,
                         message: "GitHub client not initialized.".to_string(),
                     })
                     .await;
                 return;
             };
-            let handler = client.inner().issues(owner, repo);
-            match handler.create_comment(number, body).await {
-                Ok(comment) => {
+            match client.inner().pulls(owner, repo).list_files(number).await {
+                Ok(mut page) => {
+                    let truncated = page.next.is_some();
+                    let files = std::mem::take(&mut page.items)
+                        .into_iter()
+                        .map(PrFileView::from_api)
+                        .collect::<Vec<_>>();
                     let _ = action_tx
-                        .send(Action::IssueCommentPosted {
+                        .send(Action::PullRequestFilesLoaded {
                             number,
-                            comment: CommentView::from_api(comment),
+                            files,
+                            truncated,
                         })
                         .await;
-                    let _ = action_tx
-                        .send(toast_action("Comment Sent!", ToastType::Success))
-                        .await;
                 }
                 Err(err) => {
                     let _ = action_tx
-                        .send(Action::IssueCommentPostError {
+                        .send(Action::PullRequestFilesError {
                             number,
                             message: err.to_string().replace('\n', " "),
                         })
                         .await;
-                    let _ = action_tx
-                        .send(toast_action("Failed to send comment", ToastType::Error))
-                        .await;
                 }
             }
         });
     }
-}
 
-#[async_trait(?Send)]
-impl Component for IssueConversation {
-    fn render(&mut self, area: Layout, buf: &mut Buffer) {
-        self.render(area, buf);
-    }
+    fn fetch_subscription(&mut self, number: u64) {
+        let Some(action_tx) = self.action_tx.clone() else {
+            return;
+        };
+        let owner = self.owner.clone();
+        let repo = self.repo.clone();
+        self.subscription = None;
+        self.subscription_number = None;
 
-    fn register_action_tx(&mut self, action_tx: tokio::sync::mpsc::Sender<Action>) {
-        self.action_tx = Some(action_tx);
+        tokio::spawn(async move {
+            let Ok(client) = client() else {
+                return;
+            };
+            let state = match client.issue_subscription(&owner, &repo, number).await {
+                Ok(state) => state,
+                Err(err) => {
+                    trace!(number, error = %err, "failed to read issue subscription");
+                    None
+                }
+            };
+            let _ = action_tx
+                .send(Action::IssueSubscriptionLoaded { number, state })
+                .await;
+        });
     }
 
-    async fn handle_event(&mut self, event: Action) -> Result<(), AppError> {
-        match event {
-            Action::AppEvent(ref event) => {
-                if !self.in_details_mode() {
-                    return Ok(());
+    async fn toggle_subscription(&mut self) {
+        let Some(number) = self.current.as_ref().map(|seed| seed.number) else {
+            return;
+        };
+        let Some(action_tx) = self.action_tx.clone() else {
+            return;
+        };
+        if self.subscription_pending == Some(number) {
+            return;
+        }
+        let subscribe = self.subscription_number != Some(number)
+            || self.subscription != Some(SubscriptionState::Subscribed);
+        let owner = self.owner.clone();
+        let repo = self.repo.clone();
+        self.subscription_pending = Some(number);
+
+        tokio::spawn(async move {
+            let Ok(client) = client() else {
+                let _ = action_tx
+                    .send(Action::IssueSubscriptionError {
+                        number,
+                        message: "GitHub client not initialized.".to_string(),
+                    })
+                    .await;
+                return;
+            };
+            match client
+                .set_issue_subscription(&owner, &repo, number, subscribe)
+                .await
+            {
+                Ok(state) => {
+                    let _ = action_tx
+                        .send(Action::IssueSubscriptionLoaded { number, state })
+                        .await;
+                    let message = if subscribe {
+                        format!("Subscribed to #{number}.")
+                    } else {
+                        format!("Unsubscribed from #{number}.")
+                    };
+                    let _ = action_tx.send(toast_action(message, ToastType::Info)).await;
+                }
+                Err(err) => {
+                    let _ = action_tx
+                        .send(Action::IssueSubscriptionError {
+                            number,
+                            message: err.to_string().replace('\n', " "),
+                        })
+                        .await;
+                }
+            }
+        });
+    }
+
+    fn is_locked(&self) -> bool {
+        self.current.as_ref().is_some_and(|seed| seed.locked)
+    }
+
+    fn open_lock_popup(&mut self) {
+        let Some(seed) = &self.current else {
+            self.lock_error = Some("No issue selected.".to_string());
+            return;
+        };
+        if seed.locked {
+            self.lock_error = Some("Conversation is already locked.".to_string());
+            return;
+        }
+        self.lock_error = None;
+        self.lock_popup = Some(LockPopupState::new(seed.number));
+    }
+
+    fn handle_lock_popup_event(&mut self, event: &event::Event) -> bool {
+        let Some(popup) = self.lock_popup.as_mut() else {
+            return false;
+        };
+        match event {
+            ct_event!(keycode press Esc) => self.lock_popup = None,
+            ct_event!(keycode press Up) => popup.reason_state.select_previous(),
+            ct_event!(keycode press Down) => popup.reason_state.select_next(),
+            ct_event!(keycode press Enter) => {
+                let reason = popup.selected_reason();
+                self.set_locked(Some(reason));
+            }
+            _ => {}
+        }
+        true
+    }
+
+    fn render_lock_popup(&mut self, area: Rect, buf: &mut Buffer) {
+        let Some(popup) = self.lock_popup.as_mut() else {
+            return;
+        };
+        let popup_area = area.centered(Constraint::Percentage(20), Constraint::Length(6));
+        Clear.render(popup_area, buf);
+
+        let title = format!("Lock conversation #{}", popup.issue_number);
+        let block = Block::bordered()
+            .border_type(ratatui::widgets::BorderType::Rounded)
+            .title_bottom("Enter: lock  Esc: cancel")
+            .title(title);
+        let inner = block.inner(popup_area);
+        block.render(popup_area, buf);
+
+        let items = LockIssueReason::ALL
+            .iter()
+            .map(|reason| ListItem::new(reason.label()))
+            .collect::<Vec<_>>();
+        let list = TuiList::new(items)
+            .highlight_style(Style::new().fg(Color::Cyan).add_modifier(Modifier::BOLD))
+            .highlight_symbol("> ");
+        StatefulWidget::render(list, inner, buf, &mut popup.reason_state);
+    }
+
+    /// Locks the conversation with `reason`, or unlocks it when `reason` is `None`.
+    fn set_locked(&mut self, reason: Option<LockIssueReason>) {
+        let Some(seed) = self.current.as_ref() else {
+            return;
+        };
+        let number = seed.number;
+        let lock = reason.is_some();
+        if !lock && !seed.locked {
+            self.lock_error = Some("Conversation is not locked.".to_string());
+            return;
+        }
+        if self.lock_pending == Some(number) {
+            return;
+        }
+        let Some(action_tx) = self.action_tx.clone() else {
+            return;
+        };
+        self.lock_popup = None;
+        self.lock_pending = Some(number);
+        let owner = self.owner.clone();
+        let repo = self.repo.clone();
+
+        tokio::spawn(async move {
+            let Ok(client) = client() else {
+                let _ = action_tx
+                    .send(Action::IssueLockError {
+                        number,
+                        message: "GitHub client not initialized.".to_string(),
+                    })
+                    .await;
+                return;
+            };
+            let issues = client.inner().issues(owner, repo);
+            let result = match reason {
+                Some(reason) => issues.lock(number, reason.to_octocrab()).await,
+                None => issues.unlock(number).await,
+            };
+            let action = match result {
+                Ok(true) => Action::IssueLockChanged {
+                    number,
+                    locked: lock,
+                },
+                Ok(false) => Action::IssueLockError {
+                    number,
+                    message: INSUFFICIENT_LOCK_PERMISSIONS.to_string(),
+                },
+                Err(err) => Action::IssueLockError {
+                    number,
+                    message: lock_error_message(&err),
+                },
+            };
+            let _ = action_tx.send(action).await;
+        });
+    }
+
+    fn open_assignee_popup(&mut self) {
+        if let Some(seed) = &self.current {
+            self.assignee_popup = Some(AssigneePopupState::new(seed.number));
+        }
+    }
+
+    fn handle_assignee_popup_event(&mut self, event: &event::Event) -> bool {
+        let Some(popup) = self.assignee_popup.as_mut() else {
+            return false;
+        };
+        let mut change = None;
+        match event {
+            ct_event!(keycode press Esc) => {
+                self.assignee_popup = None;
+                return true;
+            }
+            ct_event!(keycode press Up) => popup.selected.select_previous(),
+            ct_event!(keycode press Down) => popup.selected.select_next(),
+            ct_event!(keycode press Enter) => match parse_logins(popup.input.text()) {
+                Ok(logins) => change = Some(AssigneeChange::Add(logins)),
+                Err(message) => popup.error = Some(message),
+            },
+            ct_event!(keycode press Delete) if popup.input.text().is_empty() => {
+                let selected = popup.selected.selected().and_then(|idx| {
+                    self.current
+                        .as_ref()
+                        .and_then(|seed| seed.assignees.get(idx))
+                });
+                match selected {
+                    Some(login) => change = Some(AssigneeChange::Remove(login.to_string())),
+                    None => popup.error = Some("No assignee selected.".to_string()),
+                }
+            }
+            _ => {
+                popup.input.handle(event, rat_widget::event::Regular);
+            }
+        }
+        if let Some(change) = change {
+            self.change_assignees(change);
+        }
+        true
+    }
+
+    fn render_assignee_popup(&mut self, area: Rect, buf: &mut Buffer) {
+        let Some(popup) = self.assignee_popup.as_mut() else {
+            return;
+        };
+        let assignees = self
+            .current
+            .as_ref()
+            .map(|seed| seed.assignees.clone())
+            .unwrap_or_default();
+        let rows = assignees.len().clamp(1, 8) as u16;
+        let popup_area = area.centered(Constraint::Percentage(40), Constraint::Length(rows + 5));
+        Clear.render(popup_area, buf);
+
+        let block = Block::bordered()
+            .border_type(ratatui::widgets::BorderType::Rounded)
+            .title(format!("Assignees of #{}", popup.issue_number))
+            .title_bottom(if popup.pending {
+                "Updating…"
+            } else {
+                "Enter: assign  Del: remove selected  Esc: close"
+            });
+        let inner = block.inner(popup_area);
+        block.render(popup_area, buf);
+        let [list_area, input_area] = inner.layout(&ratatui::layout::Layout::vertical([
+            Constraint::Min(1),
+            Constraint::Length(3),
+        ]));
+
+        if assignees.is_empty() {
+            Line::from("No one is assigned.")
+                .dim()
+                .render(list_area, buf);
+        } else {
+            let items = assignees
+                .iter()
+                .map(|login| ListItem::new(format!("@{login}")))
+                .collect::<Vec<_>>();
+            let list = TuiList::new(items)
+                .highlight_style(Style::new().fg(Color::Cyan).add_modifier(Modifier::BOLD))
+                .highlight_symbol("> ");
+            StatefulWidget::render(list, list_area, buf, &mut popup.selected);
+        }
+
+        let input_block = Block::bordered()
+            .border_type(ratatui::widgets::BorderType::Rounded)
+            .title(match &popup.error {
+                Some(err) => format!("Assign logins | {err}"),
+                None => "Assign logins (comma separated)".to_string(),
+            });
+        TextInput::new()
+            .block(input_block)
+            .render(input_area, buf, &mut popup.input);
+    }
+
+    fn change_assignees(&mut self, change: AssigneeChange) {
+        let Some(popup) = self.assignee_popup.as_mut() else {
+            return;
+        };
+        if popup.pending {
+            return;
+        }
+        let Some(action_tx) = self.action_tx.clone() else {
+            return;
+        };
+        popup.pending = true;
+        popup.error = None;
+        let number = popup.issue_number;
+        let owner = self.owner.clone();
+        let repo = self.repo.clone();
+
+        tokio::spawn(async move {
+            let Ok(client) = client() else {
+                let _ = action_tx
+                    .send(Action::IssueAssigneesError {
+                        number,
+                        message: "GitHub client not initialized.".to_string(),
+                    })
+                    .await;
+                return;
+            };
+            let issues = client.inner().issues(owner, repo);
+            let result = match &change {
+                AssigneeChange::Add(logins) => {
+                    let logins = logins.iter().map(String::as_str).collect::<Vec<_>>();
+                    issues.add_assignees(number, &logins).await
+                }
+                AssigneeChange::Remove(login) => {
+                    issues.remove_assignees(number, &[login.as_str()]).await
+                }
+            };
+            let action = match result {
+                Ok(issue) => {
+                    // GitHub silently skips logins that can't be assigned to the repository.
+                    if let AssigneeChange::Add(logins) = &change {
+                        let skipped = logins
+                            .iter()
+                            .filter(|login| {
+                                !issue
+                                    .assignees
+                                    .iter()
+                                    .any(|a| a.login.eq_ignore_ascii_case(login))
+                            })
+                            .map(String::as_str)
+                            .collect::<Vec<_>>();
+                        if !skipped.is_empty() {
+                            let message = format!(
+                                "GitHub didn't assign {}; they may lack access to the repository.",
+                                skipped.join(", ")
+                            );
+                            let _ = action_tx
+                                .send(toast_action(message, ToastType::Warning))
+                                .await;
+                        }
+                    }
+                    Action::IssueAssigneesUpdated {
+                        number,
+                        assignees: issue.assignees,
+                    }
+                }
+                Err(err) => Action::IssueAssigneesError {
+                    number,
+                    message: err.to_string().replace('\n', " "),
+                },
+            };
+            let _ = action_tx.send(action).await;
+        });
+    }
+
+    fn open_milestone_popup(&mut self) {
+        let Some(seed) = &self.current else {
+            return;
+        };
+        let Some(action_tx) = self.action_tx.clone() else {
+            return;
+        };
+        let number = seed.number;
+        self.milestone_popup = Some(MilestonePopupState::new(number));
+        let owner = self.owner.clone();
+        let repo = self.repo.clone();
+
+        tokio::spawn(async move {
+            let Ok(client) = client() else {
+                let _ = action_tx
+                    .send(Action::IssueMilestoneError {
+                        number,
+                        message: "GitHub client not initialized.".to_string(),
+                    })
+                    .await;
+                return;
+            };
+            let action = match client.open_milestones(&owner, &repo).await {
+                Ok(milestones) => Action::MilestonesLoaded { number, milestones },
+                Err(err) => Action::IssueMilestoneError {
+                    number,
+                    message: err.to_string().replace('\n', " "),
+                },
+            };
+            let _ = action_tx.send(action).await;
+        });
+    }
+
+    fn handle_milestone_popup_event(&mut self, event: &event::Event) -> bool {
+        let Some(popup) = self.milestone_popup.as_mut() else {
+            return false;
+        };
+        match event {
+            ct_event!(keycode press Esc) => self.milestone_popup = None,
+            ct_event!(keycode press Up) => popup.selected.select_previous(),
+            ct_event!(keycode press Down) => popup.selected.select_next(),
+            ct_event!(keycode press Enter) if !popup.pending => {
+                let Some(milestones) = &popup.milestones else {
+                    return true;
+                };
+                // Row 0 is "No milestone".
+                let milestone = popup
+                    .selected
+                    .selected()
+                    .and_then(|idx| idx.checked_sub(1))
+                    .and_then(|idx| milestones.get(idx))
+                    .map(|(number, _)| *number);
+                self.set_milestone(milestone);
+            }
+            _ => {}
+        }
+        true
+    }
+
+    fn render_milestone_popup(&mut self, area: Rect, buf: &mut Buffer) {
+        let Some(popup) = self.milestone_popup.as_mut() else {
+            return;
+        };
+        let rows = popup
+            .milestones
+            .as_ref()
+            .map_or(1, |milestones| milestones.len() + 1)
+            .min(10) as u16;
+        let popup_area = area.centered(Constraint::Percentage(30), Constraint::Length(rows + 2));
+        Clear.render(popup_area, buf);
+
+        let mut block = Block::bordered()
+            .border_type(ratatui::widgets::BorderType::Rounded)
+            .title(format!("Milestone of #{}", popup.issue_number))
+            .title_bottom(match &popup.error {
+                Some(err) => err.clone(),
+                None if popup.pending => "Updating…".to_string(),
+                None => "Enter: set  Esc: cancel".to_string(),
+            });
+        if popup.error.is_some() {
+            block = block.border_style(Style::new().red());
+        }
+        let inner = block.inner(popup_area);
+        block.render(popup_area, buf);
+
+        let Some(milestones) = &popup.milestones else {
+            Line::from("Loading milestones…").dim().render(inner, buf);
+            return;
+        };
+        let items = std::iter::once(ListItem::new(Line::from("No milestone").dim()))
+            .chain(
+                milestones
+                    .iter()
+                    .map(|(_, title)| ListItem::new(format!("⚑ {title}"))),
+            )
+            .collect::<Vec<_>>();
+        let list = TuiList::new(items)
+            .highlight_style(Style::new().fg(Color::Cyan).add_modifier(Modifier::BOLD))
+            .highlight_symbol("> ");
+        StatefulWidget::render(list, inner, buf, &mut popup.selected);
+    }
+
+    /// Sets the milestone of the issue in the picker, or clears it when `milestone` is `None`.
+    fn set_milestone(&mut self, milestone: Option<i64>) {
+        let Some(popup) = self.milestone_popup.as_mut() else {
+            return;
+        };
+        let Some(action_tx) = self.action_tx.clone() else {
+            return;
+        };
+        popup.pending = true;
+        popup.error = None;
+        let number = popup.issue_number;
+        let owner = self.owner.clone();
+        let repo = self.repo.clone();
+
+        tokio::spawn(async move {
+            let Ok(client) = client() else {
+                let _ = action_tx
+                    .send(Action::IssueMilestoneError {
+                        number,
+                        message: "GitHub client not initialized.".to_string(),
+                    })
+                    .await;
+                return;
+            };
+            let action = match client
+                .set_issue_milestone(&owner, &repo, number, milestone)
+                .await
+            {
+                Ok(issue) => Action::IssueMilestoneUpdated {
+                    number,
+                    milestone: issue.milestone.map(|milestone| milestone.title),
+                },
+                Err(err) => Action::IssueMilestoneError {
+                    number,
+                    message: err.to_string().replace('\n', " "),
+                },
+            };
+            let _ = action_tx.send(action).await;
+        });
+    }
+
+    fn subscription_label(&self) -> &'static str {
+        let number = self.current.as_ref().map(|seed| seed.number);
+        if number.is_some() && self.subscription_pending == number {
+            return "updating subscription…";
+        }
+        match self
+            .subscription
+            .filter(|_| self.subscription_number == number)
+        {
+            Some(state) => state.label(),
+            None => "subscription unknown",
+        }
+    }
+
+    /// Restarts the background poll for new comments on `number`, if polling is enabled.
+    fn start_comment_poll(&mut self, number: u64) {
+        if let Some(task) = self.poll_task.take() {
+            task.abort();
+        }
+        let (Some(period), Some(action_tx)) = (self.poll_interval, self.action_tx.clone()) else {
+            return;
+        };
+        let owner = self.owner.clone();
+        let repo = self.repo.clone();
+        let gate = self.poll_gate.clone();
+
+        self.poll_task = Some(tokio::spawn(async move {
+            let mut interval = tokio::time::interval(period);
+            interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Skip);
+            interval.tick().await;
+            loop {
+                interval.tick().await;
+                if !gate.lock().is_ok_and(|gate| gate.is_open()) {
+                    continue;
+                }
+                let Ok(client) = client() else {
+                    return;
+                };
+                match client.inner().issues(&owner, &repo).get(number).await {
+                    Ok(issue) => {
+                        let polled = Action::IssueCommentCountPolled {
+                            number,
+                            count: issue.comments,
+                        };
+                        if action_tx.send(polled).await.is_err() {
+                            return;
+                        }
+                    }
+                    Err(err) => trace!(number, error = %err, "comment poll failed"),
+                }
+            }
+        }));
+    }
+
+    fn note_app_event(&self, event: &event::Event) {
+        let Ok(mut gate) = self.poll_gate.lock() else {
+            return;
+        };
+        match event {
+            event::Event::FocusGained => gate.focused = true,
+            event::Event::FocusLost => gate.focused = false,
+            event::Event::Key(_) | event::Event::Mouse(_) | event::Event::Paste(_) => {
+                gate.focused = true;
+                gate.last_input = Instant::now();
+            }
+            _ => {}
+        }
+    }
+
+    async fn send_comment(&mut self, number: u64, body: String) {
+        let Some(action_tx) = self.action_tx.clone() else {
+            return;
+        };
+        let owner = self.owner.clone();
+        let repo = self.repo.clone();
+        self.posting = true;
+        self.post_error = None;
+
+        tokio::spawn(async move {
+            let Ok(client) = client() else {
+                let _ = action_tx
+                    .send(Action::IssueCommentPostError {
+                        number,
+                        message: "GitHub client not initialized.".to_string(),
+                    })
+                    .await;
+                return;
+            };
+            let handler = client.inner().issues(owner, repo);
+            match with_connect_retry(|| handler.create_comment(number, &body)).await {
+                Ok(comment) => {
+                    let _ = action_tx
+                        .send(Action::IssueCommentPosted {
+                            number,
+                            comment: CommentView::from_api(comment),
+                        })
+                        .await;
+                    let _ = action_tx
+                        .send(toast_action("Comment Sent!", ToastType::Success))
+                        .await;
+                }
+                Err(err) => {
+                    let _ = action_tx
+                        .send(Action::IssueCommentPostError {
+                            number,
+                            message: describe_request_error(&err, rate_limit_resets_in()),
+                        })
+                        .await;
+                    let _ = action_tx
+                        .send(toast_action("Failed to send comment", ToastType::Error))
+                        .await;
+                }
+            }
+        });
+    }
+}
+
+#[async_trait(?Send)]
+impl Component for IssueConversation {
+    fn render(&mut self, area: Layout, buf: &mut Buffer) {
+        self.render(area, buf);
+    }
+
+    fn register_action_tx(&mut self, action_tx: tokio::sync::mpsc::Sender<Action>) {
+        let gate = self.poll_gate.clone();
+        let clock_tx = action_tx.clone();
+        tokio::spawn(async move {
+            let mut interval = tokio::time::interval(RELATIVE_TIME_REFRESH);
+            interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Skip);
+            interval.tick().await;
+            loop {
+                interval.tick().await;
+                if !gate.lock().is_ok_and(|gate| gate.viewing) {
+                    continue;
+                }
+                if clock_tx.send(Action::ForceRender).await.is_err() {
+                    return;
+                }
+            }
+        });
+        self.action_tx = Some(action_tx);
+    }
+
+    async fn handle_event(&mut self, event: Action) -> Result<(), AppError> {
+        match event {
+            Action::AppEvent(ref event) => {
+                self.note_app_event(event);
+                if !self.in_details_mode() {
+                    return Ok(());
                 }
                 if self.screen == MainScreen::DetailsFullscreen
                     && matches!(
@@ -1453,9 +3523,36 @@ impl Component for IssueConversation {
                 if self.handle_close_popup_event(event).await {
                     return Ok(());
                 }
+                if self.handle_lock_popup_event(event) {
+                    return Ok(());
+                }
+                if self.handle_assignee_popup_event(event) {
+                    return Ok(());
+                }
+                if self.handle_milestone_popup_event(event) {
+                    return Ok(());
+                }
                 if self.handle_reaction_mode_event(event).await {
                     return Ok(());
                 }
+                if self.handle_author_filter_prompt_event(event) {
+                    if let Some(tx) = self.action_tx.clone() {
+                        let _ = tx.send(Action::ForceRender).await;
+                    }
+                    return Ok(());
+                }
+                if self.handle_delete_confirm_event(event).await {
+                    return Ok(());
+                }
+                if self.handle_state_confirm_event(event).await {
+                    return Ok(());
+                }
+                if self.handle_mention_event(event) {
+                    if let Some(tx) = self.action_tx.clone() {
+                        let _ = tx.send(Action::ForceRender).await;
+                    }
+                    return Ok(());
+                }
 
                 match event {
                     event::Event::Key(key)
@@ -1526,6 +3623,38 @@ impl Component for IssueConversation {
                             tx.send(Action::ForceRender).await?;
                         }
                     }
+                    _ if pressed(KeyAction::CopyMessage, event)
+                        && (self.body_paragraph_state.is_focused()
+                            || self.list_state.is_focused()) =>
+                    {
+                        let Some(selected) = self
+                            .list_state
+                            .selected_checked()
+                            .and_then(|idx| self.message_keys.get(idx))
+                        else {
+                            return Ok(());
+                        };
+                        let (body, message) = match selected {
+                            MessageKey::IssueBody(_) => (
+                                self.current.as_ref().and_then(|seed| seed.body.clone()),
+                                "Copied issue body",
+                            ),
+                            MessageKey::Comment(_) => (
+                                self.selected_comment().map(|comment| comment.body.clone()),
+                                "Copied comment",
+                            ),
+                            MessageKey::Timeline(_) => return Ok(()),
+                        };
+                        let Some(body) = body else {
+                            return Ok(());
+                        };
+                        cli_clipboard::set_contents(body.to_string())
+                            .map_err(|_| anyhow!("Error copying to clipboard"))?;
+                        if let Some(tx) = self.action_tx.clone() {
+                            tx.send(toast_action(message, ToastType::Success)).await?;
+                            tx.send(Action::ForceRender).await?;
+                        }
+                    }
                     event::Event::Key(key)
                         if key.code == event::KeyCode::Char('f')
                             && key.modifiers == event::KeyModifiers::NONE
@@ -1539,11 +3668,83 @@ impl Component for IssueConversation {
                         }
                         return Ok(());
                     }
-                    event::Event::Key(key)
-                        if key.code == event::KeyCode::Char('e')
-                            && key.modifiers == event::KeyModifiers::NONE
-                            && (self.list_state.is_focused()
-                                || self.body_paragraph_state.is_focused()) =>
+                    _ if pressed(KeyAction::EditComment, event)
+                        && !self.posting
+                        && (self.list_state.is_focused()
+                            || self.body_paragraph_state.is_focused()) =>
+                    {
+                        let Some(comment) = self.selected_comment() else {
+                            return Err(AppError::Other(anyhow!("select a comment to edit")));
+                        };
+                        if !is_self_login(&self.self_logins, comment.author.as_ref()) {
+                            self.post_error = Some(
+                                "Only your own comments can be edited here (Alt+E opens the external editor)."
+                                    .to_string(),
+                            );
+                            return Ok(());
+                        }
+                        let (id, body) = (comment.id, comment.body.to_string());
+                        self.enter_edit_mode(&body);
+                        self.editing_comment = Some(id);
+                        self.editing_issue = false;
+                        self.post_error = None;
+                        self.textbox_state = InputState::Input;
+                        self.input_state.focus.set(true);
+                        self.paragraph_state.focus.set(false);
+                        self.list_state.focus.set(false);
+                        self.body_paragraph_state.focus.set(false);
+                        if let Some(tx) = self.action_tx.clone() {
+                            let _ = tx.send(Action::ForceRender).await;
+                        }
+                        return Ok(());
+                    }
+                    _ if pressed(KeyAction::EditIssue, event)
+                        && !self.posting
+                        && (self.list_state.is_focused()
+                            || self.body_paragraph_state.is_focused()) =>
+                    {
+                        let Some(seed) = &self.current else {
+                            return Ok(());
+                        };
+                        let text = format!(
+                            "{}\n\n{}",
+                            seed.title.as_deref().unwrap_or_default(),
+                            seed.body.as_deref().unwrap_or_default()
+                        );
+                        self.enter_edit_mode(text.trim_end());
+                        self.editing_issue = true;
+                        self.editing_comment = None;
+                        self.post_error = None;
+                        self.textbox_state = InputState::Input;
+                        self.input_state.focus.set(true);
+                        self.paragraph_state.focus.set(false);
+                        self.list_state.focus.set(false);
+                        self.body_paragraph_state.focus.set(false);
+                        if let Some(tx) = self.action_tx.clone() {
+                            let _ = tx.send(Action::ForceRender).await;
+                        }
+                        return Ok(());
+                    }
+                    _ if pressed(KeyAction::DeleteComment, event)
+                        && self.list_state.is_focused() =>
+                    {
+                        let Some(comment) = self.selected_comment() else {
+                            return Ok(());
+                        };
+                        if !is_self_login(&self.self_logins, comment.author.as_ref()) {
+                            self.post_error =
+                                Some("Only your own comments can be deleted.".to_string());
+                            return Ok(());
+                        }
+                        self.delete_confirm = Some(comment.id);
+                        if let Some(tx) = self.action_tx.clone() {
+                            let _ = tx.send(Action::ForceRender).await;
+                        }
+                        return Ok(());
+                    }
+                    _ if pressed(KeyAction::ExternalEditComment, event)
+                        && (self.list_state.is_focused()
+                            || self.body_paragraph_state.is_focused()) =>
                     {
                         let seed = self.current.as_ref().ok_or_else(|| {
                             AppError::Other(anyhow!("no issue selected for comment editing"))
@@ -1560,18 +3761,94 @@ impl Component for IssueConversation {
                         return Ok(());
                     }
                     event::Event::Key(key)
-                        if key.code == event::KeyCode::Char('r')
+                        if key.code == event::KeyCode::Char('s')
                             && key.modifiers == event::KeyModifiers::NONE
                             && self.list_state.is_focused() =>
                     {
+                        self.toggle_subscription().await;
+                        return Ok(());
+                    }
+                    _ if pressed(KeyAction::ReloadComments, event) => {
+                        if let Some(seed) = self.current.as_ref() {
+                            let number = seed.number;
+                            self.new_comments = 0;
+                            self.fetch_comments(number).await;
+                        }
+                        return Ok(());
+                    }
+                    _ if pressed(KeyAction::AddReaction, event) && self.list_state.is_focused() => {
                         self.start_add_reaction_mode();
                         return Ok(());
                     }
+                    _ if pressed(KeyAction::RemoveReaction, event)
+                        && self.list_state.is_focused() =>
+                    {
+                        self.start_remove_reaction_mode();
+                        return Ok(());
+                    }
                     event::Event::Key(key)
-                        if key.code == event::KeyCode::Char('R')
+                        if key.code == event::KeyCode::Char('u')
+                            && key.modifiers == event::KeyModifiers::NONE
                             && self.list_state.is_focused() =>
                     {
-                        self.start_remove_reaction_mode();
+                        self.start_author_filter_prompt();
+                        return Ok(());
+                    }
+                    event::Event::Key(key)
+                        if key.code == event::KeyCode::Char('T')
+                            && (self.list_state.is_focused()
+                                || self.body_paragraph_state.is_focused()) =>
+                    {
+                        self.absolute_times = !self.absolute_times;
+                        if let Some(tx) = self.action_tx.clone() {
+                            let _ = tx.send(Action::ForceRender).await;
+                        }
+                        return Ok(());
+                    }
+                    event::Event::Key(key)
+                        if key.code == event::KeyCode::Char('U')
+                            && self.list_state.is_focused() =>
+                    {
+                        if self.author_filter.is_some() {
+                            self.set_author_filter(None);
+                        }
+                        return Ok(());
+                    }
+                    event::Event::Key(key)
+                        if key.code == event::KeyCode::Char('p')
+                            && key.modifiers == event::KeyModifiers::NONE
+                            && self.list_state.is_focused() =>
+                    {
+                        self.toggle_pin().await;
+                        return Ok(());
+                    }
+                    event::Event::Key(key)
+                        if key.code == event::KeyCode::Char('n')
+                            && key.modifiers == event::KeyModifiers::NONE
+                            && self.list_state.is_focused() =>
+                    {
+                        if let Some(index) = self.oldest_unread_index() {
+                            let _ = self.list_state.select(Some(index));
+                            self.body_paragraph_state.set_line_offset(0);
+                        } else if let Some(tx) = self.action_tx.clone() {
+                            let _ = tx
+                                .send(toast_action("No unread comments", ToastType::Info))
+                                .await;
+                        }
+                        return Ok(());
+                    }
+                    _ if pressed(KeyAction::OpenInBrowser, event)
+                        && (self.list_state.is_focused()
+                            || self.body_paragraph_state.is_focused()) =>
+                    {
+                        self.open_in_browser().await;
+                        return Ok(());
+                    }
+                    _ if pressed(KeyAction::ToggleIssueState, event)
+                        && (self.list_state.is_focused()
+                            || self.body_paragraph_state.is_focused()) =>
+                    {
+                        self.confirm_state_toggle();
                         return Ok(());
                     }
                     event::Event::Key(key)
@@ -1582,6 +3859,35 @@ impl Component for IssueConversation {
                         self.open_close_popup();
                         return Ok(());
                     }
+                    event::Event::Key(key)
+                        if key.code == event::KeyCode::Char('L')
+                            && (self.list_state.is_focused()
+                                || self.body_paragraph_state.is_focused()) =>
+                    {
+                        self.open_lock_popup();
+                        return Ok(());
+                    }
+                    ct_event!(key press ALT-'l')
+                        if self.list_state.is_focused()
+                            || self.body_paragraph_state.is_focused() =>
+                    {
+                        self.set_locked(None);
+                        return Ok(());
+                    }
+                    _ if pressed(KeyAction::EditAssignees, event)
+                        && (self.list_state.is_focused()
+                            || self.body_paragraph_state.is_focused()) =>
+                    {
+                        self.open_assignee_popup();
+                        return Ok(());
+                    }
+                    _ if pressed(KeyAction::SetMilestone, event)
+                        && (self.list_state.is_focused()
+                            || self.body_paragraph_state.is_focused()) =>
+                    {
+                        self.open_milestone_popup();
+                        return Ok(());
+                    }
                     ct_event!(keycode press Tab) if self.input_state.is_focused() => {
                         let action_tx = self.action_tx.as_ref().ok_or_else(|| {
                             AppError::Other(anyhow!(
@@ -1606,28 +3912,47 @@ impl Component for IssueConversation {
                         })?;
                         action_tx.send(Action::ForceFocusChangeRev).await?;
                     }
+                    ct_event!(keycode press Esc)
+                        if (self.editing_comment.is_some() || self.editing_issue)
+                            && !self.posting
+                            && self.input_state.is_focused() =>
+                    {
+                        self.leave_edit_mode();
+                        self.post_error = None;
+                        return Ok(());
+                    }
                     ct_event!(keycode press Esc) if !self.body_paragraph_state.is_focused() => {
                         if let Some(tx) = self.action_tx.clone() {
                             let _ = tx.send(Action::ChangeIssueScreen(MainScreen::List)).await;
                         }
                         return Ok(());
                     }
-                    ct_event!(key press CONTROL-'p') => {
+                    _ if pressed(KeyAction::TogglePreview, event) => {
+                        // The preview opens beside the editor, so typing carries on and the
+                        // preview follows every edit.
                         self.textbox_state.toggle();
-                        match self.textbox_state {
-                            InputState::Input => {
-                                self.input_state.focus.set(true);
-                                self.paragraph_state.focus.set(false);
-                            }
-                            InputState::Preview => {
-                                self.input_state.focus.set(false);
-                                self.paragraph_state.focus.set(true);
-                            }
-                        }
+                        self.input_state.focus.set(true);
+                        self.paragraph_state.focus.set(false);
                         if let Some(ref tx) = self.action_tx {
                             let _ = tx.send(Action::ForceRender).await;
                         }
                     }
+                    _ if pressed(KeyAction::ToggleDetails, event)
+                        && (self.list_state.is_focused()
+                            || self.body_paragraph_state.is_focused()) =>
+                    {
+                        self.toggle_selected_details();
+                    }
+                    ct_event!(keycode press Enter)
+                        if self.list_state.is_focused() && self.toggle_selected_comment(false) =>
+                    {
+                        return Ok(());
+                    }
+                    ct_event!(key press ' ')
+                        if self.list_state.is_focused() && self.toggle_selected_comment(true) =>
+                    {
+                        return Ok(());
+                    }
                     ct_event!(keycode press Enter) if self.list_state.is_focused() => {
                         let action_tx = self.action_tx.as_ref().ok_or_else(|| {
                             AppError::Other(anyhow!(
@@ -1636,41 +3961,87 @@ impl Component for IssueConversation {
                         })?;
                         action_tx.send(Action::ForceFocusChange).await?;
                     }
-                    ct_event!(keycode press CONTROL-Enter) | ct_event!(keycode press ALT-Enter) => {
+                    _ if pressed(KeyAction::SendComment, event) => {
                         let Some(seed) = &self.current else {
                             return Ok(());
                         };
+                        if self.posting {
+                            if let Some(tx) = self.action_tx.clone() {
+                                let _ = tx
+                                    .send(toast_action(
+                                        "Still sending the previous comment…",
+                                        ToastType::Warning,
+                                    ))
+                                    .await;
+                            }
+                            return Ok(());
+                        }
+                        if self.editing_issue {
+                            let number = seed.number;
+                            match split_issue_text(&self.input_state.text()) {
+                                Ok((_, body)) if let Some(message) = length_error(&body) => {
+                                    self.post_error = Some(message);
+                                }
+                                Ok((title, body)) => {
+                                    self.posting = true;
+                                    self.post_error = None;
+                                    self.patch_issue(number, title, body);
+                                }
+                                Err(message) => self.post_error = Some(message),
+                            }
+                            return Ok(());
+                        }
+                        if seed.locked {
+                            self.post_error =
+                                Some("Conversation is locked; unlock it to comment.".to_string());
+                            return Ok(());
+                        }
                         let body = self.input_state.text();
                         let trimmed = body.trim();
                         if trimmed.is_empty() {
-                            self.post_error = Some("Comment cannot be empty.".to_string());
+                            self.post_error = Some(EMPTY_COMMENT_ERROR.to_string());
+                            self.empty_error_at = Some(Instant::now());
+                            return Ok(());
+                        }
+                        if let Some(message) = length_error(trimmed) {
+                            self.post_error = Some(message);
+                            return Ok(());
+                        }
+                        if let Some(comment_id) = self.editing_comment {
+                            let number = seed.number;
+                            self.posting = true;
+                            self.post_error = None;
+                            self.patch_comment(number, comment_id, trimmed.to_string())
+                                .await;
                             return Ok(());
                         }
-                        self.input_state.set_text("");
                         self.send_comment(seed.number, trimmed.to_string()).await;
                         return Ok(());
                     }
 
-                    ct_event!(key press '>')
-                        if self.list_state.is_focused()
-                            || self.body_paragraph_state.is_focused() =>
+                    _ if pressed(KeyAction::QuoteComment, event)
+                        && !self.posting
+                        && (self.list_state.is_focused()
+                            || self.body_paragraph_state.is_focused()) =>
                     {
                         if let Some(comment) = self.selected_comment() {
-                            let comment_body = comment.body.as_ref();
-                            let quoted = comment_body
-                                .lines()
-                                .map(|line| format!("> {}", line.trim()))
-                                .collect::<Vec<_>>()
-                                .join("\n");
-                            self.input_state.insert_str(&quoted);
-                            self.input_state.insert_newline();
+                            let reply = append_quote(&self.input_state.text(), &comment.body);
+                            self.input_state.set_text(reply);
                             self.input_state.move_to_end(false);
-                            self.input_state.move_to_line_end(false);
                             self.input_state.focus.set(true);
                             self.list_state.focus.set(false);
+                            self.body_paragraph_state.focus.set(false);
                         }
                     }
 
+                    // The composer keeps the pending text while posting so it can be retried on
+                    // failure; don't let edits slip in underneath the request.
+                    event::Event::Key(_) | event::Event::Paste(_)
+                        if (self.posting || (self.is_locked() && !self.editing_issue))
+                            && self.input_state.is_focused() =>
+                    {
+                        return Ok(());
+                    }
                     event::Event::Key(key) if key.code != event::KeyCode::Tab => {
                         let o = self.input_state.handle(event, rat_widget::event::Regular);
                         let o2 = self
@@ -1690,6 +4061,9 @@ impl Component for IssueConversation {
                             })?;
                             action_tx.send(Action::ForceRender).await?;
                         }
+                        if o == TextOutcome::TextChanged {
+                            self.clear_empty_error(false);
+                        }
                         if o == TextOutcome::TextChanged || o2 == Outcome::Changed {
                             trace!("Input changed, forcing re-render");
                             let action_tx = self.action_tx.as_ref().ok_or_else(|| {
@@ -1701,7 +4075,8 @@ impl Component for IssueConversation {
                         }
                     }
                     event::Event::Paste(p) if self.input_state.is_focused() => {
-                        self.input_state.insert_str(p);
+                        self.input_state.insert_str(normalize_paste(p));
+                        self.clear_empty_error(false);
                         let action_tx = self.action_tx.as_ref().ok_or_else(|| {
                             AppError::Other(anyhow!(
                                 "issue conversation action channel unavailable"
@@ -1713,13 +4088,21 @@ impl Component for IssueConversation {
                 }
                 self.body_paragraph_state
                     .handle(event, rat_widget::event::Regular);
-                let outcome = self.list_state.handle(event, rat_widget::event::Regular);
+                let outcome = self
+                    .list_state
+                    .handle(&*list_motion(event), rat_widget::event::Regular);
                 if outcome == rat_widget::event::Outcome::Changed {
                     self.body_paragraph_state.set_line_offset(0);
                 }
             }
             Action::EnterIssueDetails { seed } => {
                 let number = seed.number;
+                let previous = self.current.as_ref().map(|current| current.number);
+                if previous != Some(number) {
+                    self.mark_current_read();
+                    self.read_marker = self.read_markers.get(number);
+                    self.jump_to_unread = true;
+                }
                 self.title = seed.title.clone();
                 self.current = Some(seed);
                 self.post_error = None;
@@ -1727,6 +4110,28 @@ impl Component for IssueConversation {
                 self.close_error = None;
                 self.reaction_mode = None;
                 self.close_popup = None;
+                self.lock_popup = None;
+                self.lock_error = None;
+                self.assignee_popup = None;
+                self.milestone_popup = None;
+                self.state_confirm = None;
+                self.author_filter_prompt = None;
+                if previous != Some(number) {
+                    if let Some(previous) = previous {
+                        self.stash_draft(previous);
+                    }
+                    self.editing_comment = None;
+                    self.editing_issue = false;
+                    self.edit_stash = None;
+                    let draft = self.drafts.get(number).unwrap_or_default().to_string();
+                    self.input_state.set_text(draft);
+                    self.input_state.move_to_end(false);
+                    self.author_filter = None;
+                    self.pinned = None;
+                    self.new_comments = 0;
+                    self.start_comment_poll(number);
+                    self.fetch_subscription(number);
+                }
                 self.timeline_error = None;
                 self.body_cache = None;
                 self.body_cache_number = Some(number);
@@ -1753,10 +4158,26 @@ impl Component for IssueConversation {
                         self.fetch_timeline(number).await;
                     }
                 }
+                if self.pr_files_number != Some(number) {
+                    self.pr_files_number = None;
+                    self.pr_files.clear();
+                    self.pr_files_truncated = false;
+                    self.pr_files_error = None;
+                    if self.current.as_ref().is_some_and(|s| s.is_pull_request) {
+                        self.fetch_pr_files(number).await;
+                    }
+                }
             }
-            Action::IssueCommentsLoaded { number, comments } => {
-                self.loading.remove(&number);
+            Action::IssueCommentsLoaded {
+                number,
+                comments,
+                more,
+            } => {
+                if !more {
+                    self.loading.remove(&number);
+                }
                 if self.current.as_ref().is_some_and(|s| s.number == number) {
+                    self.new_comments = 0;
                     self.cache_number = Some(number);
                     trace!("Setting {} comments for #{}", comments.len(), number);
                     self.cache_comments = comments;
@@ -1791,7 +4212,12 @@ impl Component for IssueConversation {
             }
             Action::IssueCommentPosted { number, comment } => {
                 self.posting = false;
+                if self.drafts.remove(number) {
+                    let _ = self.drafts.write_to_file(&self.owner, &self.repo);
+                }
                 if self.current.as_ref().is_some_and(|s| s.number == number) {
+                    self.input_state.set_text("");
+                    self.jump_to_latest = true;
                     if self.cache_number == Some(number) {
                         self.cache_comments.push(comment);
                     } else {
@@ -1803,6 +4229,27 @@ impl Component for IssueConversation {
                     }
                 }
             }
+            Action::IssueCommentsPageLoaded {
+                number,
+                comments,
+                more,
+            } => {
+                if !more {
+                    self.loading.remove(&number);
+                }
+                if self.cache_number == Some(number)
+                    && self.current.as_ref().is_some_and(|s| s.number == number)
+                    && !comments.is_empty()
+                {
+                    trace!("Appending {} comments for #{}", comments.len(), number);
+                    self.cache_comments.extend(comments);
+                    if let Some(action_tx) = self.action_tx.as_ref() {
+                        let _ = action_tx.send(Action::ForceRender).await;
+                    }
+                } else if !more && let Some(action_tx) = self.action_tx.as_ref() {
+                    let _ = action_tx.send(Action::ForceRender).await;
+                }
+            }
             Action::IssueCommentsError { number, message } => {
                 self.loading.remove(&number);
                 if self.current.as_ref().is_some_and(|s| s.number == number) {
@@ -1826,6 +4273,255 @@ impl Component for IssueConversation {
                     self.timeline_error = Some(message);
                 }
             }
+            Action::IssueSubscriptionLoaded { number, state } => {
+                if self.subscription_pending == Some(number) {
+                    self.subscription_pending = None;
+                }
+                if self.current.as_ref().is_some_and(|s| s.number == number) {
+                    self.subscription_number = Some(number);
+                    self.subscription = state;
+                }
+            }
+            Action::IssueLockChanged { number, locked } => {
+                if self.lock_pending == Some(number) {
+                    self.lock_pending = None;
+                }
+                if let Some(seed) = self.current.as_mut().filter(|s| s.number == number) {
+                    seed.locked = locked;
+                    self.lock_error = None;
+                }
+                if let Ok(mut pool) = self.issue_pool.write()
+                    && let Some(issue_id) = pool.issue_id_by_number(number)
+                {
+                    pool.get_issue_mut(issue_id).locked = locked;
+                }
+                if let Some(action_tx) = self.action_tx.as_ref() {
+                    let message = if locked {
+                        format!("Locked #{number}.")
+                    } else {
+                        format!("Unlocked #{number}.")
+                    };
+                    let _ = action_tx.send(toast_action(message, ToastType::Info)).await;
+                }
+            }
+            Action::IssueAssigneesUpdated { number, assignees } => {
+                if let Some(popup) = self
+                    .assignee_popup
+                    .as_mut()
+                    .filter(|popup| popup.issue_number == number)
+                {
+                    popup.pending = false;
+                    popup.input.clear();
+                    if popup.selected.selected().unwrap_or(0) >= assignees.len() {
+                        popup
+                            .selected
+                            .select(Some(assignees.len().saturating_sub(1)));
+                    }
+                }
+                if let Some(seed) = self.current.as_mut().filter(|s| s.number == number) {
+                    seed.assignees = assignees
+                        .iter()
+                        .map(|assignee| Arc::<str>::from(assignee.login.as_str()))
+                        .collect();
+                }
+                let preview_seed = self.issue_pool.write().ok().and_then(|mut pool| {
+                    let issue_id = pool.issue_id_by_number(number)?;
+                    let ids = assignees
+                        .iter()
+                        .map(|assignee| pool.intern_author(assignee))
+                        .collect();
+                    pool.get_issue_mut(issue_id).assignees = ids;
+                    Some(
+                        crate::ui::components::issue_detail::IssuePreviewSeed::from_ui_issue(
+                            pool.get_issue(issue_id),
+                            &pool,
+                        ),
+                    )
+                });
+                if let Some(action_tx) = self.action_tx.as_ref()
+                    && let Some(seed) = preview_seed
+                    && self.current.as_ref().is_some_and(|s| s.number == number)
+                {
+                    let _ = action_tx.send(Action::SelectedIssuePreview { seed }).await;
+                }
+            }
+            Action::MilestonesLoaded { number, milestones } => {
+                if let Some(popup) = self
+                    .milestone_popup
+                    .as_mut()
+                    .filter(|popup| popup.issue_number == number)
+                {
+                    let current = self
+                        .current
+                        .as_ref()
+                        .and_then(|seed| seed.milestone.clone());
+                    let selected = current
+                        .and_then(|title| {
+                            milestones
+                                .iter()
+                                .position(|milestone| milestone.title == *title)
+                        })
+                        .map_or(0, |idx| idx + 1);
+                    popup.selected.select(Some(selected));
+                    popup.milestones = Some(
+                        milestones
+                            .into_iter()
+                            .map(|milestone| (milestone.number, milestone.title))
+                            .collect(),
+                    );
+                }
+            }
+            Action::IssueMilestoneUpdated { number, milestone } => {
+                if self
+                    .milestone_popup
+                    .as_ref()
+                    .is_some_and(|popup| popup.issue_number == number)
+                {
+                    self.milestone_popup = None;
+                }
+                if let Some(seed) = self.current.as_mut().filter(|s| s.number == number) {
+                    seed.milestone = milestone.as_deref().map(Arc::<str>::from);
+                }
+                let preview_seed = self.issue_pool.write().ok().and_then(|mut pool| {
+                    let issue_id = pool.issue_id_by_number(number)?;
+                    let title = milestone.as_deref().map(|title| pool.intern_str(title));
+                    pool.get_issue_mut(issue_id).milestone = title;
+                    Some(
+                        crate::ui::components::issue_detail::IssuePreviewSeed::from_ui_issue(
+                            pool.get_issue(issue_id),
+                            &pool,
+                        ),
+                    )
+                });
+                if let Some(action_tx) = self.action_tx.as_ref() {
+                    if let Some(seed) = preview_seed
+                        && self.current.as_ref().is_some_and(|s| s.number == number)
+                    {
+                        let _ = action_tx.send(Action::SelectedIssuePreview { seed }).await;
+                    }
+                    let message = match milestone {
+                        Some(title) => format!("Moved #{number} to milestone {title}."),
+                        None => format!("Cleared the milestone of #{number}."),
+                    };
+                    let _ = action_tx.send(toast_action(message, ToastType::Info)).await;
+                }
+            }
+            Action::IssueMilestoneError { number, message } => {
+                if let Some(popup) = self
+                    .milestone_popup
+                    .as_mut()
+                    .filter(|popup| popup.issue_number == number)
+                {
+                    popup.pending = false;
+                    popup.error = Some(message);
+                }
+            }
+            Action::IssueAssigneesError { number, message } => {
+                if let Some(popup) = self
+                    .assignee_popup
+                    .as_mut()
+                    .filter(|popup| popup.issue_number == number)
+                {
+                    popup.pending = false;
+                    popup.error = Some(message);
+                }
+            }
+            Action::IssueStateChanged { number, state } => {
+                if self.state_pending == Some(number) {
+                    self.state_pending = None;
+                }
+                if let Some(seed) = self.current.as_mut().filter(|s| s.number == number) {
+                    seed.state = state.clone();
+                }
+                let preview_seed = self.issue_pool.write().ok().and_then(|mut pool| {
+                    let issue_id = pool.issue_id_by_number(number)?;
+                    pool.get_issue_mut(issue_id).state = state.clone();
+                    Some(
+                        crate::ui::components::issue_detail::IssuePreviewSeed::from_ui_issue(
+                            pool.get_issue(issue_id),
+                            &pool,
+                        ),
+                    )
+                });
+                if let Some(action_tx) = self.action_tx.as_ref() {
+                    if let Some(seed) = preview_seed
+                        && self.current.as_ref().is_some_and(|s| s.number == number)
+                    {
+                        let _ = action_tx.send(Action::SelectedIssuePreview { seed }).await;
+                    }
+                    let message = match state {
+                        IssueState::Closed => format!("Closed #{number}."),
+                        _ => format!("Reopened #{number}."),
+                    };
+                    let _ = action_tx.send(toast_action(message, ToastType::Info)).await;
+                }
+            }
+            Action::IssueStateChangeError { number, message } => {
+                if self.state_pending == Some(number) {
+                    self.state_pending = None;
+                }
+                if self.current.as_ref().is_some_and(|s| s.number == number) {
+                    self.close_error = Some(message);
+                }
+            }
+            Action::IssueLockError { number, message } => {
+                if self.lock_pending == Some(number) {
+                    self.lock_pending = None;
+                }
+                if self.current.as_ref().is_some_and(|s| s.number == number) {
+                    self.lock_error = Some(message);
+                }
+            }
+            Action::IssueSubscriptionError { number, message } => {
+                if self.subscription_pending == Some(number) {
+                    self.subscription_pending = None;
+                }
+                if let Some(action_tx) = self.action_tx.as_ref() {
+                    let _ = action_tx
+                        .send(toast_action(
+                            format!("Failed to update subscription for #{number}: {message}"),
+                            ToastType::Error,
+                        ))
+                        .await;
+                }
+            }
+            Action::IssueCommentCountPolled { number, count } => {
+                if self.current.as_ref().is_none_or(|s| s.number != number)
+                    || self.cache_number != Some(number)
+                    || self.loading.contains(&number)
+                {
+                    return Ok(());
+                }
+                let new_comments = (count as usize).saturating_sub(self.cache_comments.len());
+                if new_comments != self.new_comments {
+                    self.new_comments = new_comments;
+                    if let Some(action_tx) = self.action_tx.as_ref() {
+                        let _ = action_tx.send(Action::ForceRender).await;
+                    }
+                }
+            }
+            Action::PullRequestFilesLoaded {
+                number,
+                files,
+                truncated,
+            } => {
+                self.pr_files_loading.remove(&number);
+                if self.current.as_ref().is_some_and(|s| s.number == number) {
+                    self.pr_files_number = Some(number);
+                    self.pr_files = files;
+                    self.pr_files_truncated = truncated;
+                    self.pr_files_error = None;
+                    if let Some(action_tx) = self.action_tx.as_ref() {
+                        let _ = action_tx.send(Action::ForceRender).await;
+                    }
+                }
+            }
+            Action::PullRequestFilesError { number, message } => {
+                self.pr_files_loading.remove(&number);
+                if self.current.as_ref().is_some_and(|s| s.number == number) {
+                    self.pr_files_error = Some(message);
+                }
+            }
             Action::IssueCommentPostError { number, message } => {
                 self.posting = false;
                 if self.current.as_ref().is_some_and(|s| s.number == number) {
@@ -1868,19 +4564,93 @@ impl Component for IssueConversation {
                             action_tx.send(Action::ForceRender).await?;
                         }
                     }
+                    Err(message) if self.editing_comment == Some(comment_id) => {
+                        self.posting = false;
+                        self.post_error = Some(format!("Edit failed: {message}"));
+                    }
                     Err(message) => {
                         return Err(AppError::Other(anyhow!("comment edit failed: {message}")));
                     }
                 }
             }
-            Action::IssueCommentPatched {
-                issue_number,
-                comment,
+            Action::IssueCommentDeleted { number, id } => {
+                if self.editing_comment == Some(id) {
+                    self.leave_edit_mode();
+                }
+                if self.cache_number == Some(number) {
+                    self.cache_comments.retain(|comment| comment.id != id);
+                    self.markdown_cache.remove(&id);
+                    if self.pinned == Some(MessageKey::Comment(id)) {
+                        self.pinned = None;
+                    }
+                }
+                if let Some(action_tx) = self.action_tx.as_ref() {
+                    let _ = action_tx
+                        .send(toast_action("Comment deleted", ToastType::Success))
+                        .await;
+                    let _ = action_tx.send(Action::ForceRender).await;
+                }
+            }
+            Action::IssueCommentDeleteError {
+                number,
+                id: _,
+                message,
+            } if self.current.as_ref().is_some_and(|s| s.number == number) => {
+                self.post_error = Some(format!("Delete failed: {message}"));
+            }
+            Action::IssueEdited {
+                number,
+                title,
+                body,
             } => {
+                let current = self.current.as_ref().is_some_and(|s| s.number == number);
+                if current && self.editing_issue {
+                    self.posting = false;
+                    self.leave_edit_mode();
+                }
+                if let Some(seed) = self.current.as_mut().filter(|s| s.number == number) {
+                    seed.title = Some(Arc::from(title.as_str()));
+                    seed.body = body.as_deref().map(Arc::<str>::from);
+                    self.title = seed.title.clone();
+                }
+                if self.body_cache_number == Some(number) {
+                    self.body_cache = None;
+                }
+                if let Ok(mut pool) = self.issue_pool.write()
+                    && let Some(issue_id) = pool.issue_id_by_number(number)
+                {
+                    let title = pool.intern_str(&title);
+                    let body = body.as_deref().map(|body| pool.intern_str(body));
+                    let issue = pool.get_issue_mut(issue_id);
+                    issue.title = title;
+                    issue.body = body;
+                }
+                if let Some(action_tx) = self.action_tx.as_ref() {
+                    let _ = action_tx
+                        .send(toast_action(
+                            format!("Updated #{number}."),
+                            ToastType::Success,
+                        ))
+                        .await;
+                    let _ = action_tx.send(Action::ForceRender).await;
+                }
+            }
+            Action::IssueEditError { number, message }
+                if self.editing_issue
+                    && self.current.as_ref().is_some_and(|s| s.number == number) =>
+            {
+                self.posting = false;
+                self.post_error = Some(format!("Edit failed: {message}"));
+            }
+            Action::IssueCommentEdited { number, comment } => {
+                if self.editing_comment == Some(comment.id) {
+                    self.posting = false;
+                    self.leave_edit_mode();
+                }
                 if self
                     .current
                     .as_ref()
-                    .is_some_and(|seed| seed.number == issue_number)
+                    .is_some_and(|seed| seed.number == number)
                     && let Some(existing) =
                         self.cache_comments.iter_mut().find(|c| c.id == comment.id)
                 {
@@ -1929,12 +4699,22 @@ impl Component for IssueConversation {
             }
             Action::ChangeIssueScreen(screen) => {
                 self.screen = screen;
+                if let Ok(mut gate) = self.poll_gate.lock() {
+                    gate.viewing = screen != MainScreen::List && screen != MainScreen::CreateIssue;
+                }
                 match screen {
                     MainScreen::List => {
+                        if let Some(number) = self.current.as_ref().map(|seed| seed.number) {
+                            self.stash_draft(number);
+                        }
                         self.input_state.focus.set(false);
                         self.list_state.focus.set(false);
                         self.reaction_mode = None;
                         self.close_popup = None;
+                        self.lock_popup = None;
+                        self.assignee_popup = None;
+                        self.milestone_popup = None;
+                        self.mark_current_read();
                     }
                     MainScreen::Details => {}
                     MainScreen::DetailsFullscreen => {
@@ -1948,10 +4728,22 @@ impl Component for IssueConversation {
                         self.list_state.focus.set(false);
                         self.reaction_mode = None;
                         self.close_popup = None;
+                        self.lock_popup = None;
+                        self.assignee_popup = None;
+                        self.milestone_popup = None;
                     }
                 }
             }
             Action::Tick => {
+                if self.empty_error_at.is_some() {
+                    self.clear_empty_error(true);
+                    // Ticks stop redrawing once nothing animates, so draw the cleared title.
+                    if self.empty_error_at.is_none()
+                        && let Some(action_tx) = &self.action_tx
+                    {
+                        let _ = action_tx.send(Action::ForceRender).await;
+                    }
+                }
                 if self.is_loading_current() {
                     self.throbber_state.calc_next();
                 }
@@ -1970,6 +4762,9 @@ impl Component for IssueConversation {
     }
 
     fn cursor(&self) -> Option<(u16, u16)> {
+        if let Some(popup) = &self.assignee_popup {
+            return popup.input.screen_cursor();
+        }
         self.input_state.screen_cursor()
     }
 
@@ -1981,6 +4776,7 @@ impl Component for IssueConversation {
         self.in_details_mode()
             && (self.is_loading_current()
                 || self.posting
+                || self.empty_error_at.is_some()
                 || self.close_popup.as_ref().is_some_and(|popup| popup.loading))
     }
 
@@ -1991,7 +4787,14 @@ impl Component for IssueConversation {
         if self.screen == MainScreen::DetailsFullscreen {
             return true;
         }
-        if self.close_popup.is_some() {
+        if self.close_popup.is_some()
+            || self.lock_popup.is_some()
+            || self.assignee_popup.is_some()
+            || self.milestone_popup.is_some()
+            || self.author_filter_prompt.is_some()
+            || self.delete_confirm.is_some()
+            || self.state_confirm.is_some()
+        {
             return true;
         }
         if self.input_state.is_focused() {
@@ -2016,6 +4819,14 @@ impl Component for IssueConversation {
             let _ = action_tx.try_send(Action::SetHelp(HELP));
         }
     }
+
+    fn help_lines(&self) -> Vec<Line<'static>> {
+        if self.input_state.is_focused() {
+            keybind_lines(COMPOSER_HELP)
+        } else {
+            keybind_lines(HELP)
+        }
+    }
 }
 
 impl HasFocus for IssueConversation {
@@ -2023,10 +4834,10 @@ impl HasFocus for IssueConversation {
         let tag = builder.start(self);
         builder.widget(&self.list_state);
         builder.widget(&self.body_paragraph_state);
-        match self.textbox_state {
-            InputState::Input => builder.widget(&self.input_state),
-            InputState::Preview => builder.widget(&self.paragraph_state),
-        };
+        builder.widget(&self.input_state);
+        if self.textbox_state == InputState::Preview {
+            builder.widget(&self.paragraph_state);
+        }
         builder.end(tag);
     }
 
@@ -2047,11 +4858,61 @@ impl HasFocus for IssueConversation {
     }
 }
 
+/// The partial login after an `@` that ends `line`. The `@` must start a word, so e-mail
+/// addresses don't trigger suggestions.
+fn mention_query(line: &str) -> Option<&str> {
+    let at = line.rfind('@')?;
+    let query = &line[at + 1..];
+    let starts_word = line[..at]
+        .chars()
+        .next_back()
+        .is_none_or(|c| c.is_whitespace() || "([{\"'".contains(c));
+    let is_login = query.chars().all(|c| c.is_ascii_alphanumeric() || c == '-');
+    (starts_word && is_login).then_some(query)
+}
+
+/// Distinct `participants` whose logins contain `query` case-insensitively, in first-seen order
+/// with prefix matches first.
+fn suggest_mentions<'a>(
+    participants: impl IntoIterator<Item = &'a Arc<str>>,
+    query: &str,
+) -> Vec<Arc<str>> {
+    let query = query.to_lowercase();
+    let mut matches: Vec<Arc<str>> = Vec::new();
+    for login in participants {
+        if login.to_lowercase().contains(&query)
+            && !matches.iter().any(|seen| seen.eq_ignore_ascii_case(login))
+        {
+            matches.push(login.clone());
+        }
+    }
+    matches.sort_by_key(|login| !login.to_lowercase().starts_with(&query));
+    matches.truncate(MAX_MENTION_SUGGESTIONS);
+    matches
+}
+
+/// Why GitHub would reject `text` as a comment or issue body, if it is too long.
+fn length_error(text: &str) -> Option<String> {
+    let chars = text.chars().count();
+    (chars > MAX_COMMENT_CHARS).then(|| {
+        format!("Too long: {chars} characters, GitHub allows at most {MAX_COMMENT_CHARS}.")
+    })
+}
+
+/// Whether `author` is the authenticated user or one of the extra logins configured to count as
+/// them, such as a bot account.
+fn is_self_login(self_logins: &[String], author: &str) -> bool {
+    self_logins
+        .iter()
+        .any(|login| login.eq_ignore_ascii_case(author))
+}
+
 fn build_comment_item(
     author: &str,
     created_at: &str,
     preview: &str,
     is_self: bool,
+    edited: bool,
     reactions: Option<&[(ReactionContent, u64)]>,
 ) -> ListItem<'static> {
     let author_style = if is_self {
@@ -2059,11 +4920,14 @@ fn build_comment_item(
     } else {
         Style::new().fg(Color::Cyan)
     };
-    let header = Line::from(vec![
+    let mut header = Line::from(vec![
         Span::styled(author.to_string(), author_style),
         Span::raw("  "),
         Span::styled(created_at.to_string(), Style::new()),
     ]);
+    if edited {
+        header.push_span(Span::styled(" (edited)", Style::new().dim()));
+    }
     let preview_line = Line::from(vec![
         Span::raw("  "),
         Span::styled(preview.to_string(), Style::new()),
@@ -2077,16 +4941,25 @@ fn build_comment_item(
     ListItem::new(lines)
 }
 
+fn dim_if_read(item: ListItem<'static>, read: bool) -> ListItem<'static> {
+    if read {
+        item.style(Style::new().add_modifier(Modifier::DIM))
+    } else {
+        item
+    }
+}
+
 fn build_comment_preview_item(
     author: &str,
     created_at: &str,
     body_lines: &[Line<'static>],
     preview_width: usize,
     is_self: bool,
+    edited: bool,
     reactions: Option<&[(ReactionContent, u64)]>,
 ) -> ListItem<'static> {
     let preview = extract_preview(body_lines, preview_width);
-    build_comment_item(author, created_at, &preview, is_self, reactions)
+    build_comment_item(author, created_at, &preview, is_self, edited, reactions)
 }
 
 fn build_timeline_item(entry: &TimelineEventView, preview_width: usize) -> ListItem<'static> {
@@ -2357,15 +5230,19 @@ fn reaction_add_options() -> [ReactionContent; 8] {
 }
 
 fn format_reaction_picker(selected: usize, options: &[ReactionContent]) -> String {
+    let labels = options.iter().map(reaction_label).collect::<Vec<_>>();
+    format_picker(selected, &labels)
+}
+
+fn format_picker(selected: usize, labels: &[&str]) -> String {
     let mut out = String::new();
     let mut bracket_start = None;
     let mut bracket_end = None;
     const TOTAL_WIDTH: usize = 20;
-    for (idx, content) in options.iter().enumerate() {
+    for (idx, label) in labels.iter().enumerate() {
         if idx > 0 {
             out.push(' ');
         }
-        let label = reaction_label(content);
         if idx == selected {
             bracket_start = Some(out.len());
             out.push('[');
@@ -2437,21 +5314,141 @@ fn truncate_preview(input: &str, max_width: usize) -> String {
     out
 }
 
+/// Cuts `lines` down to `limit` lines followed by a "show more" marker, returning how many of
+/// the original lines are still shown.
+fn collapse_lines(lines: &mut Vec<Line<'static>>, limit: usize) -> usize {
+    if lines.len() <= limit {
+        return lines.len();
+    }
+    let hidden = lines.len() - limit;
+    lines.truncate(limit);
+    lines.push(Line::styled(
+        format!("  … show more ({hidden} more lines, Enter/Space)"),
+        Style::new().dim().italic(),
+    ));
+    limit
+}
+
+/// Splits the issue editor text into the title, taken from the first line, and the body below it.
+fn split_issue_text(text: &str) -> Result<(String, String), String> {
+    let (title, body) = text.split_once('\n').unwrap_or((text, ""));
+    let title = title.trim();
+    if title.is_empty() {
+        return Err("The first line is the title and can't be empty.".to_string());
+    }
+    Ok((title.to_string(), body.trim().to_string()))
+}
+
+/// Splits the assignee input into logins, accepting commas or spaces between them and an
+/// optional leading `@`.
+fn parse_logins(text: &str) -> Result<Vec<String>, String> {
+    let logins = text
+        .split(|c: char| c == ',' || c.is_whitespace())
+        .map(|login| login.trim_start_matches('@'))
+        .filter(|login| !login.is_empty())
+        .map(str::to_string)
+        .collect::<Vec<_>>();
+    if logins.is_empty() {
+        return Err("type a login first".to_string());
+    }
+    if let Some(bad) = logins
+        .iter()
+        .find(|login| !login.chars().all(|c| c.is_ascii_alphanumeric() || c == '-'))
+    {
+        return Err(format!("`{bad}` isn't a valid login"));
+    }
+    Ok(logins)
+}
+
+/// Maps permission failures from the lock endpoints to a readable message.
+fn lock_error_message(err: &octocrab::Error) -> String {
+    match err {
+        octocrab::Error::GitHub { source, .. }
+            if matches!(source.status_code.as_u16(), 403 | 404) =>
+        {
+            INSUFFICIENT_LOCK_PERMISSIONS.to_string()
+        }
+        err => err.to_string().replace('\n', " "),
+    }
+}
+
+/// Value of the attribute `name` in the inside of an HTML tag, such as `img src="a.png" alt="A"`.
+fn html_attribute(tag: &str, name: &str) -> Option<String> {
+    let lower = tag.to_ascii_lowercase();
+    let mut search_from = 0;
+    while let Some(found) = lower[search_from..].find(name) {
+        let start = search_from + found;
+        search_from = start + name.len();
+        let preceded_by_space = lower[..start].ends_with(char::is_whitespace);
+        let Some(value) = tag[search_from..].trim_start().strip_prefix('=') else {
+            continue;
+        };
+        if !preceded_by_space {
+            continue;
+        }
+        let value = value.trim_start();
+        let raw = match value.chars().next() {
+            Some(quote @ ('"' | '\'')) => value[1..].split(quote).next().unwrap_or_default(),
+            _ => value
+                .split(|c: char| c.is_whitespace() || c == '/')
+                .next()
+                .unwrap_or_default(),
+        };
+        return Some(decode_html_entities(raw).into_owned());
+    }
+    None
+}
+
+/// Decodes the handful of character references that show up in hand-written HTML.
+fn decode_html_entities(text: &str) -> Cow<'_, str> {
+    if !text.contains('&') {
+        return Cow::Borrowed(text);
+    }
+    Cow::Owned(
+        text.replace("&lt;", "<")
+            .replace("&gt;", ">")
+            .replace("&quot;", "\"")
+            .replace("&#39;", "'")
+            .replace("&apos;", "'")
+            .replace("&nbsp;", " ")
+            .replace("&amp;", "&"),
+    )
+}
+
 pub(crate) fn render_markdown_lines(text: &str, width: usize, indent: usize) -> Vec<Line<'static>> {
     render_markdown(text, width, indent).lines
 }
 
-fn render_markdown(text: &str, width: usize, indent: usize) -> MarkdownRender {
-    let mut renderer = MarkdownRenderer::new(width, indent);
-    let options = Options::ENABLE_GFM
+/// Renders an issue body or comment in the conversation, with `<details>` sections folded
+/// unless `expand_details` is set.
+fn render_message_markdown(text: &str, width: usize, expand_details: bool) -> MarkdownRender {
+    render_markdown_with(text, width, 2, expand_details)
+}
+
+fn markdown_options() -> Options {
+    Options::ENABLE_GFM
         | Options::ENABLE_STRIKETHROUGH
         | Options::ENABLE_TASKLISTS
         | Options::ENABLE_TABLES
         | Options::ENABLE_FOOTNOTES
         | Options::ENABLE_SUPERSCRIPT
         | Options::ENABLE_SUBSCRIPT
-        | Options::ENABLE_MATH;
-    let parser = Parser::new_ext(text, options);
+        | Options::ENABLE_MATH
+}
+
+fn render_markdown(text: &str, width: usize, indent: usize) -> MarkdownRender {
+    render_markdown_with(text, width, indent, true)
+}
+
+fn render_markdown_with(
+    text: &str,
+    width: usize,
+    indent: usize,
+    expand_details: bool,
+) -> MarkdownRender {
+    let mut renderer = MarkdownRenderer::new(width, indent, expand_details);
+    let text = normalize_line_endings(text);
+    let parser = Parser::new_ext(&text, markdown_options());
     let parser = TextMergeStream::new(parser);
     for event in parser {
         match event {
@@ -2462,15 +5459,243 @@ fn render_markdown(text: &str, width: usize, indent: usize) -> MarkdownRender {
             MdEvent::InlineMath(text) | MdEvent::DisplayMath(text) => renderer.inline_math(&text),
             MdEvent::SoftBreak => renderer.soft_break(),
             MdEvent::HardBreak => renderer.hard_break(),
-            MdEvent::Html(text) | MdEvent::InlineHtml(text) => renderer.text(&text),
+            MdEvent::Html(html) | MdEvent::InlineHtml(html) => renderer.html(&html),
             MdEvent::Rule => renderer.rule(),
             MdEvent::TaskListMarker(checked) => renderer.task_list_marker(checked),
-            _ => {}
+            MdEvent::FootnoteReference(label) => renderer.footnote_reference(&label),
         }
     }
     renderer.finish()
 }
 
+/// Converts a Markdown body to plain text using the same parser options as
+/// [`render_markdown_lines`]. Formatting is dropped, links keep their text followed by the URL,
+/// list items get a `-` or number prefix and HTML is stripped.
+pub fn markdown_to_plaintext(text: &str) -> String {
+    let text = normalize_line_endings(text);
+    let parser = TextMergeStream::new(Parser::new_ext(&text, markdown_options()));
+    let mut out = String::new();
+    let mut lists: Vec<Option<u64>> = Vec::new();
+    let mut links: Vec<(String, usize)> = Vec::new();
+
+    fn end_block(out: &mut String) {
+        let trimmed = out.trim_end_matches([' ', '\n']).len();
+        out.truncate(trimmed);
+        if !out.is_empty() {
+            out.push_str("\n\n");
+        }
+    }
+    fn end_line(out: &mut String) {
+        let trimmed = out.trim_end_matches(' ').len();
+        out.truncate(trimmed);
+        if !out.is_empty() && !out.ends_with('\n') {
+            out.push('\n');
+        }
+    }
+
+    for event in parser {
+        match event {
+            MdEvent::Start(Tag::Item) => {
+                end_line(&mut out);
+                let depth = lists.len().saturating_sub(1);
+                out.push_str(&"  ".repeat(depth));
+                match lists.last_mut() {
+                    Some(Some(number)) => {
+                        out.push_str(&format!("{number}. "));
+                        *number += 1;
+                    }
+                    _ => out.push_str("- "),
+                }
+            }
+            MdEvent::Start(Tag::List(start)) => {
+                end_line(&mut out);
+                lists.push(start);
+            }
+            MdEvent::Start(Tag::Link { dest_url, .. } | Tag::Image { dest_url, .. }) => {
+                links.push((dest_url.to_string(), out.len()));
+            }
+            MdEvent::Start(Tag::FootnoteDefinition(label)) => {
+                end_block(&mut out);
+                out.push_str(&format!("[{label}]: "));
+            }
+            MdEvent::Start(_) => {}
+            MdEvent::End(TagEnd::Link | TagEnd::Image) => {
+                if let Some((url, start)) = links.pop()
+                    && !url.is_empty()
+                    && out[start..] != url
+                {
+                    out.push_str(&format!(" ({url})"));
+                }
+            }
+            MdEvent::End(TagEnd::List(_)) => {
+                lists.pop();
+                if lists.is_empty() {
+                    end_block(&mut out);
+                }
+            }
+            MdEvent::End(TagEnd::Item | TagEnd::TableHead | TagEnd::TableRow) => {
+                let trimmed = out.trim_end_matches([' ', '|']).len();
+                out.truncate(trimmed);
+                end_line(&mut out);
+            }
+            MdEvent::End(TagEnd::TableCell) => out.push_str(" | "),
+            MdEvent::End(
+                TagEnd::Paragraph
+                | TagEnd::Heading(_)
+                | TagEnd::BlockQuote(_)
+                | TagEnd::CodeBlock
+                | TagEnd::Table
+                | TagEnd::FootnoteDefinition
+                | TagEnd::HtmlBlock,
+            ) => {
+                if lists.is_empty() {
+                    end_block(&mut out);
+                } else {
+                    end_line(&mut out);
+                }
+            }
+            MdEvent::End(_) => {}
+            MdEvent::Text(text)
+            | MdEvent::Code(text)
+            | MdEvent::InlineMath(text)
+            | MdEvent::DisplayMath(text) => out.push_str(&text),
+            MdEvent::SoftBreak => out.push(' '),
+            MdEvent::HardBreak => out.push('\n'),
+            MdEvent::Html(_) | MdEvent::InlineHtml(_) => {}
+            MdEvent::Rule => end_block(&mut out),
+            MdEvent::TaskListMarker(checked) => out.push_str(if checked { "[x] " } else { "[ ] " }),
+            MdEvent::FootnoteReference(label) => out.push_str(&format!("[{label}]")),
+        }
+    }
+    out.trim_end().to_string()
+}
+
+fn normalize_line_endings(text: &str) -> Cow<'_, str> {
+    if text.contains('\r') {
+        Cow::Owned(text.replace("\r\n", "\n").replace('\r', "\n"))
+    } else {
+        Cow::Borrowed(text)
+    }
+}
+
+/// Replaces tabs with spaces up to the next multiple of `tab_width` columns.
+fn expand_tabs(line: &str, tab_width: usize) -> Cow<'_, str> {
+    if !line.contains('\t') {
+        return Cow::Borrowed(line);
+    }
+    let tab_width = tab_width.max(1);
+    let mut out = String::with_capacity(line.len() + tab_width);
+    let mut column = 0;
+    let mut buf = [0u8; 4];
+    for ch in line.chars() {
+        if ch == '\t' {
+            let spaces = tab_width - column % tab_width;
+            out.extend(std::iter::repeat_n(' ', spaces));
+            column += spaces;
+        } else {
+            out.push(ch);
+            column += display_width(ch.encode_utf8(&mut buf));
+        }
+    }
+    Cow::Owned(out)
+}
+
+fn table_cell_width(cell: &[(String, Style)]) -> usize {
+    cell.iter().map(|(text, _)| display_width(text)).sum()
+}
+
+/// Lays out one table cell in exactly `width` columns, clipping with an ellipsis when it doesn't
+/// fit.
+fn table_cell_spans(
+    cell: &[(String, Style)],
+    width: usize,
+    alignment: Alignment,
+    header: bool,
+) -> Vec<Span<'static>> {
+    let header_style = if header {
+        Style::new().add_modifier(Modifier::BOLD)
+    } else {
+        Style::new()
+    };
+    let mut spans = Vec::new();
+    let mut used = 0;
+    if table_cell_width(cell) <= width {
+        for (text, style) in cell {
+            used += display_width(text);
+            spans.push(Span::styled(text.clone(), header_style.patch(*style)));
+        }
+    } else {
+        let mut buf = [0u8; 4];
+        'cell: for (text, style) in cell {
+            let mut fitted = String::new();
+            for ch in text.chars() {
+                let ch_width = display_width(ch.encode_utf8(&mut buf));
+                if used + ch_width + 1 > width {
+                    spans.push(Span::styled(fitted, header_style.patch(*style)));
+                    break 'cell;
+                }
+                fitted.push(ch);
+                used += ch_width;
+            }
+            spans.push(Span::styled(fitted, header_style.patch(*style)));
+        }
+        spans.push(Span::raw("…"));
+        used += 1;
+    }
+
+    let padding = width.saturating_sub(used);
+    let (left, right) = match alignment {
+        Alignment::Right => (padding, 0),
+        Alignment::Center => (padding / 2, padding - padding / 2),
+        Alignment::None | Alignment::Left => (0, padding),
+    };
+    if left > 0 {
+        spans.insert(0, Span::raw(" ".repeat(left)));
+    }
+    if right > 0 {
+        spans.push(Span::raw(" ".repeat(right)));
+    }
+    spans
+}
+
+/// H1 is underlined, H2 bold and deeper levels dimmed, so the outline stays readable without
+/// `#` markers.
+fn heading_style(level: HeadingLevel) -> Style {
+    match level {
+        HeadingLevel::H1 => Style::new().add_modifier(Modifier::BOLD | Modifier::UNDERLINED),
+        HeadingLevel::H2 => Style::new().add_modifier(Modifier::BOLD),
+        _ => Style::new().add_modifier(Modifier::BOLD | Modifier::DIM),
+    }
+}
+
+/// Dim `(url)` shown after a link's text, unless the text already is the URL.
+fn link_annotation(text: &str, url: &str) -> Option<String> {
+    let text = text.trim();
+    let bare = url.strip_prefix("mailto:").unwrap_or(url);
+    if url.is_empty() || text == url || text == bare {
+        return None;
+    }
+    Some(format!("({url})"))
+}
+
+/// Byte range of the first bare `http://` or `https://` URL in `text`. Trailing punctuation is
+/// left out, so a URL ending a sentence doesn't swallow the full stop.
+fn find_bare_url(text: &str) -> Option<(usize, usize)> {
+    let start = ["https://", "http://"]
+        .iter()
+        .filter_map(|scheme| text.find(scheme))
+        .min()?;
+    let rest = &text[start..];
+    let len = rest
+        .find(|c: char| c.is_whitespace() || matches!(c, '<' | '>' | '"'))
+        .unwrap_or(rest.len());
+    let url = rest[..len].trim_end_matches(['.', ',', ';', ':', '!', '?', ')', '\'']);
+    if url.ends_with("//") {
+        return None;
+    }
+    Some((start, start + url.len()))
+}
+
 struct MarkdownRenderer {
     lines: Vec<Line<'static>>,
     links: Vec<RenderedLink>,
@@ -2480,15 +5705,71 @@ struct MarkdownRenderer {
     indent: usize,
     style_stack: Vec<Style>,
     current_style: Style,
-    in_block_quote: bool,
+    /// Number of open block quotes; each draws its own `│ ` bar.
+    block_quote_depth: usize,
     block_quote_style: Option<AdmonitionStyle>,
     block_quote_title_pending: bool,
     in_code_block: bool,
     code_block_lang: Option<String>,
     code_block_buf: String,
     list_prefix: Option<String>,
+    list_prefix_style: Style,
+    lists: Vec<ListLevel>,
     pending_space: bool,
     active_link_url: Option<String>,
+    /// Text of the open link, compared with its URL when the link closes.
+    active_link_text: String,
+    footnote_numbers: HashMap<String, usize>,
+    footnotes: Vec<RenderedFootnote>,
+    footnote_capture: Option<FootnoteCapture>,
+    table: Option<TableBuffer>,
+    /// Inside an HTML comment, which can span several HTML events.
+    in_html_comment: bool,
+    /// Styles pushed by inline HTML tags that are still open.
+    html_style_depth: usize,
+    /// Whether `<details>` bodies are shown or folded under their summary.
+    expand_details: bool,
+    /// Open `<details>` sections, innermost last. Folded ones hold the line their body starts
+    /// at once the summary is done.
+    open_details: Vec<Option<usize>>,
+    details_count: usize,
+}
+
+/// Rows of a table collected until it ends, since every column has to be measured before the
+/// first row can be drawn.
+struct TableBuffer {
+    alignments: Vec<Alignment>,
+    rows: Vec<Vec<TableCell>>,
+    header_rows: usize,
+}
+
+/// Styled words of one table cell.
+type TableCell = Vec<(String, Style)>;
+
+const TABLE_COLUMN_SEPARATOR: &str = " │ ";
+const MIN_TABLE_COLUMN_WIDTH: usize = 3;
+
+/// An open list, innermost last.
+struct ListLevel {
+    /// Next item number, or `None` for bullet lists.
+    next_number: Option<u64>,
+    /// Width of the current item's indent and marker, so text continuing after a nested list
+    /// lines up with the item again.
+    item_width: usize,
+}
+
+/// A footnote definition rendered on its own, appended under "Footnotes" at the end.
+struct RenderedFootnote {
+    number: usize,
+    lines: Vec<Line<'static>>,
+    links: Vec<RenderedLink>,
+}
+
+/// Body state saved while a footnote definition is rendered into a fresh buffer.
+struct FootnoteCapture {
+    number: usize,
+    lines: Vec<Line<'static>>,
+    links: Vec<RenderedLink>,
 }
 
 #[derive(Clone, Copy)]
@@ -2537,7 +5818,7 @@ impl AdmonitionStyle {
 }
 
 impl MarkdownRenderer {
-    fn new(max_width: usize, indent: usize) -> Self {
+    fn new(max_width: usize, indent: usize, expand_details: bool) -> Self {
         Self {
             lines: Vec::new(),
             links: Vec::new(),
@@ -2547,15 +5828,27 @@ impl MarkdownRenderer {
             indent,
             style_stack: Vec::new(),
             current_style: Style::new(),
-            in_block_quote: false,
+            block_quote_depth: 0,
             block_quote_style: None,
             block_quote_title_pending: false,
             in_code_block: false,
             code_block_lang: None,
             code_block_buf: String::new(),
             list_prefix: None,
+            list_prefix_style: Style::new(),
+            lists: Vec::new(),
             pending_space: false,
             active_link_url: None,
+            active_link_text: String::new(),
+            footnote_numbers: HashMap::new(),
+            footnotes: Vec::new(),
+            footnote_capture: None,
+            table: None,
+            in_html_comment: false,
+            html_style_depth: 0,
+            expand_details,
+            open_details: Vec::new(),
+            details_count: 0,
         }
     }
 
@@ -2569,20 +5862,24 @@ impl MarkdownRenderer {
             }
             Tag::Link { dest_url, .. } => {
                 self.active_link_url = Some(dest_url.to_string());
+                self.active_link_text.clear();
                 self.push_style(
                     Style::new()
                         .fg(Color::Blue)
                         .add_modifier(Modifier::UNDERLINED),
                 );
             }
-            Tag::Heading { .. } => {
-                self.push_style(Style::new().add_modifier(Modifier::BOLD));
+            Tag::Heading { level, .. } => {
+                self.flush_line();
+                self.push_style(heading_style(level));
             }
             Tag::BlockQuote(kind) => {
                 self.flush_line();
-                self.in_block_quote = true;
-                self.block_quote_style = kind.and_then(AdmonitionStyle::from_block_quote_kind);
-                self.block_quote_title_pending = self.block_quote_style.is_some();
+                self.block_quote_depth += 1;
+                if let Some(style) = kind.and_then(AdmonitionStyle::from_block_quote_kind) {
+                    self.block_quote_style = Some(style);
+                    self.block_quote_title_pending = true;
+                }
             }
             Tag::CodeBlock(kind) => {
                 self.ensure_admonition_header();
@@ -2591,84 +5888,410 @@ impl MarkdownRenderer {
                 self.code_block_lang = code_block_kind_lang(kind);
                 self.code_block_buf.clear();
             }
-            Tag::Item => {
-                self.flush_line();
-                self.list_prefix = Some("• ".to_string());
+            Tag::List(start) => {
+                self.flush_line();
+                self.lists.push(ListLevel {
+                    next_number: start,
+                    item_width: 0,
+                });
+            }
+            Tag::Item => {
+                self.flush_line();
+                let depth = self.lists.len().saturating_sub(1);
+                let Some(level) = self.lists.last_mut() else {
+                    self.list_prefix = Some("• ".to_string());
+                    return;
+                };
+                let marker = match level.next_number.as_mut() {
+                    Some(number) => {
+                        let marker = format!("{number}. ");
+                        *number += 1;
+                        marker
+                    }
+                    None => "• ".to_string(),
+                };
+                let prefix = format!("{}{marker}", "  ".repeat(depth));
+                level.item_width = display_width(&prefix);
+                self.list_prefix = Some(prefix);
+            }
+            Tag::Table(alignments) => {
+                self.flush_line();
+                self.table = Some(TableBuffer {
+                    alignments,
+                    rows: Vec::new(),
+                    header_rows: 0,
+                });
+            }
+            Tag::TableHead | Tag::TableRow => {
+                if let Some(table) = self.table.as_mut() {
+                    table.rows.push(Vec::new());
+                }
+            }
+            Tag::TableCell => {
+                if let Some(row) = self.table.as_mut().and_then(|table| table.rows.last_mut()) {
+                    row.push(Vec::new());
+                }
+                self.pending_space = false;
+            }
+            Tag::FootnoteDefinition(label) => {
+                self.flush_line();
+                let number = self.footnote_number(&label);
+                self.footnote_capture = Some(FootnoteCapture {
+                    number,
+                    lines: std::mem::take(&mut self.lines),
+                    links: std::mem::take(&mut self.links),
+                });
+                self.push_text(&format!("[{number}]"), Style::new().fg(Color::Cyan));
+                self.pending_space = true;
+            }
+            _ => {}
+        }
+    }
+
+    fn end_tag(&mut self, tag: TagEnd) {
+        match tag {
+            TagEnd::Emphasis
+            | TagEnd::Strong
+            | TagEnd::Strikethrough
+            | TagEnd::Superscript
+            | TagEnd::Subscript
+            | TagEnd::Link => {
+                self.pop_style();
+                if matches!(tag, TagEnd::Link)
+                    && let Some(url) = self.active_link_url.take()
+                    && let Some(annotation) = link_annotation(&self.active_link_text, &url)
+                {
+                    self.pending_space = true;
+                    self.push_text(&annotation, Style::new().add_modifier(Modifier::DIM));
+                }
+            }
+            TagEnd::Heading(_) => {
+                self.pop_style();
+                self.flush_line();
+                self.push_blank_line();
+            }
+            TagEnd::BlockQuote(_) => {
+                self.flush_line();
+                // The quote's last paragraph left a barred blank line; the gap after the quote
+                // belongs to the enclosing level instead.
+                let prefix = self.block_quote_prefix();
+                if self.lines.last().is_some_and(|line| line.spans == prefix) {
+                    self.lines.pop();
+                }
+                self.block_quote_depth = self.block_quote_depth.saturating_sub(1);
+                if self.block_quote_depth == 0 {
+                    self.block_quote_style = None;
+                }
+                self.block_quote_title_pending = false;
+                self.push_blank_line();
+            }
+            TagEnd::CodeBlock => {
+                self.render_code_block();
+                self.flush_line();
+                self.in_code_block = false;
+                self.code_block_lang = None;
+                self.code_block_buf.clear();
+                self.push_blank_line();
+            }
+            TagEnd::List(_) => {
+                self.flush_line();
+                self.lists.pop();
+                // Text after a nested list still belongs to the enclosing item.
+                self.list_prefix = self.lists.last().map(|level| " ".repeat(level.item_width));
+            }
+            TagEnd::Item => {
+                self.flush_line();
+                self.list_prefix = None;
+            }
+            TagEnd::Paragraph => {
+                self.flush_line();
+                self.push_blank_line();
+            }
+            TagEnd::TableHead => {
+                if let Some(table) = self.table.as_mut() {
+                    table.header_rows = table.rows.len();
+                }
+            }
+            TagEnd::Table => {
+                self.render_table();
+                self.push_blank_line();
+            }
+            TagEnd::FootnoteDefinition => {
+                self.flush_line();
+                let Some(capture) = self.footnote_capture.take() else {
+                    return;
+                };
+                let mut lines = std::mem::replace(&mut self.lines, capture.lines);
+                while lines.last().is_some_and(|line| line.spans.is_empty()) {
+                    lines.pop();
+                }
+                let links = std::mem::replace(&mut self.links, capture.links);
+                self.footnotes.push(RenderedFootnote {
+                    number: capture.number,
+                    lines,
+                    links,
+                });
+            }
+            _ => {}
+        }
+    }
+
+    /// Number shown for footnote `label`. Numbers are handed out in order of first appearance, so
+    /// they only depend on the comment's own text.
+    fn footnote_number(&mut self, label: &str) -> usize {
+        let next = self.footnote_numbers.len() + 1;
+        *self
+            .footnote_numbers
+            .entry(label.to_string())
+            .or_insert(next)
+    }
+
+    fn footnote_reference(&mut self, label: &str) {
+        self.ensure_admonition_header();
+        let number = self.footnote_number(label);
+        let style = self.current_style.patch(Style::new().fg(Color::Cyan));
+        self.push_word(&format!("[{number}]"), style);
+    }
+
+    fn append_footnotes(&mut self) {
+        if self.footnotes.is_empty() {
+            return;
+        }
+        self.footnotes.sort_by_key(|footnote| footnote.number);
+        self.push_blank_line();
+        self.start_line();
+        self.current_line.push(Span::styled(
+            "Footnotes",
+            Style::new().add_modifier(Modifier::BOLD | Modifier::DIM),
+        ));
+        self.flush_line();
+        for footnote in std::mem::take(&mut self.footnotes) {
+            let offset = self.lines.len();
+            self.lines.extend(footnote.lines);
+            self.links
+                .extend(footnote.links.into_iter().map(|mut link| {
+                    link.line += offset;
+                    link
+                }));
+        }
+    }
+
+    fn text(&mut self, text: &str) {
+        if self.block_quote_depth > 0 && self.block_quote_title_pending {
+            if let Some(style) = self.block_quote_style
+                && let Some(title) = extract_admonition_title(text, style.marker)
+            {
+                let title = if title.is_empty() {
+                    style.default_title
+                } else {
+                    title
+                };
+                self.push_admonition_header(title, style);
+                self.block_quote_title_pending = false;
+                return;
+            }
+            self.ensure_admonition_header();
+        }
+        if self.in_code_block {
+            self.code_block_text(text);
+        } else if self.active_link_url.is_some() {
+            self.active_link_text.push_str(text);
+            let style = self.current_style;
+            self.push_text(text, style);
+        } else {
+            self.push_autolinked_text(text);
+        }
+    }
+
+    /// Renders the few HTML tags GitHub users commonly write (line breaks, images, `<details>`
+    /// and simple formatting) and strips every other tag and comment.
+    fn html(&mut self, html: &str) {
+        let mut rest = html;
+        while !rest.is_empty() {
+            if self.in_html_comment {
+                let Some(end) = rest.find("-->") else {
+                    return;
+                };
+                self.in_html_comment = false;
+                rest = &rest[end + 3..];
+                continue;
+            }
+            if let Some(after) = rest.strip_prefix("<!--") {
+                self.in_html_comment = true;
+                rest = after;
+                continue;
+            }
+            let Some(start) = rest.find('<') else {
+                self.html_text(rest);
+                return;
+            };
+            if start > 0 {
+                self.html_text(&rest[..start]);
+                rest = &rest[start..];
+                continue;
+            }
+            let is_tag = rest[1..]
+                .chars()
+                .next()
+                .is_some_and(|c| c.is_ascii_alphabetic() || c == '/');
+            match rest.find('>') {
+                Some(end) if is_tag => {
+                    self.html_tag(&rest[1..end]);
+                    rest = &rest[end + 1..];
+                }
+                _ => {
+                    self.html_text("<");
+                    rest = &rest[1..];
+                }
             }
-            _ => {}
         }
     }
 
-    fn end_tag(&mut self, tag: TagEnd) {
-        match tag {
-            TagEnd::Emphasis
-            | TagEnd::Strong
-            | TagEnd::Strikethrough
-            | TagEnd::Superscript
-            | TagEnd::Subscript
-            | TagEnd::Link => {
-                if matches!(tag, TagEnd::Link) {
-                    self.active_link_url = None;
-                }
-                self.pop_style();
+    fn html_tag(&mut self, tag: &str) {
+        let closing = tag.starts_with('/');
+        let name = tag
+            .trim_start_matches('/')
+            .split(|c: char| c.is_whitespace() || c == '/')
+            .next()
+            .unwrap_or_default()
+            .to_ascii_lowercase();
+        match (name.as_str(), closing) {
+            ("br", _) => self.hard_break(),
+            ("hr", _) => self.rule(),
+            ("img", false) => {
+                self.ensure_admonition_header();
+                let alt = html_attribute(tag, "alt")
+                    .filter(|alt| !alt.trim().is_empty())
+                    .unwrap_or_else(|| "image".to_string());
+                let style = self.current_style.patch(
+                    Style::new()
+                        .fg(Color::DarkGray)
+                        .add_modifier(Modifier::ITALIC),
+                );
+                self.push_text(&format!("[{alt}]"), style);
             }
-            TagEnd::Heading(_) => {
-                self.pop_style();
+            ("details", false) => {
                 self.flush_line();
+                self.open_details.push(None);
+                self.details_count += 1;
             }
-            TagEnd::BlockQuote(_) => {
-                self.flush_line();
-                self.in_block_quote = false;
-                self.block_quote_style = None;
-                self.block_quote_title_pending = false;
+            ("details", true) => {
+                self.close_details();
                 self.push_blank_line();
             }
-            TagEnd::CodeBlock => {
-                self.render_code_block();
+            ("summary", false) => {
                 self.flush_line();
-                self.in_code_block = false;
-                self.code_block_lang = None;
-                self.code_block_buf.clear();
-                self.push_blank_line();
+                let marker = if self.expand_details { "▾ " } else { "▸ " };
+                self.push_text(marker, Style::new().fg(Color::DarkGray));
+                self.push_html_style(Style::new().add_modifier(Modifier::BOLD));
             }
-            TagEnd::Item => {
+            ("summary", true) => {
+                self.pop_html_style();
                 self.flush_line();
-                self.list_prefix = None;
+                // Footnotes render into a separate buffer, so their line numbers can't be used
+                // to cut the body out again.
+                if !self.expand_details
+                    && self.footnote_capture.is_none()
+                    && let Some(body_start @ None) = self.open_details.last_mut()
+                {
+                    *body_start = Some(self.lines.len());
+                }
             }
-            TagEnd::Paragraph => {
+            ("p" | "div" | "table" | "ul" | "ol", true) => {
                 self.flush_line();
                 self.push_blank_line();
             }
+            ("p" | "div" | "table" | "ul" | "ol" | "tr" | "li", _) => self.flush_line(),
+            ("b" | "strong", false) => {
+                self.push_html_style(Style::new().add_modifier(Modifier::BOLD))
+            }
+            ("i" | "em", false) => {
+                self.push_html_style(Style::new().add_modifier(Modifier::ITALIC))
+            }
+            ("s" | "del" | "strike", false) => {
+                self.push_html_style(Style::new().add_modifier(Modifier::CROSSED_OUT))
+            }
+            ("code" | "kbd", false) => {
+                self.push_html_style(Style::new().fg(Color::Yellow).add_modifier(Modifier::BOLD))
+            }
+            ("b" | "strong" | "i" | "em" | "s" | "del" | "strike" | "code" | "kbd", true) => {
+                self.pop_html_style()
+            }
             _ => {}
         }
     }
 
-    fn text(&mut self, text: &str) {
-        if self.in_block_quote && self.block_quote_title_pending {
-            if let Some(style) = self.block_quote_style
-                && let Some(title) = extract_admonition_title(text, style.marker)
-            {
-                let title = if title.is_empty() {
-                    style.default_title
-                } else {
-                    title
-                };
-                self.push_admonition_header(title, style);
-                self.block_quote_title_pending = false;
-                return;
+    /// Text inside an HTML block. Line breaks there only lay out the markup, so whitespace is
+    /// collapsed like a browser would.
+    fn html_text(&mut self, text: &str) {
+        let text = decode_html_entities(text);
+        let words = text.split_whitespace().collect::<Vec<_>>();
+        if words.is_empty() {
+            if !text.is_empty() && !self.current_line.is_empty() {
+                self.pending_space = true;
             }
-            self.ensure_admonition_header();
+            return;
         }
-        if self.in_code_block {
-            self.code_block_text(text);
-        } else {
-            let style = self.current_style;
-            self.push_text(text, style);
+        if text.starts_with(char::is_whitespace) && !self.current_line.is_empty() {
+            self.pending_space = true;
+        }
+        self.text(&words.join(" "));
+        if text.ends_with(char::is_whitespace) {
+            self.pending_space = true;
+        }
+    }
+
+    /// Ends the innermost `<details>` section, dropping its body when it is folded.
+    fn close_details(&mut self) {
+        self.flush_line();
+        let Some(Some(body_start)) = self.open_details.pop() else {
+            return;
+        };
+        if self.footnote_capture.is_none() && body_start <= self.lines.len() {
+            self.lines.truncate(body_start);
+            self.links.retain(|link| link.line < body_start);
+        }
+    }
+
+    fn push_html_style(&mut self, style: Style) {
+        self.push_style(style);
+        self.html_style_depth += 1;
+    }
+
+    /// Pops a style pushed by an HTML tag, ignoring stray closing tags.
+    fn pop_html_style(&mut self) {
+        if self.html_style_depth > 0 {
+            self.html_style_depth -= 1;
+            self.pop_style();
+        }
+    }
+
+    /// Pushes `text`, turning bare `http(s)://` URLs into links.
+    fn push_autolinked_text(&mut self, mut text: &str) {
+        let style = self.current_style;
+        while let Some((start, end)) = find_bare_url(text) {
+            self.push_text(&text[..start], style);
+            let url = &text[start..end];
+            self.active_link_url = Some(url.to_string());
+            self.push_text(
+                url,
+                style.patch(
+                    Style::new()
+                        .fg(Color::Blue)
+                        .add_modifier(Modifier::UNDERLINED),
+                ),
+            );
+            self.active_link_url = None;
+            text = &text[end..];
         }
+        self.push_text(text, style);
     }
 
     fn inline_code(&mut self, text: &str) {
         self.ensure_admonition_header();
+        if self.active_link_url.is_some() {
+            self.active_link_text.push_str(text);
+        }
         let style = self
             .current_style
             .patch(Style::new().fg(Color::Yellow).add_modifier(Modifier::BOLD));
@@ -2705,6 +6328,21 @@ impl MarkdownRenderer {
 
     fn task_list_marker(&mut self, checked: bool) {
         self.ensure_admonition_header();
+        // The marker arrives right after the item starts, so the bullet hasn't been drawn yet and
+        // can be swapped for a checkbox.
+        if self.current_line.is_empty()
+            && let Some(indent) = self
+                .list_prefix
+                .as_deref()
+                .and_then(|prefix| prefix.strip_suffix("• "))
+        {
+            let checkbox = if checked { "☑ " } else { "☐ " };
+            self.list_prefix = Some(format!("{indent}{checkbox}"));
+            if checked {
+                self.list_prefix_style = Style::new().fg(Color::Green);
+            }
+            return;
+        }
         let marker = if checked { "[x] " } else { "[ ] " };
         self.push_text(marker, self.current_style);
     }
@@ -2748,6 +6386,20 @@ impl MarkdownRenderer {
     }
 
     fn push_word(&mut self, word: &str, style: Style) {
+        if let Some(cell) = self
+            .table
+            .as_mut()
+            .and_then(|table| table.rows.last_mut())
+            .and_then(|row| row.last_mut())
+        {
+            if self.pending_space && !cell.is_empty() {
+                cell.push((" ".to_string(), Style::new()));
+            }
+            cell.push((word.to_string(), style));
+            self.pending_space = false;
+            return;
+        }
+
         let prefix_width = self.prefix_width();
         let max_width = self.max_width;
         let word_width = display_width(word);
@@ -2791,6 +6443,74 @@ impl MarkdownRenderer {
         self.push_link_segment(word, link_start_col, word_width);
     }
 
+    fn render_table(&mut self) {
+        let Some(table) = self.table.take() else {
+            return;
+        };
+        let columns = table.rows.iter().map(Vec::len).max().unwrap_or(0);
+        if columns == 0 {
+            return;
+        }
+
+        let mut widths = vec![0; columns];
+        for row in &table.rows {
+            for (idx, cell) in row.iter().enumerate() {
+                widths[idx] = widths[idx].max(table_cell_width(cell));
+            }
+        }
+        // Shrink the widest column until the table fits next to any indent or quote border.
+        let available = self
+            .max_width
+            .saturating_sub(self.prefix_width())
+            .saturating_sub(display_width(TABLE_COLUMN_SEPARATOR) * (columns - 1));
+        while widths.iter().sum::<usize>() > available {
+            let Some((idx, widest)) = widths
+                .iter()
+                .copied()
+                .enumerate()
+                .max_by_key(|(_, width)| *width)
+            else {
+                break;
+            };
+            if widest <= MIN_TABLE_COLUMN_WIDTH {
+                break;
+            }
+            widths[idx] -= 1;
+        }
+
+        let separator_style = Style::new().fg(Color::DarkGray);
+        for (row_idx, row) in table.rows.iter().enumerate() {
+            let header = row_idx < table.header_rows;
+            self.flush_line();
+            self.start_line();
+            for (idx, width) in widths.iter().copied().enumerate() {
+                if idx > 0 {
+                    self.current_line
+                        .push(Span::styled(TABLE_COLUMN_SEPARATOR, separator_style));
+                }
+                let cell = row.get(idx).map(Vec::as_slice).unwrap_or_default();
+                let alignment = table
+                    .alignments
+                    .get(idx)
+                    .copied()
+                    .unwrap_or(Alignment::None);
+                self.current_line
+                    .extend(table_cell_spans(cell, width, alignment, header));
+            }
+            if row_idx + 1 == table.header_rows {
+                self.flush_line();
+                self.start_line();
+                let rule = widths
+                    .iter()
+                    .map(|width| "─".repeat(*width))
+                    .collect::<Vec<_>>()
+                    .join("─┼─");
+                self.current_line.push(Span::styled(rule, separator_style));
+            }
+        }
+        self.flush_line();
+    }
+
     fn push_long_word(&mut self, word: &str, style: Style) {
         let available = self.max_width.saturating_sub(self.prefix_width()).max(1);
         let wrapped = textwrap::wrap(word, textwrap::Options::new(available).break_words(true));
@@ -2866,8 +6586,11 @@ impl MarkdownRenderer {
         let syntax = resolve_syntax(&assets.syntaxes, self.code_block_lang.as_deref());
         let mut highlighter = HighlightLines::new(syntax, &assets.theme);
         let fallback_style = Style::new().light_yellow();
+        let tab_width = CODE_TAB_WIDTH.load(Ordering::Relaxed);
 
         for raw_line in code.split('\n') {
+            let raw_line = expand_tabs(raw_line, tab_width);
+            let raw_line = raw_line.as_ref();
             self.flush_line();
             self.start_line();
             match highlighter.highlight_line(raw_line, &assets.syntaxes) {
@@ -2899,30 +6622,37 @@ impl MarkdownRenderer {
         if !self.current_line.is_empty() {
             return;
         }
+        self.current_line = self.block_quote_prefix();
+        self.current_width += self.indent + 2 * self.block_quote_depth;
+        if let Some(prefix) = self.list_prefix.take() {
+            let width = display_width(&prefix);
+            self.current_width += width;
+            let style = std::mem::take(&mut self.list_prefix_style);
+            self.current_line.push(Span::styled(prefix, style));
+            // Wrapped lines of the same item line up under its text instead of repeating the
+            // marker.
+            self.list_prefix = Some(" ".repeat(width));
+        }
+    }
+
+    /// Indent and one border per open block quote, which starts every line inside a quote.
+    fn block_quote_prefix(&self) -> Vec<Span<'static>> {
+        let mut spans = Vec::new();
         if self.indent > 0 {
-            let indent = " ".repeat(self.indent);
-            self.current_width += self.indent;
-            self.current_line.push(Span::raw(indent));
-        }
-        if self.in_block_quote {
-            self.current_width += 2;
-            let border_style = self
-                .block_quote_style
-                .map(|s| Style::new().fg(s.border_color))
-                .unwrap_or_else(|| Style::new().fg(Color::DarkGray));
-            self.current_line.push(Span::styled("│ ", border_style));
+            spans.push(Span::raw(" ".repeat(self.indent)));
         }
-        if let Some(prefix) = &self.list_prefix {
-            self.current_width += display_width(prefix);
-            self.current_line.push(Span::raw(prefix.clone()));
+        let border_style = self
+            .block_quote_style
+            .map(|s| Style::new().fg(s.border_color))
+            .unwrap_or_else(|| Style::new().fg(Color::DarkGray));
+        for _ in 0..self.block_quote_depth {
+            spans.push(Span::styled("│ ", border_style));
         }
+        spans
     }
 
     fn prefix_width(&self) -> usize {
-        let mut width = self.indent;
-        if self.in_block_quote {
-            width += 2;
-        }
+        let mut width = self.indent + 2 * self.block_quote_depth;
         if let Some(prefix) = &self.list_prefix {
             width += display_width(prefix);
         }
@@ -2941,10 +6671,16 @@ impl MarkdownRenderer {
     }
 
     fn push_blank_line(&mut self) {
-        if self.lines.last().is_some_and(|line| line.spans.is_empty()) {
+        // Inside a quote the gap keeps its bars so paragraphs read as one quote.
+        let prefix = if self.block_quote_depth > 0 {
+            self.block_quote_prefix()
+        } else {
+            Vec::new()
+        };
+        if self.lines.last().is_some_and(|line| line.spans == prefix) {
             return;
         }
-        self.lines.push(Line::from(Vec::<Span<'static>>::new()));
+        self.lines.push(Line::from(prefix));
     }
 
     fn push_style(&mut self, style: Style) {
@@ -2960,6 +6696,11 @@ impl MarkdownRenderer {
 
     fn finish(mut self) -> MarkdownRender {
         self.flush_line();
+        // A folded section that is never closed hides everything after its summary.
+        while !self.open_details.is_empty() {
+            self.close_details();
+        }
+        self.append_footnotes();
         while self.lines.last().is_some_and(|line| line.spans.is_empty()) {
             self.lines.pop();
         }
@@ -2969,6 +6710,7 @@ impl MarkdownRenderer {
         MarkdownRender {
             lines: self.lines,
             links: self.links,
+            details: self.details_count,
         }
     }
 
@@ -3069,7 +6811,111 @@ fn syntect_style_to_ratatui(style: syntect::highlighting::Style) -> Style {
 
 #[cfg(test)]
 mod tests {
-    use super::render_markdown;
+    use std::sync::{Arc, RwLock};
+
+    use octocrab::models::IssueState;
+    use ratatui::{style::Modifier, text::Line};
+
+    use super::{
+        IssueConversation, IssueConversationSeed, MarkdownRender, append_quote, collapse_lines,
+        expand_tabs, issue_meta_line, length_error, markdown_to_plaintext, mention_query,
+        parse_logins, render_markdown, render_message_markdown, split_issue_text, suggest_mentions,
+    };
+    use crate::ui::{Action, AppState, components::Component, issue_data::UiIssuePool};
+
+    #[test]
+    fn splits_title_from_body() {
+        assert_eq!(
+            split_issue_text("Crash on save\n\nSteps:\n1. save\n").unwrap(),
+            ("Crash on save".to_string(), "Steps:\n1. save".to_string())
+        );
+        assert_eq!(
+            split_issue_text("  Title only ").unwrap(),
+            ("Title only".to_string(), String::new())
+        );
+        assert!(split_issue_text("\nbody without a title").is_err());
+    }
+
+    #[test]
+    fn collapses_long_comments_behind_show_more() {
+        let mut lines = (0..5)
+            .map(|idx| Line::from(idx.to_string()))
+            .collect::<Vec<_>>();
+        assert_eq!(collapse_lines(&mut lines, 3), 3);
+        assert_eq!(lines.len(), 4);
+        assert!(lines[3].to_string().contains("2 more lines"));
+
+        let mut short = vec![Line::from("only")];
+        assert_eq!(collapse_lines(&mut short, 3), 1);
+        assert_eq!(short.len(), 1);
+    }
+
+    #[test]
+    fn parses_assignee_logins() {
+        assert_eq!(
+            parse_logins("@alice, bob  carol-d").unwrap(),
+            vec!["alice", "bob", "carol-d"]
+        );
+        assert!(parse_logins(" , @ ").is_err());
+        assert!(parse_logins("alice, b@d").is_err());
+    }
+
+    #[test]
+    fn quotes_comment_after_existing_draft() {
+        assert_eq!(
+            append_quote("", "first\n\n  indented"),
+            "> first\n>\n>   indented\n\n"
+        );
+        assert_eq!(append_quote("draft\n", "hi"), "draft\n\n> hi\n\n");
+    }
+
+    #[test]
+    fn suggests_participants_for_partial_mention() {
+        assert_eq!(mention_query("thanks @al"), Some("al"));
+        assert_eq!(mention_query("@"), Some(""));
+        assert_eq!(mention_query("mail me@host"), None);
+        assert_eq!(mention_query("@alice done"), None);
+
+        let logins = ["alice", "bob", "Alice", "malory"].map(Arc::<str>::from);
+        let suggestions = suggest_mentions(&logins, "al");
+        assert_eq!(
+            suggestions,
+            vec![Arc::<str>::from("alice"), Arc::from("malory")]
+        );
+    }
+
+    #[test]
+    fn summarizes_state_assignees_and_milestone() {
+        let mut seed = IssueConversationSeed {
+            number: 7,
+            author: Arc::from("alice"),
+            created_at: Arc::from("2024-01-01 00:00"),
+            created_ts: 0,
+            updated_ts: 0,
+            body: None,
+            title: Some(Arc::from("Crash")),
+            is_pull_request: false,
+            locked: false,
+            state: IssueState::Open,
+            assignees: vec![Arc::from("bob"), Arc::from("carol")],
+            milestone: Some(Arc::from("v1.0")),
+            html_url: Arc::from("https://github.com/owner/repo/issues/7"),
+        };
+        let text = |seed: &IssueConversationSeed| {
+            issue_meta_line(seed)
+                .spans
+                .iter()
+                .map(|span| span.content.as_ref())
+                .collect::<String>()
+        };
+
+        assert_eq!(text(&seed), "● Open  @bob, @carol  ⚑ v1.0");
+
+        seed.state = IssueState::Closed;
+        seed.assignees.clear();
+        seed.milestone = None;
+        assert_eq!(text(&seed), "● Closed  unassigned");
+    }
 
     fn line_text(rendered: &super::MarkdownRender, idx: usize) -> String {
         rendered.lines[idx]
@@ -3079,6 +6925,14 @@ mod tests {
             .collect()
     }
 
+    /// Text of every rendered line that isn't blank, keeping indentation.
+    fn non_empty_lines(rendered: &super::MarkdownRender) -> Vec<String> {
+        (0..rendered.lines.len())
+            .map(|idx| line_text(rendered, idx))
+            .filter(|line| !line.trim().is_empty())
+            .collect()
+    }
+
     #[test]
     fn extracts_link_segments_with_urls() {
         let rendered = render_markdown("Go to [ratatui docs](https://github.com/ratatui/).", 80, 0);
@@ -3111,4 +6965,270 @@ mod tests {
                 .all(|link| !link.label.starts_with(' ') && !link.label.ends_with(' '))
         );
     }
+
+    #[test]
+    fn annotates_links_with_their_url() {
+        let rendered = render_markdown("See [the docs](https://example.com/docs).", 80, 0);
+        assert_eq!(
+            line_text(&rendered, 0),
+            "See the docs (https://example.com/docs)."
+        );
+
+        let rendered = render_markdown("<https://example.com>", 80, 0);
+        assert_eq!(line_text(&rendered, 0), "https://example.com");
+    }
+
+    #[test]
+    fn renders_common_html_and_strips_the_rest() {
+        let rendered = render_markdown(
+            "<!-- template\nhint -->\nline one<br>line two <img src=\"x.png\" alt=\"Screenshot\"> <span>end</span>\n\n<details>\n<summary>Logs &amp; traces</summary>\n\nbody\n\n</details>",
+            80,
+            0,
+        );
+        let lines = non_empty_lines(&rendered);
+
+        assert_eq!(
+            lines,
+            vec![
+                "line one",
+                "line two [Screenshot] end",
+                "▾ Logs & traces",
+                "body"
+            ]
+        );
+    }
+
+    #[test]
+    fn folds_details_until_expanded() {
+        let text = "before\n\n<details>\n<summary>Logs</summary>\n\nhidden\n\n</details>\n\nafter";
+        let text_of = |rendered: &MarkdownRender| {
+            non_empty_lines(rendered)
+                .iter()
+                .map(|line| line.trim().to_string())
+                .collect::<Vec<_>>()
+        };
+
+        let folded = render_message_markdown(text, 80, false);
+        assert_eq!(text_of(&folded), vec!["before", "▸ Logs", "after"]);
+        assert_eq!(folded.details, 1);
+
+        let expanded = render_message_markdown(text, 80, true);
+        assert_eq!(
+            text_of(&expanded),
+            vec!["before", "▾ Logs", "hidden", "after"]
+        );
+    }
+
+    #[test]
+    fn rejects_bodies_over_the_github_limit() {
+        assert!(length_error(&"é".repeat(super::MAX_COMMENT_CHARS)).is_none());
+        assert!(length_error(&"a".repeat(super::MAX_COMMENT_CHARS + 1)).is_some());
+    }
+
+    #[tokio::test]
+    async fn editing_a_comment_keeps_the_unsent_draft() {
+        let mut conversation = IssueConversation::new(
+            AppState::new("repo".to_string(), "owner".to_string(), "me".to_string()),
+            Arc::new(RwLock::new(UiIssuePool::default())),
+        );
+        conversation.input_state.set_text("half-written reply");
+
+        conversation.enter_edit_mode("old comment body");
+        conversation.editing_comment = Some(5);
+        assert_eq!(conversation.input_state.text(), "old comment body");
+        // Switching to another comment mid-edit keeps the original draft aside.
+        conversation.enter_edit_mode("another comment");
+        assert_eq!(
+            conversation.edit_stash.as_deref(),
+            Some("half-written reply")
+        );
+
+        conversation
+            .handle_event(Action::IssueCommentDeleted { number: 1, id: 5 })
+            .await
+            .unwrap();
+        assert_eq!(conversation.editing_comment, None);
+        assert_eq!(conversation.input_state.text(), "half-written reply");
+    }
+
+    #[test]
+    fn detects_bare_urls() {
+        let rendered = render_markdown("Fixed in https://example.com/pr/1.", 80, 0);
+
+        assert_eq!(
+            line_text(&rendered, 0),
+            "Fixed in https://example.com/pr/1."
+        );
+        assert!(
+            rendered
+                .links
+                .iter()
+                .all(|link| link.url == "https://example.com/pr/1")
+        );
+        assert!(!rendered.links.is_empty());
+    }
+
+    #[test]
+    fn styles_headings_by_level() {
+        let rendered = render_markdown("# One\n## Two\n### Three\ntext", 80, 0);
+
+        let text = (0..rendered.lines.len())
+            .map(|idx| line_text(&rendered, idx))
+            .collect::<Vec<_>>();
+        assert_eq!(text, vec!["One", "", "Two", "", "Three", "", "text"]);
+        let style = |idx: usize| rendered.lines[idx].spans[0].style;
+        assert!(style(0).add_modifier.contains(Modifier::UNDERLINED));
+        assert!(!style(2).add_modifier.contains(Modifier::UNDERLINED));
+        assert!(style(4).add_modifier.contains(Modifier::DIM));
+        assert!(!style(6).add_modifier.contains(Modifier::BOLD));
+    }
+
+    #[test]
+    fn draws_one_bar_per_quote_level() {
+        let rendered = render_markdown("> outer\n>\n> > inner\n>\n> again\n\nafter", 80, 0);
+
+        let text = (0..rendered.lines.len())
+            .map(|idx| line_text(&rendered, idx))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            text,
+            vec!["│ outer", "│ ", "│ │ inner", "│ ", "│ again", "", "after"]
+        );
+    }
+
+    #[test]
+    fn normalizes_crlf_line_endings() {
+        let rendered = render_markdown("first line\r\n\r\nsecond line\r\n", 80, 0);
+
+        let text = rendered
+            .lines
+            .iter()
+            .map(|line| {
+                line.spans
+                    .iter()
+                    .map(|s| s.content.as_ref())
+                    .collect::<String>()
+            })
+            .collect::<Vec<_>>();
+        assert!(text.iter().all(|line| !line.contains('\r')));
+        assert!(text.contains(&"first line".to_string()));
+        assert!(text.contains(&"second line".to_string()));
+    }
+
+    #[test]
+    fn expands_tabs_in_code_blocks() {
+        let rendered = render_markdown("```\r\nfn main() {\r\n\tlet x = 1;\r\n}\r\n```\r\n", 80, 0);
+
+        assert_eq!(line_text(&rendered, 0), "fn main() {");
+        assert_eq!(line_text(&rendered, 1), "    let x = 1;");
+        assert_eq!(line_text(&rendered, 2), "}");
+    }
+
+    #[test]
+    fn collects_footnotes_at_the_end() {
+        let rendered = render_markdown(
+            "Second[^b] and first[^a].\n\n[^a]: Alpha note.\n[^b]: Beta [link](https://example.com).\n\nTrailing paragraph.",
+            80,
+            0,
+        );
+
+        let text = (0..rendered.lines.len())
+            .map(|idx| line_text(&rendered, idx))
+            .collect::<Vec<_>>();
+        assert_eq!(text[0], "Second[1] and first[2].");
+        let section = text
+            .iter()
+            .position(|line| line == "Footnotes")
+            .expect("footnotes section");
+        assert!(text[..section].contains(&"Trailing paragraph.".to_string()));
+        assert_eq!(text[section + 1], "[1] Beta link (https://example.com).");
+        assert_eq!(text[section + 2], "[2] Alpha note.");
+        assert!(
+            rendered
+                .links
+                .iter()
+                .all(|link| link.line == section + 1 && link.label == "link")
+        );
+    }
+
+    #[test]
+    fn converts_markdown_to_plaintext() {
+        let text = markdown_to_plaintext(
+            "# Title\n\nSome **bold** and [docs](https://example.com).<br>\n\n- one\n  1. nested\n- [x] done\n\n```rust\nlet x = 1;\n```",
+        );
+
+        assert_eq!(
+            text,
+            "Title\n\nSome bold and docs (https://example.com).\n\n- one\n  1. nested\n- [x] done\n\nlet x = 1;"
+        );
+    }
+
+    #[test]
+    fn renders_task_list_checkboxes_alongside_bullets() {
+        let rendered = render_markdown("- [x] done\n- [ ] todo\n- plain", 40, 0);
+
+        let text = non_empty_lines(&rendered);
+        assert_eq!(text, ["☑ done", "☐ todo", "• plain"]);
+        assert_eq!(
+            rendered.lines[0].spans[0].style.fg,
+            Some(ratatui::style::Color::Green)
+        );
+        assert_eq!(rendered.lines[1].spans[0].style.fg, None);
+    }
+
+    #[test]
+    fn renders_tables_as_aligned_columns() {
+        let rendered = render_markdown(
+            "| Name | Count |\n| --- | ---: |\n| apples | 3 |\n| a very long name | 12 |",
+            22,
+            0,
+        );
+
+        let text = non_empty_lines(&rendered);
+        assert_eq!(
+            text,
+            [
+                "Name           │ Count",
+                "───────────────┼──────",
+                "apples         │     3",
+                "a very long n… │    12",
+            ]
+        );
+    }
+
+    #[test]
+    fn numbers_ordered_list_items_from_start() {
+        let rendered = render_markdown("9. nine\n10. ten words that wrap\n\n- dot", 20, 0);
+
+        let text = non_empty_lines(&rendered);
+        assert_eq!(text, ["9. nine", "10. ten words that", "    wrap", "• dot"]);
+    }
+
+    #[test]
+    fn indents_nested_lists_by_depth() {
+        let rendered = render_markdown(
+            "- outer\n  1. first nested\n  2. second\n\n  back in outer\n- next",
+            40,
+            0,
+        );
+
+        let text = non_empty_lines(&rendered);
+        assert_eq!(
+            text,
+            [
+                "• outer",
+                "  1. first nested",
+                "  2. second",
+                "  back in outer",
+                "• next"
+            ]
+        );
+    }
+
+    #[test]
+    fn expands_tabs_to_next_tab_stop() {
+        assert_eq!(expand_tabs("a\tb", 4), "a   b");
+        assert_eq!(expand_tabs("\t\tc", 2), "    c");
+        assert_eq!(expand_tabs("plain", 4), "plain");
+    }
 }
@@ -10,21 +10,23 @@ use rat_widget::{
 };
 use ratatui::{
     buffer::Buffer,
-    layout::Rect,
-    style::{Color, Style},
-    widgets::{Block, StatefulWidget},
+    layout::{Constraint, Rect},
+    style::{Color, Modifier, Style, Stylize},
+    text::{Line, Span},
+    widgets::{Block, Clear, List, ListItem, ListState, StatefulWidget, Widget},
 };
 use ratatui_macros::vertical;
 use throbber_widgets_tui::{BRAILLE_SIX_DOUBLE, Throbber, ThrobberState, WhichUse};
 
 use crate::{
-    app::GITHUB_CLIENT,
+    app::client,
     errors::AppError,
+    github::IssueTemplate,
     ui::{
         Action, AppState,
         components::{
             Component,
-            help::HelpElementKind,
+            help::{HelpElementKind, keybind_lines},
             issue_conversation::{IssueConversationSeed, render_markdown_lines},
             issue_detail::IssuePreviewSeed,
             issue_list::MainScreen,
@@ -32,18 +34,20 @@ use crate::{
         issue_data::{IssueId, UiIssue, UiIssuePool},
         layout::Layout,
         toast_action,
-        utils::get_border_style,
+        utils::{get_border_style, normalize_paste},
     },
 };
 use anyhow::anyhow;
 use ratatui_toaster::ToastType;
 use std::sync::{Arc, RwLock};
+use tracing::error;
 
 pub const HELP: &[HelpElementKind] = &[
     crate::help_text!("Issue Create Help"),
     crate::help_keybind!("n", "open new issue composer (from issue list)"),
     crate::help_keybind!("Tab / Shift+Tab", "switch fields"),
     crate::help_keybind!("Ctrl+P", "toggle body input and markdown preview"),
+    crate::help_keybind!("Ctrl+T", "choose an issue template"),
     crate::help_keybind!("Ctrl+Enter / Alt+Enter", "create issue"),
     crate::help_keybind!("Esc", "return to issue list"),
 ];
@@ -85,6 +89,9 @@ pub struct IssueCreate {
     preview_cache_input: String,
     preview_cache_width: usize,
     preview_cache: Vec<ratatui::text::Line<'static>>,
+    templates: Option<Vec<IssueTemplate>>,
+    templates_loading: bool,
+    template_picker: Option<ListState>,
 }
 
 impl IssueCreate {
@@ -113,6 +120,9 @@ impl IssueCreate {
             preview_cache_input: String::new(),
             preview_cache_width: 0,
             preview_cache: Vec::new(),
+            templates: None,
+            templates_loading: false,
+            template_picker: None,
         }
     }
 
@@ -133,6 +143,122 @@ impl IssueCreate {
         self.preview_cache_width = 0;
     }
 
+    fn is_form_empty(&self) -> bool {
+        self.title_state.text().trim().is_empty() && self.body_state.text().trim().is_empty()
+    }
+
+    fn fetch_templates(&mut self) {
+        if self.templates.is_some() || self.templates_loading {
+            return;
+        }
+        let Some(action_tx) = self.action_tx.clone() else {
+            return;
+        };
+        let owner = self.owner.clone();
+        let repo = self.repo.clone();
+        self.templates_loading = true;
+
+        tokio::spawn(async move {
+            let Ok(client) = client() else {
+                let _ = action_tx
+                    .send(Action::IssueTemplatesLoaded {
+                        templates: Vec::new(),
+                    })
+                    .await;
+                return;
+            };
+            let templates = match client.issue_templates(&owner, &repo).await {
+                Ok(templates) => templates,
+                Err(err) => {
+                    error!("Failed to load issue templates: {err}");
+                    Vec::new()
+                }
+            };
+            let _ = action_tx
+                .send(Action::IssueTemplatesLoaded { templates })
+                .await;
+        });
+    }
+
+    fn open_template_picker(&mut self) {
+        if self.templates.as_ref().is_some_and(|t| !t.is_empty()) {
+            self.template_picker = Some(ListState::default().with_selected(Some(0)));
+        }
+    }
+
+    /// Index 0 is the blank form, template `i` lives at `i + 1`.
+    fn apply_template(&mut self, index: usize) {
+        self.template_picker = None;
+        let Some(template) = index
+            .checked_sub(1)
+            .and_then(|i| self.templates.as_ref()?.get(i))
+            .cloned()
+        else {
+            return;
+        };
+        self.title_state.set_text(&template.title);
+        self.labels_state.set_text(template.labels.join(", "));
+        self.assignees_state.set_text(template.assignees.join(", "));
+        self.body_state.set_text(&template.body);
+        self.error = None;
+    }
+
+    fn handle_template_picker_event(&mut self, event: &event::Event) -> bool {
+        let Some(picker) = self.template_picker.as_mut() else {
+            return false;
+        };
+        let count = self.templates.as_ref().map_or(0, Vec::len) + 1;
+        match event {
+            ct_event!(keycode press Up) => {
+                let selected = picker.selected().unwrap_or(0);
+                picker.select(Some((selected + count - 1) % count));
+            }
+            ct_event!(keycode press Down) => {
+                let selected = picker.selected().unwrap_or(0);
+                picker.select(Some((selected + 1) % count));
+            }
+            ct_event!(keycode press Enter) => {
+                let selected = picker.selected().unwrap_or(0);
+                self.apply_template(selected);
+            }
+            ct_event!(keycode press Esc) => self.template_picker = None,
+            _ => {}
+        }
+        true
+    }
+
+    fn render_template_picker(&mut self, area: Rect, buf: &mut Buffer) {
+        let Some(picker) = self.template_picker.as_mut() else {
+            return;
+        };
+        let templates = self.templates.as_deref().unwrap_or_default();
+        let height = (templates.len() as u16 + 3).min(area.height);
+        let popup_area = area.centered(Constraint::Percentage(50), Constraint::Length(height));
+        Clear.render(popup_area, buf);
+
+        let block = Block::bordered()
+            .border_type(ratatui::widgets::BorderType::Rounded)
+            .title("Choose a template")
+            .title_bottom("Enter: use  Esc: blank issue");
+        let inner = block.inner(popup_area);
+        block.render(popup_area, buf);
+
+        let items = std::iter::once(ListItem::new("Blank issue"))
+            .chain(templates.iter().map(|template| {
+                let mut line = Line::from(template.name.clone());
+                if let Some(about) = &template.about {
+                    line.push_span(Span::raw(" - ").dim());
+                    line.push_span(Span::raw(about.clone()).dim());
+                }
+                ListItem::new(line)
+            }))
+            .collect::<Vec<_>>();
+        let list = List::new(items)
+            .highlight_style(Style::new().fg(Color::Cyan).add_modifier(Modifier::BOLD))
+            .highlight_symbol("> ");
+        StatefulWidget::render(list, inner, buf, picker);
+    }
+
     fn parse_csv(input: &str) -> Option<Vec<String>> {
         let values = input
             .split(',')
@@ -182,7 +308,7 @@ impl IssueCreate {
         self.error = None;
 
         tokio::spawn(async move {
-            let Some(client) = GITHUB_CLIENT.get() else {
+            let Ok(client) = client() else {
                 let _ = action_tx
                     .send(Action::IssueCreateError {
                         message: "GitHub client not initialized.".to_string(),
@@ -350,6 +476,8 @@ impl IssueCreate {
                 .use_type(WhichUse::Spin);
             StatefulWidget::render(throbber, title_area, buf, &mut self.create_throbber_state);
         }
+
+        self.render_template_picker(body_area, buf);
     }
 }
 
@@ -369,6 +497,9 @@ impl Component for IssueCreate {
                 if self.screen != MainScreen::CreateIssue {
                     return Ok(());
                 }
+                if self.handle_template_picker_event(event) {
+                    return Ok(());
+                }
                 match event {
                     ct_event!(keycode press Esc) => {
                         if let Some(action_tx) = self.action_tx.clone() {
@@ -392,18 +523,26 @@ impl Component for IssueCreate {
                         }
                         return Ok(());
                     }
+                    ct_event!(key press CONTROL-'t') => {
+                        self.open_template_picker();
+                        return Ok(());
+                    }
                     ct_event!(keycode press CONTROL-Enter) | ct_event!(keycode press ALT-Enter) => {
                         self.submit().await;
                         return Ok(());
                     }
-                    ct_event!(keycode press Tab) | ct_event!(keycode press SHIFT-Tab)
-                        if self.body_state.is_focused() =>
-                    {
+                    ct_event!(keycode press Tab) if self.body_state.is_focused() => {
                         if let Some(action_tx) = self.action_tx.clone() {
                             let _ = action_tx.send(Action::ForceFocusChange).await;
                         }
                         return Ok(());
                     }
+                    ct_event!(keycode press SHIFT-BackTab) if self.body_state.is_focused() => {
+                        if let Some(action_tx) = self.action_tx.clone() {
+                            let _ = action_tx.send(Action::ForceFocusChangeRev).await;
+                        }
+                        return Ok(());
+                    }
                     _ => {}
                 }
 
@@ -431,8 +570,15 @@ impl Component for IssueCreate {
                         {
                             return Ok(());
                         }
-                        if let event::Event::Paste(pasted_stuff) = event {
-                            self.body_state.insert_str(pasted_stuff);
+                        if let event::Event::Paste(pasted_stuff) = event
+                            && self.body_state.is_focused()
+                        {
+                            self.body_state.insert_str(normalize_paste(pasted_stuff));
+                            let action_tx = self.action_tx.as_ref().ok_or_else(|| {
+                                AppError::Other(anyhow!("issue create action channel unavailable"))
+                            })?;
+                            action_tx.send(Action::ForceRender).await?;
+                            return Ok(());
                         }
                         let o = self.body_state.handle(event, rat_widget::event::Regular);
                         if o == TextOutcome::TextChanged {
@@ -455,6 +601,19 @@ impl Component for IssueCreate {
             Action::EnterIssueCreate => {
                 self.screen = MainScreen::CreateIssue;
                 self.reset_form();
+                self.template_picker = None;
+                self.fetch_templates();
+                self.open_template_picker();
+            }
+            Action::IssueTemplatesLoaded { templates } => {
+                self.templates_loading = false;
+                self.templates = Some(templates);
+                if self.screen == MainScreen::CreateIssue
+                    && self.template_picker.is_none()
+                    && self.is_form_empty()
+                {
+                    self.open_template_picker();
+                }
             }
             Action::IssueCreateSuccess { issue_id } => {
                 if self.screen == MainScreen::CreateIssue {
@@ -470,6 +629,7 @@ impl Component for IssueCreate {
             Action::ChangeIssueScreen(screen) => {
                 self.screen = screen;
                 if screen != MainScreen::CreateIssue {
+                    self.template_picker = None;
                     self.title_state.focus.set(false);
                     self.labels_state.focus.set(false);
                     self.assignees_state.focus.set(false);
@@ -502,6 +662,9 @@ impl Component for IssueCreate {
         if self.screen != MainScreen::CreateIssue {
             return false;
         }
+        if self.template_picker.is_some() {
+            return true;
+        }
         if !(self.title_state.is_focused()
             || self.labels_state.is_focused()
             || self.assignees_state.is_focused()
@@ -512,7 +675,7 @@ impl Component for IssueCreate {
         if self.body_state.is_focused()
             && !matches!(
                 event,
-                ct_event!(keycode press Tab) | ct_event!(keycode press SHIFT-Tab)
+                ct_event!(keycode press Tab) | ct_event!(keycode press SHIFT-BackTab)
             )
         {
             return true;
@@ -535,6 +698,10 @@ impl Component for IssueCreate {
             let _ = action_tx.try_send(Action::SetHelp(HELP));
         }
     }
+
+    fn help_lines(&self) -> Vec<Line<'static>> {
+        keybind_lines(HELP)
+    }
 }
 
 impl HasFocus for IssueCreate {
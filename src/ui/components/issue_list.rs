@@ -1,15 +1,24 @@
 use crate::{
-    app::GITHUB_CLIENT,
+    app::client,
     bookmarks::Bookmarks,
     errors::AppError,
+    github::with_timeout,
+    storage::{SeenIssues, read_seen_issues},
     ui::{
-        Action, CloseIssueReason, MergeStrategy,
+        Action, AppState, CloseIssueReason, MergeStrategy,
         components::{
-            Component, help::HelpElementKind, issue_conversation::IssueConversationSeed,
+            Component,
+            help::{HelpElementKind, keybind_lines},
+            issue_conversation::IssueConversationSeed,
             issue_detail::IssuePreviewSeed,
+            status_bar::rate_limit_resets_in,
         },
+        filter::filters::{PooledIssue, by_label, by_title},
+        issue_columns::{IssueColumn, build_column_spans},
         issue_data::{IssueId, UiIssue, UiIssuePool},
+        keymap::{KeyAction, list_motion, pressed},
         layout::Layout,
+        toast_action,
         utils::get_border_style,
     },
 };
@@ -21,7 +30,7 @@ use octocrab::{
     models::{IssueState, issues::Issue},
 };
 use rat_widget::{
-    event::{HandleEvent, ct_event},
+    event::{HandleEvent, TextOutcome, ct_event},
     focus::{HasFocus, Navigation},
     list::selection::RowSelection,
     text_input::TextInputState,
@@ -46,21 +55,29 @@ use std::{
         atomic::{AtomicU32, Ordering},
     },
 };
-use textwrap::{Options, wrap};
+use textwrap::{Options, core::display_width, wrap};
 use throbber_widgets_tui::{BRAILLE_SIX_DOUBLE, Throbber, ThrobberState, WhichUse};
 use tokio::sync::oneshot;
 use tokio_util::sync::CancellationToken;
 use tracing::trace;
 
 pub static LOADED_ISSUE_COUNT: AtomicU32 = AtomicU32::new(0);
+/// Shown on rows of issues that were updated since they were last opened.
+const UPDATED_MARKER: &str = "● ";
+/// Page size of the first load when `per_page` isn't configured.
+const INITIAL_PER_PAGE: u8 = 15;
+
 pub const HELP: &[HelpElementKind] = &[
     crate::help_text!("Issue List Help"),
-    crate::help_keybind!("Up/Down", "navigate issues"),
+    crate::help_keybind!("Up/Down, j/k", "navigate issues"),
+    crate::help_keybind!("g/G", "go to first / last issue"),
     crate::help_keybind!("Enter", "view issue details"),
+    crate::help_keybind!("m", "load more results (also when reaching the last issue)"),
     crate::help_keybind!("b", "toggle bookmark"),
     crate::help_keybind!("B", "open bookmark finder"),
     crate::help_keybind!("C", "close selected issue"),
     crate::help_keybind!("l", "copy issue link to clipboard"),
+    crate::help_keybind!("/", "filter loaded issues by title or label (Esc clears)"),
     crate::help_keybind!("Enter (bookmark popup)", "open selected bookmark"),
     crate::help_keybind!("Esc (bookmark popup)", "close bookmark popup"),
     crate::help_keybind!("Enter (popup)", "confirm close reason"),
@@ -69,6 +86,14 @@ pub const HELP: &[HelpElementKind] = &[
     crate::help_keybind!("n", "create new issue"),
     crate::help_keybind!("Esc", "cancel popup / assign input"),
 ];
+/// Keys that apply while typing a filter.
+const FILTER_HELP: &[HelpElementKind] = &[
+    crate::help_text!("Issue Filter Help"),
+    crate::help_keybind!("Type", "filter loaded issues by title or label"),
+    crate::help_keybind!("Enter", "keep the filter and return to the list"),
+    crate::help_keybind!("Esc", "clear the filter"),
+];
+
 pub struct IssueList<'a> {
     pub issues: Vec<IssueListItem>,
     pub page: Option<Arc<Page<Issue>>>,
@@ -79,7 +104,14 @@ pub struct IssueList<'a> {
     pub throbber_state: ThrobberState,
     pub assign_throbber_state: ThrobberState,
     pub assign_input_state: rat_widget::text_input::TextInputState,
+    filter_input_state: TextInputState,
+    /// Every loaded issue while the local filter narrows `issues`.
+    unfiltered: Option<Vec<IssueListItem>>,
     bookmarks: Arc<RwLock<Bookmarks>>,
+    /// When each issue was last opened, to flag the ones that changed since.
+    seen_issues: SeenIssues,
+    columns: Vec<IssueColumn>,
+    highlight_terms: Vec<String>,
     assign_loading: bool,
     assign_done_rx: Option<oneshot::Receiver<()>>,
     close_popup: Option<IssueClosePopupState>,
@@ -92,6 +124,8 @@ pub struct IssueList<'a> {
     pub repo: String,
     index: usize,
     state: LoadingState,
+    /// The initial load or the `load_more` request in flight, so `Ctrl+G` can abandon it.
+    load_task: Option<tokio::task::JoinHandle<()>>,
     inner_state: IssueListState,
     assignment_mode: AssignmentMode,
     pub screen: MainScreen,
@@ -150,6 +184,7 @@ enum IssueListState {
     #[default]
     Normal,
     AssigningInput,
+    Filtering,
 }
 
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
@@ -178,32 +213,51 @@ pub enum MainScreen {
 impl<'a> IssueList<'a> {
     pub async fn new(
         handler: IssueHandler<'a>,
-        owner: String,
-        repo: String,
+        state: &AppState,
         tx: tokio::sync::mpsc::Sender<Action>,
         bookmarks: Arc<RwLock<Bookmarks>>,
         issue_pool: Arc<RwLock<UiIssuePool>>,
     ) -> Self {
         LOADED_ISSUE_COUNT.store(0, Ordering::Relaxed);
-        let owner_clone = owner.clone();
-        let repo_clone = repo.clone();
-        tokio::spawn(async move {
-            let Some(client) = GITHUB_CLIENT.get() else {
-                return;
+        let owner = state.owner.clone();
+        let repo = state.repo.clone();
+        let query = format!(
+            "repo:{owner}/{repo} {}",
+            state.default_query.as_deref().unwrap_or("is:open")
+        );
+        let per_page = state.per_page.unwrap_or(INITIAL_PER_PAGE);
+        let load_task = tokio::spawn(async move {
+            let client = match client() {
+                Ok(client) => client,
+                Err(err) => {
+                    let _ = tx
+                        .send(Action::SearchError {
+                            message: err.describe(rate_limit_resets_in()),
+                        })
+                        .await;
+                    return;
+                }
             };
-            let Ok(p) = client
-                .inner()
-                .search()
-                .issues_and_pull_requests(&format!(
-                    "repo:{}/{} is:issue is:open",
-                    owner_clone, repo_clone
-                ))
-                .page(1u32)
-                .per_page(15u8)
-                .send()
-                .await
-            else {
-                return;
+            let p = match with_timeout(
+                client
+                    .inner()
+                    .search()
+                    .issues_and_pull_requests(&query)
+                    .page(1u32)
+                    .per_page(per_page)
+                    .send(),
+            )
+            .await
+            {
+                Ok(p) => p,
+                Err(err) => {
+                    let _ = tx
+                        .send(Action::SearchError {
+                            message: err.describe(rate_limit_resets_in()),
+                        })
+                        .await;
+                    return;
+                }
             };
 
             let _ = tx
@@ -213,8 +267,11 @@ impl<'a> IssueList<'a> {
         Self {
             page: None,
             issue_pool,
+            seen_issues: read_seen_issues(&owner, &repo),
             owner,
             bookmarks,
+            columns: state.issue_columns.clone(),
+            highlight_terms: Vec::new(),
             repo,
             throbber_state: ThrobberState::default(),
             action_tx: None,
@@ -222,6 +279,8 @@ impl<'a> IssueList<'a> {
             list_state: rat_widget::list::ListState::default(),
             assign_throbber_state: ThrobberState::default(),
             assign_input_state: TextInputState::default(),
+            filter_input_state: TextInputState::default(),
+            unfiltered: None,
             assign_loading: false,
             assign_done_rx: None,
             close_popup: None,
@@ -234,6 +293,7 @@ impl<'a> IssueList<'a> {
             index: 0,
             screen: MainScreen::default(),
             state: LoadingState::default(),
+            load_task: Some(load_task),
             inner_state: IssueListState::default(),
             assignment_mode: AssignmentMode::default(),
         }
@@ -288,7 +348,7 @@ impl<'a> IssueList<'a> {
         let repo = self.repo.clone();
         let issue_pool = self.issue_pool.clone();
         tokio::spawn(async move {
-            let Some(client) = GITHUB_CLIENT.get() else {
+            let Ok(client) = client() else {
                 let _ = action_tx
                     .send(Action::IssueCloseError {
                         number,
@@ -436,7 +496,7 @@ impl<'a> IssueList<'a> {
         let repo = self.repo.clone();
         let cancel = popup.fetch_cancel.clone();
         tokio::spawn(async move {
-            let Some(client) = GITHUB_CLIENT.get() else {
+            let Ok(client) = client() else {
                 let _ = action_tx
                     .send(Action::BookmarkTitleLoadError {
                         number,
@@ -528,7 +588,7 @@ impl<'a> IssueList<'a> {
         let cancel = popup.fetch_cancel.clone();
         let issue_pool = self.issue_pool.clone();
         tokio::spawn(async move {
-            let Some(client) = GITHUB_CLIENT.get() else {
+            let Ok(client) = client() else {
                 let _ = action_tx
                     .send(Action::BookmarkedIssueLoadError {
                         number,
@@ -570,6 +630,83 @@ impl<'a> IssueList<'a> {
         Ok(())
     }
 
+    /// Opens an issue from the back/forward history. The cursor follows when the issue is listed;
+    /// otherwise the issue opens by number and the selection stays put. Issues that were never
+    /// loaded, e.g. after a new search, are fetched first.
+    async fn open_from_history(&mut self, number: u64) -> Result<(), AppError> {
+        let Some(action_tx) = self.action_tx.clone() else {
+            return Ok(());
+        };
+        let listed = {
+            let pool = self.issue_pool.read().expect("issue pool lock poisoned");
+            self.issues
+                .iter()
+                .position(|item| pool.get_issue(item.0).number == number)
+        };
+        if let Some(idx) = listed {
+            self.list_state.select(Some(idx));
+            self.list_state.scroll_to_selected();
+        }
+        let opened = {
+            let pool = self.issue_pool.read().expect("issue pool lock poisoned");
+            pool.issue_id_by_number(number).map(|issue_id| {
+                let issue = pool.get_issue(issue_id);
+                (
+                    issue.labels.clone(),
+                    IssuePreviewSeed::from_ui_issue(issue, &pool),
+                    IssueConversationSeed::from_ui_issue(issue, &pool),
+                )
+            })
+        };
+        let Some((labels, preview_seed, conversation_seed)) = opened else {
+            let owner = self.owner.clone();
+            let repo = self.repo.clone();
+            let issue_pool = self.issue_pool.clone();
+            tokio::spawn(async move {
+                let result = match client() {
+                    Ok(client) => {
+                        with_timeout(client.inner().issues(owner, repo).get(number)).await
+                    }
+                    Err(err) => Err(err),
+                };
+                let action = match result {
+                    Ok(issue) => {
+                        let mut pool = issue_pool.write().expect("issue pool lock poisoned");
+                        let compact = UiIssue::from_octocrab(&issue, &mut pool);
+                        pool.upsert_issue(compact);
+                        Action::OpenIssueFromHistory { number }
+                    }
+                    Err(err) => toast_action(
+                        format!(
+                            "Failed to open #{number}: {}",
+                            err.describe(rate_limit_resets_in())
+                        ),
+                        ToastType::Error,
+                    ),
+                };
+                let _ = action_tx.send(action).await;
+            });
+            return Ok(());
+        };
+        action_tx
+            .send(Action::SelectedIssue { number, labels })
+            .await?;
+        action_tx
+            .send(Action::SelectedIssuePreview { seed: preview_seed })
+            .await?;
+        action_tx
+            .send(Action::EnterIssueDetails {
+                seed: conversation_seed,
+            })
+            .await?;
+        if self.screen == MainScreen::List {
+            action_tx
+                .send(Action::ChangeIssueScreen(MainScreen::Details))
+                .await?;
+        }
+        Ok(())
+    }
+
     async fn handle_bookmark_popup_event(
         &mut self,
         event: &crossterm::event::Event,
@@ -681,7 +818,10 @@ impl<'a> IssueList<'a> {
         }
 
         let mut assign_input_area = Rect::default();
-        if self.inner_state == IssueListState::AssigningInput {
+        if matches!(
+            self.inner_state,
+            IssueListState::AssigningInput | IssueListState::Filtering
+        ) {
             let split = vertical![*=1, ==3].split(area.main_content);
             area.main_content = split[0];
             assign_input_area = split[1];
@@ -698,16 +838,27 @@ impl<'a> IssueList<'a> {
             } else if let Some(err) = &self.bookmark_error {
                 title.push_str(" | ");
                 title.push_str(err);
+            } else if let Some(unfiltered) = &self.unfiltered {
+                title.push_str(&format!(
+                    " | {}/{} shown",
+                    self.issues.len(),
+                    unfiltered.len()
+                ));
             }
             block = block.title(title);
+            if self.has_more() {
+                block = block.title_bottom(Line::from(" m: load more ").right_aligned().dim());
+            }
         }
         {
             let bookmarks = self.bookmarks.read().unwrap();
             let pool = self.issue_pool.read().expect("issue pool lock poisoned");
+            // Borders plus the horizontal padding of the block.
+            let row_width = area.main_content.width.saturating_sub(8) as usize;
             let list = rat_widget::list::List::<RowSelection>::new(
                 self.issues
                     .iter()
-                    .map(|issue| self.build_list_item(issue, &bookmarks, &pool)),
+                    .map(|issue| self.build_list_item(issue, &bookmarks, &pool, row_width)),
             )
             .block(block)
             .style(Style::default())
@@ -755,15 +906,131 @@ impl<'a> IssueList<'a> {
                 StatefulWidget::render(full, title_area, buf, &mut self.assign_throbber_state);
             }
         }
+        if self.inner_state == IssueListState::Filtering {
+            let input_block = Block::bordered()
+                .border_type(ratatui::widgets::BorderType::Rounded)
+                .border_style(get_border_style(&self.filter_input_state))
+                .title("Filter loaded issues (Esc clears)");
+            let input = rat_widget::text_input::TextInput::new().block(input_block);
+            input.render(assign_input_area, buf, &mut self.filter_input_state);
+        }
         self.render_close_popup(area.main_content, buf);
         self.render_bookmark_popup(area.main_content, buf);
     }
 
+    fn open_filter(&mut self) {
+        self.inner_state = IssueListState::Filtering;
+        if self.unfiltered.is_none() {
+            self.unfiltered = Some(std::mem::take(&mut self.issues));
+            self.apply_filter();
+        }
+        self.filter_input_state.focus.set(true);
+        self.list_state.focus.set(false);
+    }
+
+    fn clear_filter(&mut self) {
+        self.filter_input_state.set_text("");
+        if let Some(issues) = self.unfiltered.take() {
+            self.issues = issues;
+        }
+        self.inner_state = IssueListState::Normal;
+        self.list_state.focus.set(true);
+        let _ = self.list_state.select(Some(0));
+    }
+
+    /// Narrows `issues` to the loaded issues whose title contains the filter text or that carry
+    /// a label of that name. Nothing is fetched.
+    fn apply_filter(&mut self) {
+        let Some(unfiltered) = self.unfiltered.as_ref() else {
+            return;
+        };
+        let query = self.filter_input_state.text().trim().to_string();
+        self.issues = if query.is_empty() {
+            unfiltered.clone()
+        } else {
+            let pool = self.issue_pool.read().expect("issue pool lock poisoned");
+            let filter = by_title(query.as_str()).or(by_label(query.as_str()));
+            unfiltered
+                .iter()
+                .filter(|item| {
+                    filter.matches(&PooledIssue {
+                        issue: pool.get_issue(item.0),
+                        pool: &pool,
+                    })
+                })
+                .cloned()
+                .collect()
+        };
+        if self.issues.is_empty() {
+            self.list_state.clear_selection();
+        } else {
+            let _ = self.list_state.select(Some(0));
+        }
+    }
+
+    fn has_more(&self) -> bool {
+        self.page.as_ref().is_some_and(|page| page.next.is_some())
+    }
+
+    /// Fetches the page after the last loaded one and appends it to the list.
+    fn load_more(&mut self) {
+        if self.state == LoadingState::Loading || !self.has_more() {
+            return;
+        }
+        let (Some(tx), Some(page)) = (self.action_tx.clone(), self.page.as_ref()) else {
+            return;
+        };
+        let page_next = page.next.clone();
+        self.state = LoadingState::Loading;
+        self.load_task = Some(tokio::spawn(async move {
+            let Ok(client) = client() else {
+                let _ = tx.send(Action::FinishedLoading).await;
+                return;
+            };
+            match with_timeout(client.inner().get_page::<Issue>(&page_next)).await {
+                Ok(Some(p)) => {
+                    let _ = tx
+                        .send(Action::NewPage(Arc::new(p), MergeStrategy::Append))
+                        .await;
+                }
+                Ok(None) => {}
+                Err(err) => {
+                    let _ = tx
+                        .send(toast_action(
+                            format!(
+                                "Failed to load more issues: {}",
+                                err.describe(rate_limit_resets_in())
+                            ),
+                            ToastType::Error,
+                        ))
+                        .await;
+                }
+            }
+            let _ = tx.send(Action::FinishedLoading).await;
+        }));
+    }
+
+    /// Abandons the initial load or `load_more` request, keeping the issues already listed.
+    async fn cancel_load(&mut self) -> bool {
+        let Some(task) = self.load_task.take_if(|task| !task.is_finished()) else {
+            return false;
+        };
+        task.abort();
+        self.state = LoadingState::Loaded;
+        if let Some(tx) = self.action_tx.as_ref() {
+            let _ = tx
+                .send(toast_action("Stopped loading issues.", ToastType::Info))
+                .await;
+        }
+        true
+    }
+
     fn build_list_item(
         &self,
         issue: &IssueListItem,
         bookmarks: &Bookmarks,
         pool: &UiIssuePool,
+        width: usize,
     ) -> ListItem<'static> {
         let issue = pool.get_issue(issue.0);
         let options = Options::with_termwidth();
@@ -774,21 +1041,30 @@ impl<'a> IssueList<'a> {
 
         let bookmarked = bookmarks.is_bookmarked(&self.owner, &self.repo, issue.number);
         let bookmark_symbol = if bookmarked { " b " } else { "   " };
-        let title = pool.resolve_str(issue.title);
         let author = pool.author_login(issue.author);
         let created_at = pool.resolve_str(issue.created_at_full);
 
+        let updated = self.seen_issues.is_updated(issue.number, issue.updated_ts);
+        let updated_symbol = if updated { UPDATED_MARKER } else { "  " };
+
+        let mut summary = vec![
+            span!(bookmark_symbol).style(if bookmarked {
+                Style::new().reversed()
+            } else {
+                Style::new()
+            }),
+            span!(updated_symbol).style(Style::new().yellow().bold()),
+        ];
+        summary.extend(build_column_spans(
+            &self.columns,
+            issue,
+            pool,
+            width.saturating_sub(bookmark_symbol.len() + display_width(updated_symbol)),
+            &self.highlight_terms,
+        ));
+
         let lines = vec![
-            line![
-                span!(bookmark_symbol).style(if bookmarked {
-                    Style::new().reversed()
-                } else {
-                    Style::new()
-                }),
-                span!(title.to_string()),
-                " ",
-                span!("#{}", issue.number).dim(),
-            ],
+            Line::from(summary),
             line![
                 span!(symbols::shade::FULL).style({
                     if matches!(issue.state, IssueState::Open) {
@@ -858,6 +1134,7 @@ pub(crate) fn render_issue_close_popup(
     }
 }
 
+#[derive(Clone)]
 pub struct IssueListItem(pub IssueId);
 
 #[async_trait(?Send)]
@@ -906,6 +1183,12 @@ impl Component for IssueList<'_> {
                 if self.screen != MainScreen::List {
                     return Ok(());
                 }
+                if self.state == LoadingState::Loading
+                    && pressed(KeyAction::CancelLoading, event)
+                    && self.cancel_load().await
+                {
+                    return Ok(());
+                }
                 if self.handle_bookmark_popup_event(event).await? {
                     return Ok(());
                 }
@@ -913,7 +1196,49 @@ impl Component for IssueList<'_> {
                     return Ok(());
                 }
 
+                if self.inner_state == IssueListState::Filtering
+                    && self.filter_input_state.is_focused()
+                {
+                    match event {
+                        ct_event!(keycode press Esc) => self.clear_filter(),
+                        ct_event!(keycode press Enter) => {
+                            self.list_state.focus.set(true);
+                            self.filter_input_state.focus.set(false);
+                        }
+                        _ => {
+                            if self
+                                .filter_input_state
+                                .handle(event, rat_widget::event::Regular)
+                                == TextOutcome::TextChanged
+                            {
+                                self.apply_filter();
+                            }
+                        }
+                    }
+                    if let Some(action_tx) = self.action_tx.as_ref() {
+                        action_tx.send(Action::ForceRender).await?;
+                    }
+                    return Ok(());
+                }
+
                 match event {
+                    ct_event!(key press '/')
+                        if self.list_state.is_focused()
+                            && self.inner_state != IssueListState::AssigningInput =>
+                    {
+                        self.open_filter();
+                        return Ok(());
+                    }
+                    ct_event!(keycode press Esc)
+                        if self.list_state.is_focused()
+                            && self.inner_state == IssueListState::Filtering =>
+                    {
+                        self.clear_filter();
+                        if let Some(action_tx) = self.action_tx.as_ref() {
+                            action_tx.send(Action::ForceRender).await?;
+                        }
+                        return Ok(());
+                    }
                     ct_event!(key press 'a') if self.list_state.is_focused() => {
                         self.inner_state = IssueListState::AssigningInput;
                         self.assignment_mode = AssignmentMode::Add;
@@ -959,6 +1284,10 @@ impl Component for IssueList<'_> {
                             }
                         }
                     }
+                    ct_event!(key press 'm') if self.list_state.is_focused() => {
+                        self.load_more();
+                        return Ok(());
+                    }
                     ct_event!(key press 'n') if self.list_state.is_focused() => {
                         self.action_tx
                             .as_ref()
@@ -1061,7 +1390,7 @@ impl Component for IssueList<'_> {
                                 .filter_map(|s| if s.is_empty() { None } else { Some(&**s) })
                                 .collect::<Vec<_>>();
 
-                            let issue_handler = if let Some(client) = GITHUB_CLIENT.get() {
+                            let issue_handler = if let Ok(client) = client() {
                                 client.inner().issues(owner, repo)
                             } else {
                                 let _ = done_tx.send(());
@@ -1119,49 +1448,14 @@ impl Component for IssueList<'_> {
 
                 self.assign_input_state
                     .handle(event, rat_widget::event::Regular);
-                if let rat_widget::event::Outcome::Changed =
-                    self.list_state.handle(event, rat_widget::event::Regular)
+                if let rat_widget::event::Outcome::Changed = self
+                    .list_state
+                    .handle(&*list_motion(event), rat_widget::event::Regular)
                 {
                     let selected = self.list_state.selected_checked();
                     if let Some(selected) = selected {
-                        if selected == self.issues.len() - 1
-                            && let Some(page) = &self.page
-                        {
-                            let tx = self
-                                .action_tx
-                                .as_ref()
-                                .ok_or_else(|| {
-                                    AppError::Other(anyhow!(
-                                        "issue list action channel unavailable"
-                                    ))
-                                })?
-                                .clone();
-                            let page_next = page.next.clone();
-                            self.state = LoadingState::Loading;
-                            tokio::spawn(async move {
-                                let Some(client) = GITHUB_CLIENT.get() else {
-                                    let _ = tx.send(crate::ui::Action::FinishedLoading).await;
-                                    return;
-                                };
-                                let p = client.inner().get_page::<Issue>(&page_next).await;
-                                if let Ok(pres) = p
-                                    && let Some(mut p) = pres
-                                {
-                                    let items = std::mem::take(&mut p.items);
-                                    let items = items
-                                        .into_iter()
-                                        .filter(|i| i.pull_request.is_none())
-                                        .collect();
-                                    p.items = items;
-                                    let _ = tx
-                                        .send(crate::ui::Action::NewPage(
-                                            Arc::new(p),
-                                            MergeStrategy::Append,
-                                        ))
-                                        .await;
-                                }
-                                let _ = tx.send(crate::ui::Action::FinishedLoading).await;
-                            });
+                        if selected == self.issues.len() - 1 {
+                            self.load_more();
                         }
                         let (issue_number, labels, preview_seed) = {
                             let pool = self.issue_pool.read().expect("issue pool lock poisoned");
@@ -1204,11 +1498,13 @@ impl Component for IssueList<'_> {
                         })
                         .collect::<Vec<_>>()
                 };
+                let loaded = self.unfiltered.as_mut().unwrap_or(&mut self.issues);
                 match merge_strat {
-                    MergeStrategy::Replace => self.issues = converted,
-                    MergeStrategy::Append => self.issues.extend(converted),
+                    MergeStrategy::Replace => *loaded = converted,
+                    MergeStrategy::Append => loaded.extend(converted),
                 }
-                let count = self.issues.len().min(u32::MAX as usize) as u32;
+                let count = loaded.len().min(u32::MAX as usize) as u32;
+                self.apply_filter();
                 LOADED_ISSUE_COUNT.store(count, Ordering::Relaxed);
                 let mut page_meta = (*p).clone();
                 page_meta.items.clear();
@@ -1218,6 +1514,9 @@ impl Component for IssueList<'_> {
             crate::ui::Action::FinishedLoading => {
                 self.state = LoadingState::Loaded;
             }
+            crate::ui::Action::SearchTermsChanged { terms } => {
+                self.highlight_terms = terms;
+            }
             crate::ui::Action::IssueCloseSuccess { issue_id } => {
                 let (issue_number, preview_seed) = {
                     let pool = self.issue_pool.read().expect("issue pool lock poisoned");
@@ -1236,6 +1535,15 @@ impl Component for IssueList<'_> {
                 if let Some(existing_idx) = existing_idx {
                     self.issues[existing_idx].0 = issue_id;
                 }
+                if let Some(unfiltered) = self.unfiltered.as_mut() {
+                    let pool = self.issue_pool.read().expect("issue pool lock poisoned");
+                    for item in unfiltered
+                        .iter_mut()
+                        .filter(|item| pool.get_issue(item.0).number == issue_number)
+                    {
+                        item.0 = issue_id;
+                    }
+                }
                 let initiated_here = self
                     .close_popup
                     .as_ref()
@@ -1332,6 +1640,14 @@ impl Component for IssueList<'_> {
                     self.bookmark_error = Some(message.to_string());
                 }
             }
+            crate::ui::Action::OpenIssueFromHistory { number } => {
+                self.open_from_history(number).await?;
+            }
+            crate::ui::Action::EnterIssueDetails { ref seed }
+                if self.seen_issues.mark_seen(seed.number, seed.updated_ts) =>
+            {
+                let _ = self.seen_issues.write_to_file(&self.owner, &self.repo);
+            }
             crate::ui::Action::ChangeIssueScreen(screen) => {
                 self.screen = screen;
                 if screen == MainScreen::List {
@@ -1375,14 +1691,28 @@ impl Component for IssueList<'_> {
     fn capture_focus_event(&self, _event: &crossterm::event::Event) -> bool {
         self.close_popup.is_some() || self.bookmark_popup.is_some()
     }
+
+    fn help_lines(&self) -> Vec<Line<'static>> {
+        if self.inner_state == IssueListState::Filtering {
+            keybind_lines(FILTER_HELP)
+        } else {
+            keybind_lines(HELP)
+        }
+    }
 }
 
 impl HasFocus for IssueList<'_> {
     fn build(&self, builder: &mut rat_widget::focus::FocusBuilder) {
         let tag = builder.start(self);
         builder.widget(&self.list_state);
-        if self.inner_state == IssueListState::AssigningInput {
-            builder.widget(&self.assign_input_state);
+        match self.inner_state {
+            IssueListState::AssigningInput => {
+                builder.widget(&self.assign_input_state);
+            }
+            IssueListState::Filtering => {
+                builder.widget(&self.filter_input_state);
+            }
+            IssueListState::Normal => {}
         }
         builder.end(tag);
     }
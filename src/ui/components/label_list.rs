@@ -1,7 +1,7 @@
 use std::{
     cmp::min,
+    collections::VecDeque,
     slice,
-    str::FromStr,
     time::{Duration, Instant},
 };
 
@@ -19,6 +19,7 @@ use ratatui::{
     buffer::Buffer,
     layout::{Constraint, Direction, Layout as TuiLayout, Rect},
     style::{Color, Style, Stylize},
+    text::Line,
     widgets::{Block, Clear, ListItem, Paragraph, StatefulWidget, Widget},
 };
 use ratatui_macros::{line, span};
@@ -27,14 +28,22 @@ use throbber_widgets_tui::{BRAILLE_SIX_DOUBLE, Throbber, ThrobberState, WhichUse
 use tracing::error;
 
 use crate::{
-    app::GITHUB_CLIENT,
-    errors::AppError,
+    app::client,
+    errors::{AppError, describe_request_error},
+    github::with_retry,
     ui::{
-        Action, AppState, COLOR_PROFILE,
-        components::{Component, help::HelpElementKind, issue_list::MainScreen},
+        Action, AppState,
+        components::{
+            Component,
+            help::{HelpElementKind, keybind_lines},
+            issue_list::MainScreen,
+            search_bar::split_labels,
+            status_bar::rate_limit_resets_in,
+        },
+        keymap::{KeyAction, keymap, list_motion},
         layout::Layout,
         toast_action,
-        utils::get_border_style,
+        utils::{get_border_style, label_color},
         widgets::color_picker::{ColorPicker, ColorPickerState},
     },
 };
@@ -43,38 +52,173 @@ use ratatui_toaster::ToastType;
 const MARKER: &str = ratatui::symbols::marker::DOT;
 const STATUS_TTL: Duration = Duration::from_secs(3);
 const DEFAULT_COLOR: &str = "ededed";
+const MAX_LABEL_SUGGESTIONS: usize = 6;
 pub const HELP: &[HelpElementKind] = &[
     crate::help_text!("Label List Help"),
-    crate::help_keybind!("Up/Down", "select label"),
-    crate::help_keybind!("a", "add label to selected issue"),
-    crate::help_keybind!("d", "remove selected label from issue"),
-    crate::help_keybind!("f", "open popup label regex search"),
+    crate::help_keybind!("Up/Down, j/k", "select label"),
+    crate::help_keybind!("g/G", "select first / last label"),
+    crate::help_action!(
+        AddLabel,
+        "add label(s) to selected issue (separate with --label-separator, ';' by default; quote names containing it)"
+    ),
+    crate::help_action!(
+        RemoveLabel,
+        "remove selected label from issue (y/n to confirm)"
+    ),
+    crate::help_action!(
+        UndoRemoveLabel,
+        "undo the last removal while its status is shown"
+    ),
+    crate::help_action!(RecolorLabel, "change selected label's color"),
+    crate::help_action!(FindLabel, "open popup label regex search"),
+    crate::help_action!(ReloadLabels, "reload cached repository labels"),
+    crate::help_action!(CycleLabelSort, "cycle sort: name, color, repository order"),
     crate::help_keybind!("Ctrl+I", "toggle case-insensitive search (popup)"),
     crate::help_keybind!("Enter", "submit add/create input"),
+    crate::help_keybind!("Up/Down (adding)", "pick an existing label suggestion"),
     crate::help_keybind!("Arrows", "navigate label color picker"),
     crate::help_keybind!("Tab / Shift+Tab", "switch input and picker focus"),
-    crate::help_keybind!("Type hex", "set color manually"),
+    crate::help_keybind!("# (picker)", "type a custom hex color"),
     crate::help_keybind!("Esc", "cancel current label edit flow"),
     crate::help_keybind!("y / n", "confirm or cancel creating missing label"),
 ];
 
+/// Order labels are listed in, both on the issue and in search results.
+#[derive(clap::ValueEnum, Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum LabelSort {
+    /// Alphabetical, ignoring case.
+    #[default]
+    Name,
+    /// By hue, from red through violet, with grays last.
+    Color,
+    /// The order GitHub returns them in.
+    Api,
+}
+
+impl LabelSort {
+    fn next(self) -> Self {
+        match self {
+            LabelSort::Name => LabelSort::Color,
+            LabelSort::Color => LabelSort::Api,
+            LabelSort::Api => LabelSort::Name,
+        }
+    }
+
+    fn describe(self) -> &'static str {
+        match self {
+            LabelSort::Name => "name",
+            LabelSort::Color => "color",
+            LabelSort::Api => "repository order",
+        }
+    }
+}
+
+/// Hue of a `rrggbb` label color in degrees, turned 15° so slightly bluish reds still sort with
+/// red instead of after violet. `None` for grays and invalid colors, which are listed last.
+fn label_hue(hex: &str) -> Option<u16> {
+    let value = u32::from_str_radix(hex.trim_start_matches('#'), 16).ok()?;
+    let [_, r, g, b] = value.to_be_bytes().map(i32::from);
+    let max = r.max(g).max(b);
+    let delta = max - r.min(g).min(b);
+    // Near-grays have no meaningful hue.
+    if delta < 16 {
+        return None;
+    }
+    let sector = if max == r {
+        (g - b) as f32 / delta as f32
+    } else if max == g {
+        (b - r) as f32 / delta as f32 + 2.0
+    } else {
+        (r - g) as f32 / delta as f32 + 4.0
+    };
+    Some((sector * 60.0 + 15.0).rem_euclid(360.0) as u16)
+}
+
+/// Returns the section a label belongs to when grouping by prefix, e.g. `area` for
+/// `area: parser`. Labels without a non-empty prefix and remainder aren't grouped.
+fn label_group(name: &str) -> Option<&str> {
+    let (group, rest) = name.split_once(':')?;
+    let group = group.trim();
+    (!group.is_empty() && !rest.trim().is_empty()).then_some(group)
+}
+
+/// Sorts labels in place. When grouping, prefixed labels are kept together by group and
+/// ungrouped labels come last; the sort order still applies within each section.
+fn sort_labels(labels: &mut [LabelListItem], sort: LabelSort, group: bool) {
+    labels.sort_by(|a, b| {
+        let by_group = if group {
+            match (label_group(&a.name), label_group(&b.name)) {
+                (Some(a), Some(b)) => a.to_lowercase().cmp(&b.to_lowercase()),
+                (Some(_), None) => std::cmp::Ordering::Less,
+                (None, Some(_)) => std::cmp::Ordering::Greater,
+                (None, None) => std::cmp::Ordering::Equal,
+            }
+        } else {
+            std::cmp::Ordering::Equal
+        };
+        by_group.then_with(|| match sort {
+            LabelSort::Name => a.name.to_lowercase().cmp(&b.name.to_lowercase()),
+            LabelSort::Color => match (label_hue(&a.color), label_hue(&b.color)) {
+                (Some(a), Some(b)) => a.cmp(&b),
+                (Some(_), None) => std::cmp::Ordering::Less,
+                (None, Some(_)) => std::cmp::Ordering::Greater,
+                (None, None) => std::cmp::Ordering::Equal,
+            }
+            .then_with(|| a.name.to_lowercase().cmp(&b.name.to_lowercase())),
+            LabelSort::Api => std::cmp::Ordering::Equal,
+        })
+    });
+}
+
 #[derive(Debug)]
 pub struct LabelList {
     state: ListState<RowSelection>,
     labels: Vec<LabelListItem>,
+    /// Names of the issue's labels in the order GitHub returned them.
+    api_order: Vec<String>,
     action_tx: Option<tokio::sync::mpsc::Sender<Action>>,
     current_issue_number: Option<u64>,
     mode: LabelEditMode,
     status_message: Option<StatusMessage>,
     pending_status: Option<String>,
+    /// Label whose removal is in flight, offered for undo once it succeeds.
+    pending_undo: Option<String>,
+    /// Label that `u` puts back, available while the removal status is shown.
+    undo_remove: Option<String>,
     owner: String,
     repo: String,
     screen: MainScreen,
     popup_search: Option<PopupLabelSearchState>,
     label_search_request_seq: u64,
+    skip_create_confirm: bool,
+    sort: LabelSort,
+    group: bool,
+    repo_labels: Option<RepoLabelCache>,
+    repo_labels_loading: bool,
+    label_cache_ttl: Duration,
+    label_separator: char,
+    /// Missing labels still waiting to be created after the one being created now.
+    create_queue: VecDeque<String>,
     index: usize,
 }
 
+/// Every label defined in the repository, as of `fetched_at`.
+#[derive(Debug)]
+struct RepoLabelCache {
+    labels: Vec<Label>,
+    fetched_at: Instant,
+}
+
+impl RepoLabelCache {
+    fn is_stale(&self, ttl: Duration) -> bool {
+        self.fetched_at.elapsed() >= ttl
+    }
+
+    fn contains(&self, name: &str) -> bool {
+        self.labels.iter().any(|label| label.name == name)
+    }
+}
+
 #[derive(Debug, Clone)]
 struct LabelListItem(Label);
 
@@ -83,22 +227,71 @@ enum LabelEditMode {
     Idle,
     Adding {
         input: TextInputState,
+        /// Highlighted entry of the suggestion dropdown, if any.
+        suggestion: Option<usize>,
     },
-    ConfirmCreate {
+    ConfirmRemove {
         name: String,
     },
+    /// Offers to create labels that weren't found, one after another.
+    ConfirmCreate {
+        names: Vec<String>,
+    },
     CreateColor {
         name: String,
         input: TextInputState,
         picker: ColorPickerState,
     },
+    /// New color for a label that already exists in the repository.
+    EditColor {
+        name: String,
+        input: TextInputState,
+        picker: ColorPickerState,
+    },
+    /// Optional description for a new label; an empty input creates it without one.
+    CreateDescription {
+        name: String,
+        color: String,
+        input: TextInputState,
+    },
 }
 
 impl LabelEditMode {
+    /// Color selection for a new label, starting from [`DEFAULT_COLOR`]. The palette grid has
+    /// focus so a standard color is one keystroke away; the hex input is reached with Tab or `#`.
+    fn create_color(name: String) -> Self {
+        let picker = ColorPickerState::with_initial_hex(DEFAULT_COLOR);
+        picker.focus().set(true);
+        let mut input = TextInputState::new();
+        input.set_text(picker.selected_hex());
+        LabelEditMode::CreateColor {
+            name,
+            input,
+            picker,
+        }
+    }
+
+    /// Recoloring of `label`, seeded with its current color. The hex input keeps the exact color
+    /// even when the palette has no match, so Enter before moving the picker leaves it unchanged.
+    fn edit_color(label: &Label) -> Self {
+        let picker = ColorPickerState::with_initial_hex(&label.color);
+        picker.focus().set(true);
+        let mut input = TextInputState::new();
+        input.set_text(&label.color);
+        LabelEditMode::EditColor {
+            name: label.name.clone(),
+            input,
+            picker,
+        }
+    }
+
     fn input(&self) -> Option<&TextInputState> {
         match self {
-            LabelEditMode::Adding { input } => Some(input),
-            LabelEditMode::CreateColor { input, .. } => Some(input),
+            LabelEditMode::Adding { input, .. } => Some(input),
+            LabelEditMode::CreateColor { input, .. } | LabelEditMode::EditColor { input, .. } => {
+                Some(input)
+            }
+            LabelEditMode::CreateDescription { input, .. } => Some(input),
             _ => None,
         }
     }
@@ -140,29 +333,16 @@ impl std::ops::Deref for LabelListItem {
 
 impl From<&LabelListItem> for ListItem<'_> {
     fn from(value: &LabelListItem) -> Self {
-        let rgb = &value.0.color;
-        let mut c = Color::from_str(&format!("#{}", rgb)).unwrap_or(Color::Gray);
-        if let Some(profile) = COLOR_PROFILE.get() {
-            let adapted = profile.adapt_color(c);
-            if let Some(adapted) = adapted {
-                c = adapted;
-            }
-        }
-        let line = line![span!("{} {}", MARKER, value.0.name).fg(c)];
+        let line = line![span!("{} {}", MARKER, value.0.name).style(label_style(value))];
         ListItem::new(line)
     }
 }
 
-fn popup_list_item(value: &LabelListItem) -> ListItem<'_> {
-    let rgb = &value.0.color;
-    let mut c = Color::from_str(&format!("#{}", rgb)).unwrap_or(Color::Gray);
-    if let Some(profile) = COLOR_PROFILE.get() {
-        let adapted = profile.adapt_color(c);
-        if let Some(adapted) = adapted {
-            c = adapted;
-        }
-    }
+fn label_style(value: &LabelListItem) -> Style {
+    Style::new().fg(label_color(&value.0.color))
+}
 
+fn popup_list_item(value: &LabelListItem) -> ListItem<'_> {
     let description = value
         .0
         .description
@@ -170,27 +350,122 @@ fn popup_list_item(value: &LabelListItem) -> ListItem<'_> {
         .filter(|desc| !desc.trim().is_empty())
         .unwrap_or("No description");
     let lines = vec![
-        line![span!("{} {}", MARKER, value.0.name).fg(c)],
+        line![span!("{} {}", MARKER, value.0.name).style(label_style(value))],
         line![span!("  {description}").dim()],
     ];
     ListItem::new(lines)
 }
 
+/// Repository labels whose names contain `query` case-insensitively, excluding those already
+/// `applied` to the issue. Prefix matches come first.
+fn suggest_labels(
+    repo_labels: &[Label],
+    applied: &[LabelListItem],
+    query: &str,
+) -> Vec<LabelListItem> {
+    let query = query.trim().to_lowercase();
+    if query.is_empty() {
+        return Vec::new();
+    }
+    let mut matches = repo_labels
+        .iter()
+        .filter(|label| !applied.iter().any(|item| item.name == label.name))
+        .filter(|label| label.name.to_lowercase().contains(&query))
+        .cloned()
+        .map(LabelListItem)
+        .collect::<Vec<_>>();
+    matches.sort_by_key(|label| !label.name.to_lowercase().starts_with(&query));
+    matches.truncate(MAX_LABEL_SUGGESTIONS);
+    matches
+}
+
+/// The label being typed in the add input: everything after the last `separator`.
+fn last_label(input: &str, separator: char) -> &str {
+    input.rsplit(separator).next().unwrap_or(input)
+}
+
+/// Status line summarizing which labels were added, which failed and which don't exist yet.
+fn add_report_status(added: &[String], failed: &[String], missing: &[String]) -> String {
+    let mut parts = Vec::new();
+    if !added.is_empty() {
+        parts.push(format!("Added: {}", added.join(", ")));
+    }
+    if !failed.is_empty() {
+        parts.push(format!("Failed: {}", failed.join(", ")));
+    }
+    if !missing.is_empty() {
+        parts.push(format!("Not found: {}", missing.join(", ")));
+    }
+    parts.join(" · ")
+}
+
+fn one_line(err: &OctoError) -> String {
+    describe_request_error(err, rate_limit_resets_in())
+}
+
+/// Builds the rows of the label list. When grouping, the first label of each section carries
+/// the section header so rows stay aligned with `labels` for selection.
+fn list_items(labels: &[LabelListItem], group: bool) -> Vec<ListItem<'_>> {
+    let mut previous: Option<Option<&str>> = None;
+    let has_groups = group && labels.iter().any(|l| label_group(&l.name).is_some());
+    labels
+        .iter()
+        .map(|label| {
+            let item = ListItem::from(label);
+            if !has_groups {
+                return item;
+            }
+            let group = label_group(&label.name);
+            let is_new_section = previous
+                .is_none_or(|prev| prev.map(str::to_lowercase) != group.map(str::to_lowercase));
+            previous = Some(group);
+            if !is_new_section {
+                return item;
+            }
+            let header = line![span!("{}", group.unwrap_or("other")).bold().dim()];
+            let row = line![span!("{} {}", MARKER, label.name).style(label_style(label))];
+            ListItem::new(vec![header, row])
+        })
+        .collect()
+}
+
 impl LabelList {
-    pub fn new(AppState { repo, owner, .. }: AppState) -> Self {
+    pub fn new(
+        AppState {
+            repo,
+            owner,
+            skip_label_confirm,
+            label_sort,
+            group_labels,
+            label_cache_ttl,
+            label_separator,
+            ..
+        }: AppState,
+    ) -> Self {
         Self {
             state: Default::default(),
             labels: vec![],
+            api_order: Vec::new(),
             action_tx: None,
             current_issue_number: None,
             mode: LabelEditMode::Idle,
             status_message: None,
             pending_status: None,
+            pending_undo: None,
+            undo_remove: None,
             owner,
             repo,
             screen: MainScreen::default(),
             popup_search: None,
             label_search_request_seq: 0,
+            skip_create_confirm: skip_label_confirm,
+            sort: label_sort,
+            group: group_labels,
+            repo_labels: None,
+            repo_labels_loading: false,
+            label_cache_ttl,
+            label_separator,
+            create_queue: VecDeque::new(),
             index: 0,
         }
     }
@@ -223,17 +498,16 @@ impl LabelList {
             .border_type(ratatui::widgets::BorderType::Rounded)
             .title(title)
             .border_style(get_border_style(&self.state));
-        let list = rat_widget::list::List::<RowSelection>::new(
-            self.labels.iter().map(Into::<ListItem>::into),
-        )
-        .select_style(Style::default().bg(Color::Black))
-        .focus_style(Style::default().bold().bg(Color::Black))
-        .block(block);
+        let list =
+            rat_widget::list::List::<RowSelection>::new(list_items(&self.labels, self.group))
+                .select_style(Style::default().bg(Color::Black))
+                .focus_style(Style::default().bold().bg(Color::Black))
+                .block(block);
         list.render(list_area, buf, &mut self.state);
 
         if let Some(area) = footer_area {
             match &mut self.mode {
-                LabelEditMode::Adding { input } => {
+                LabelEditMode::Adding { input, .. } => {
                     let widget = TextInput::new().block(
                         Block::bordered()
                             .border_type(ratatui::widgets::BorderType::Rounded)
@@ -242,8 +516,24 @@ impl LabelList {
                     );
                     widget.render(area, buf, input);
                 }
-                LabelEditMode::ConfirmCreate { name } => {
-                    let prompt = format!("Label \"{name}\" not found. Create? (y/n)");
+                LabelEditMode::ConfirmRemove { name } => {
+                    let prompt = format!("Remove label \"{name}\"? (y/n)");
+                    Paragraph::new(prompt)
+                        .block(
+                            Block::bordered()
+                                .border_type(ratatui::widgets::BorderType::Rounded)
+                                .border_style(Style::default().yellow())
+                                .title("Confirm [y/n]"),
+                        )
+                        .render(area, buf);
+                }
+                LabelEditMode::ConfirmCreate { names } => {
+                    let prompt = match names.as_slice() {
+                        [name] => format!("Label \"{name}\" not found. Create? (y/n)"),
+                        names => {
+                            format!("Labels {} not found. Create them? (y/n)", names.join(", "))
+                        }
+                    };
                     Paragraph::new(prompt)
                         .block(
                             Block::bordered()
@@ -253,16 +543,37 @@ impl LabelList {
                         )
                         .render(area, buf);
                 }
-                LabelEditMode::CreateColor { input, .. } => {
+                LabelEditMode::CreateColor { name, input, .. } => {
+                    let title = format!("Color for new label \"{name}\" (#RRGGBB)");
                     let widget = TextInput::new().block(
                         Block::bordered()
                             .border_type(ratatui::widgets::BorderType::Rounded)
                             .border_style(get_border_style(input))
-                            .title("Label color (#RRGGBB)"),
+                            .title(title),
                     );
                     widget.render(area, buf, input);
                     color_input_area = Some(area);
                 }
+                LabelEditMode::EditColor { name, input, .. } => {
+                    let title = format!("New color for \"{name}\" (#RRGGBB)");
+                    let widget = TextInput::new().block(
+                        Block::bordered()
+                            .border_type(ratatui::widgets::BorderType::Rounded)
+                            .border_style(get_border_style(input))
+                            .title(title),
+                    );
+                    widget.render(area, buf, input);
+                    color_input_area = Some(area);
+                }
+                LabelEditMode::CreateDescription { input, .. } => {
+                    let widget = TextInput::new().block(
+                        Block::bordered()
+                            .border_type(ratatui::widgets::BorderType::Rounded)
+                            .border_style(get_border_style(input))
+                            .title("Label description (optional, Enter to create)"),
+                    );
+                    widget.render(area, buf, input);
+                }
                 LabelEditMode::Idle => {
                     if let Some(status) = &self.status_message {
                         Paragraph::new(status.message.clone()).render(area, buf);
@@ -273,10 +584,58 @@ impl LabelList {
 
         self.render_popup(area, buf);
         self.render_color_picker(area, buf, color_input_area);
+        if let Some(anchor) = footer_area {
+            self.render_suggestions(anchor, buf);
+        }
+    }
+
+    fn label_suggestions(&self, query: &str) -> Vec<LabelListItem> {
+        self.repo_labels
+            .as_ref()
+            .map(|cache| suggest_labels(&cache.labels, &self.labels, query))
+            .unwrap_or_default()
+    }
+
+    fn render_suggestions(&self, anchor: Rect, buf: &mut Buffer) {
+        let LabelEditMode::Adding { input, suggestion } = &self.mode else {
+            return;
+        };
+        let suggestions = self.label_suggestions(last_label(input.text(), self.label_separator));
+        if suggestions.is_empty() {
+            return;
+        }
+        let height = (suggestions.len() as u16 + 2).min(anchor.y);
+        let area = Rect {
+            x: anchor.x,
+            y: anchor.y.saturating_sub(height),
+            width: anchor.width,
+            height,
+        };
+        let items = suggestions
+            .iter()
+            .enumerate()
+            .map(|(idx, label)| {
+                let item = ListItem::from(label);
+                if *suggestion == Some(idx) {
+                    item.style(Style::new().bold().reversed())
+                } else {
+                    item
+                }
+            })
+            .collect::<Vec<_>>();
+        Clear.render(area, buf);
+        let list = ratatui::widgets::List::new(items).block(
+            Block::bordered()
+                .border_type(ratatui::widgets::BorderType::Rounded)
+                .title("Existing labels (Up/Down, Enter)"),
+        );
+        Widget::render(list, area, buf);
     }
 
     fn render_color_picker(&mut self, area: Layout, buf: &mut Buffer, anchor: Option<Rect>) {
-        let LabelEditMode::CreateColor { picker, .. } = &mut self.mode else {
+        let (LabelEditMode::CreateColor { picker, .. } | LabelEditMode::EditColor { picker, .. }) =
+            &mut self.mode
+        else {
             return;
         };
         let Some(anchor) = anchor else {
@@ -418,8 +777,11 @@ impl LabelList {
         matches!(
             self.mode,
             LabelEditMode::Adding { .. }
+                | LabelEditMode::ConfirmRemove { .. }
                 | LabelEditMode::ConfirmCreate { .. }
                 | LabelEditMode::CreateColor { .. }
+                | LabelEditMode::EditColor { .. }
+                | LabelEditMode::CreateDescription { .. }
         )
     }
 
@@ -428,17 +790,49 @@ impl LabelList {
             && status.at.elapsed() > STATUS_TTL
         {
             self.status_message = None;
+            self.undo_remove = None;
         }
     }
 
     fn set_status(&mut self, message: impl Into<String>) {
         let message = message.into().replace('\n', " ");
+        self.undo_remove = None;
         self.status_message = Some(StatusMessage {
             message,
             at: Instant::now(),
         });
     }
 
+    fn sort(&mut self) {
+        sort_labels(&mut self.labels, self.sort, self.group);
+    }
+
+    /// Switches to the next sort order, keeping the selected label selected.
+    fn cycle_sort(&mut self) {
+        let prev = self
+            .state
+            .selected_checked()
+            .and_then(|idx| self.labels.get(idx).map(|label| label.name.clone()));
+        self.sort = self.sort.next();
+        if self.sort == LabelSort::Api {
+            // Sorting is stable, so the API order has to be restored from the issue itself.
+            self.restore_api_order();
+        }
+        self.sort();
+        self.reset_selection(prev);
+        self.set_status(format!("Sorted by {}", self.sort.describe()));
+    }
+
+    fn restore_api_order(&mut self) {
+        let api_order = &self.api_order;
+        self.labels.sort_by_key(|label| {
+            api_order
+                .iter()
+                .position(|name| *name == label.name)
+                .unwrap_or(usize::MAX)
+        });
+    }
+
     fn set_mode(&mut self, mode: LabelEditMode) {
         self.mode = mode;
     }
@@ -464,15 +858,6 @@ impl LabelList {
         )
     }
 
-    fn normalize_label_name(input: &str) -> Option<String> {
-        let trimmed = input.trim();
-        if trimmed.is_empty() {
-            None
-        } else {
-            Some(trimmed.to_string())
-        }
-    }
-
     fn normalize_color(input: &str) -> Result<String, String> {
         let trimmed = input.trim();
         if trimmed.is_empty() {
@@ -522,12 +907,18 @@ impl LabelList {
         let Some(popup) = self.popup_search.as_mut() else {
             return;
         };
+        let selected = popup
+            .list_state
+            .selected_checked()
+            .and_then(|idx| popup.matches.get(idx).map(|label| label.name.clone()));
         popup
             .matches
             .extend(items.into_iter().map(Into::<LabelListItem>::into));
-        if popup.list_state.selected_checked().is_none() && !popup.matches.is_empty() {
-            let _ = popup.list_state.select(Some(0));
-        }
+        sort_labels(&mut popup.matches, self.sort, self.group);
+        let selected = selected
+            .and_then(|name| popup.matches.iter().position(|label| label.name == name))
+            .or((!popup.matches.is_empty()).then_some(0));
+        let _ = popup.list_state.select(selected);
     }
 
     async fn start_popup_search(&mut self) {
@@ -570,7 +961,7 @@ impl LabelList {
         let repo = self.repo.clone();
 
         tokio::spawn(async move {
-            let Some(client) = GITHUB_CLIENT.get() else {
+            let Ok(client) = client() else {
                 let _ = action_tx
                     .send(Action::LabelSearchError {
                         request_id,
@@ -595,7 +986,7 @@ impl LabelList {
                     let _ = action_tx
                         .send(Action::LabelSearchError {
                             request_id,
-                            message: err.to_string().replace('\n', " "),
+                            message: describe_request_error(&err, rate_limit_resets_in()),
                         })
                         .await;
                     return;
@@ -636,7 +1027,7 @@ impl LabelList {
                         let _ = action_tx
                             .send(Action::LabelSearchError {
                                 request_id,
-                                message: err.to_string().replace('\n', " "),
+                                message: describe_request_error(&err, rate_limit_resets_in()),
                             })
                             .await;
                         return;
@@ -654,6 +1045,55 @@ impl LabelList {
         });
     }
 
+    /// Refreshes the cached repository labels. Unless `force` is set, a cache younger than the
+    /// configured TTL is kept.
+    fn reload_repo_labels(&mut self, force: bool) {
+        if self.repo_labels_loading
+            || (!force
+                && self
+                    .repo_labels
+                    .as_ref()
+                    .is_some_and(|cache| !cache.is_stale(self.label_cache_ttl)))
+        {
+            return;
+        }
+        let Some(action_tx) = self.action_tx.clone() else {
+            return;
+        };
+        self.repo_labels_loading = true;
+        let owner = self.owner.clone();
+        let repo = self.repo.clone();
+
+        tokio::spawn(async move {
+            let Ok(client) = client() else {
+                let _ = action_tx
+                    .send(Action::RepoLabelsError {
+                        message: "GitHub client not initialized.".to_string(),
+                    })
+                    .await;
+                return;
+            };
+            let crab = client.inner();
+            let result = match crab
+                .issues(owner, repo)
+                .list_labels_for_repo()
+                .per_page(100u8)
+                .send()
+                .await
+            {
+                Ok(first) => crab.all_pages(first).await,
+                Err(err) => Err(err),
+            };
+            let action = match result {
+                Ok(labels) => Action::RepoLabelsLoaded { labels },
+                Err(err) => Action::RepoLabelsError {
+                    message: describe_request_error(&err, rate_limit_resets_in()),
+                },
+            };
+            let _ = action_tx.send(action).await;
+        });
+    }
+
     async fn apply_selected_popup_label(&mut self) {
         let Some(popup) = self.popup_search.as_mut() else {
             return;
@@ -667,7 +1107,7 @@ impl LabelList {
             return;
         };
         let name = label.name.clone();
-        self.handle_add_submit(name).await;
+        self.handle_add_submit(vec![name]).await;
         self.close_popup_search();
     }
 
@@ -707,12 +1147,18 @@ impl LabelList {
         true
     }
 
-    async fn handle_add_submit(&mut self, name: String) {
+    async fn handle_add_submit(&mut self, mut names: Vec<String>) {
         let Some(issue_number) = self.current_issue_number else {
             self.set_status("No issue selected.");
             return;
         };
-        if self.labels.iter().any(|l| l.name == name) {
+        let mut seen = Vec::new();
+        names.retain(|name| {
+            let new = !seen.contains(name) && !self.labels.iter().any(|l| &l.name == name);
+            seen.push(name.clone());
+            new
+        });
+        if names.is_empty() {
             self.set_status("Label already applied.");
             return;
         }
@@ -722,10 +1168,18 @@ impl LabelList {
         };
         let owner = self.owner.clone();
         let repo = self.repo.clone();
-        self.pending_status = Some(format!("Added: {name}"));
+        self.pending_status = None;
+        let known: Vec<bool> = names
+            .iter()
+            .map(|name| {
+                self.repo_labels.as_ref().is_some_and(|cache| {
+                    !cache.is_stale(self.label_cache_ttl) && cache.contains(name)
+                })
+            })
+            .collect();
 
         tokio::spawn(async move {
-            let Some(client) = GITHUB_CLIENT.get() else {
+            let Ok(client) = client() else {
                 let _ = action_tx
                     .send(Action::LabelEditError {
                         message: "GitHub client not initialized.".to_string(),
@@ -734,11 +1188,23 @@ impl LabelList {
                 return;
             };
             let handler = client.inner().issues(owner, repo);
-            match handler.get_label(&name).await {
-                Ok(_) => match handler
-                    .add_labels(issue_number, slice::from_ref(&name))
-                    .await
-                {
+            let mut existing = Vec::new();
+            let mut missing = Vec::new();
+            let mut failed = Vec::new();
+            for (name, known) in names.into_iter().zip(known) {
+                if known {
+                    existing.push(name);
+                    continue;
+                }
+                match with_retry(|| handler.get_label(&name)).await {
+                    Ok(_) => existing.push(name),
+                    Err(err) if LabelList::is_not_found(&err) => missing.push(name),
+                    Err(err) => failed.push(format!("{name} ({})", one_line(&err))),
+                }
+            }
+            let mut added = Vec::new();
+            if !existing.is_empty() {
+                match with_retry(|| handler.add_labels(issue_number, &existing)).await {
                     Ok(labels) => {
                         let _ = action_tx
                             .send(Action::IssueLabelsUpdated {
@@ -746,74 +1212,73 @@ impl LabelList {
                                 labels,
                             })
                             .await;
+                        added = existing;
                     }
                     Err(err) => {
-                        let _ = action_tx
-                            .send(toast_action(
-                                format!("Failed to add label: {}", err),
-                                ToastType::Error,
-                            ))
-                            .await;
-                        let _ = action_tx
-                            .send(Action::LabelEditError {
-                                message: err.to_string(),
-                            })
-                            .await;
-                    }
-                },
-                Err(err) => {
-                    if LabelList::is_not_found(&err) {
-                        let _ = action_tx
-                            .send(toast_action(
-                                format!("Label not found: {}", &name),
-                                ToastType::Warning,
-                            ))
-                            .await;
-                        let _ = action_tx
-                            .send(Action::LabelMissing { name: name.clone() })
-                            .await;
-                    } else {
-                        let _ = action_tx
-                            .send(toast_action(
-                                format!("Failed to add label: {}", err),
-                                ToastType::Error,
-                            ))
-                            .await;
-                        let _ = action_tx
-                            .send(Action::LabelEditError {
-                                message: err.to_string(),
-                            })
-                            .await;
+                        let reason = one_line(&err);
+                        failed.extend(existing.iter().map(|name| format!("{name} ({reason})")));
                     }
                 }
             }
+            if !failed.is_empty() {
+                let _ = action_tx
+                    .send(toast_action(
+                        format!("Failed to add: {}", failed.join(", ")),
+                        ToastType::Error,
+                    ))
+                    .await;
+            } else if !missing.is_empty() {
+                let _ = action_tx
+                    .send(toast_action(
+                        format!("Label not found: {}", missing.join(", ")),
+                        ToastType::Warning,
+                    ))
+                    .await;
+            }
+            let _ = action_tx
+                .send(Action::LabelAddReport {
+                    added,
+                    failed,
+                    missing,
+                })
+                .await;
         });
     }
 
-    async fn handle_remove_selected(&mut self) {
-        let Some(issue_number) = self.current_issue_number else {
+    /// Asks to confirm removing the selected label.
+    fn confirm_remove_selected(&mut self) -> Option<LabelEditMode> {
+        if self.current_issue_number.is_none() {
             self.set_status("No issue selected.");
-            return;
-        };
-        let Some(selected) = self.state.selected_checked() else {
+            return None;
+        }
+        let Some(label) = self
+            .state
+            .selected_checked()
+            .and_then(|idx| self.labels.get(idx))
+        else {
             self.set_status("No label selected.");
-            return;
+            return None;
         };
-        let Some(label) = self.labels.get(selected) else {
-            self.set_status("No label selected.");
+        Some(LabelEditMode::ConfirmRemove {
+            name: label.name.clone(),
+        })
+    }
+
+    async fn handle_remove(&mut self, name: String) {
+        let Some(issue_number) = self.current_issue_number else {
+            self.set_status("No issue selected.");
             return;
         };
-        let name = label.name.clone();
-
         let Some(action_tx) = self.action_tx.clone() else {
             return;
         };
         let owner = self.owner.clone();
         let repo = self.repo.clone();
-        self.pending_status = Some(format!("Removed: {name}"));
+        self.pending_status = Some(format!("Removed {name} — press u to undo"));
+        self.pending_undo = Some(name.clone());
 
         tokio::spawn(async move {
-            let Some(client) = GITHUB_CLIENT.get() else {
+            let Ok(client) = client() else {
                 let _ = action_tx
                     .send(Action::LabelEditError {
                         message: "GitHub client not initialized.".to_string(),
@@ -835,7 +1300,7 @@ impl LabelList {
                     error!("Failed to remove label: {err}");
                     let _ = action_tx
                         .send(Action::LabelEditError {
-                            message: err.to_string(),
+                            message: describe_request_error(&err, rate_limit_resets_in()),
                         })
                         .await;
                 }
@@ -843,7 +1308,37 @@ impl LabelList {
         });
     }
 
-    async fn handle_create_and_add(&mut self, name: String, color: String) {
+    fn recolor_label(&mut self, name: String, color: String) {
+        let Some(action_tx) = self.action_tx.clone() else {
+            return;
+        };
+        let owner = self.owner.clone();
+        let repo = self.repo.clone();
+        self.set_status(format!("Recoloring {name}..."));
+
+        tokio::spawn(async move {
+            let Ok(client) = client() else {
+                let _ = action_tx
+                    .send(Action::LabelEditError {
+                        message: "GitHub client not initialized.".to_string(),
+                    })
+                    .await;
+                return;
+            };
+            let action = match client
+                .update_label(&owner, &repo, &name, &color, None)
+                .await
+            {
+                Ok(label) => Action::RepoLabelUpdated { label },
+                Err(err) => Action::LabelEditError {
+                    message: err.describe(rate_limit_resets_in()),
+                },
+            };
+            let _ = action_tx.send(action).await;
+        });
+    }
+
+    async fn handle_create_and_add(&mut self, name: String, color: String, description: String) {
         let Some(issue_number) = self.current_issue_number else {
             self.set_status("No issue selected.");
             return;
@@ -853,10 +1348,14 @@ impl LabelList {
         };
         let owner = self.owner.clone();
         let repo = self.repo.clone();
-        self.pending_status = Some(format!("Added: {name}"));
+        self.pending_status = Some(if description.is_empty() {
+            format!("Added: {name}")
+        } else {
+            format!("Added: {name} (with description)")
+        });
 
         tokio::spawn(async move {
-            let Some(client) = GITHUB_CLIENT.get() else {
+            let Ok(client) = client() else {
                 let _ = action_tx
                     .send(Action::LabelEditError {
                         message: "GitHub client not initialized.".to_string(),
@@ -865,7 +1364,7 @@ impl LabelList {
                 return;
             };
             let handler = client.inner().issues(owner, repo);
-            match handler.create_label(&name, &color, "").await {
+            match handler.create_label(&name, &color, &description).await {
                 Ok(_) => match handler
                     .add_labels(issue_number, slice::from_ref(&name))
                     .await
@@ -881,7 +1380,7 @@ impl LabelList {
                     Err(err) => {
                         let _ = action_tx
                             .send(Action::LabelEditError {
-                                message: err.to_string(),
+                                message: describe_request_error(&err, rate_limit_resets_in()),
                             })
                             .await;
                     }
@@ -889,7 +1388,7 @@ impl LabelList {
                 Err(err) => {
                     let _ = action_tx
                         .send(Action::LabelEditError {
-                            message: err.to_string(),
+                            message: describe_request_error(&err, rate_limit_resets_in()),
                         })
                         .await;
                 }
@@ -917,11 +1416,21 @@ impl Component for LabelList {
                 }
 
                 enum SubmitAction {
-                    Add(String),
-                    Create { name: String, color: String },
+                    Add(Vec<String>),
+                    Remove(String),
+                    Create {
+                        name: String,
+                        color: String,
+                        description: String,
+                    },
+                    Recolor {
+                        name: String,
+                        color: String,
+                    },
                 }
 
                 let mut mode = std::mem::replace(&mut self.mode, LabelEditMode::Idle);
+                let recolor = matches!(mode, LabelEditMode::EditColor { .. });
                 let mut next_mode: Option<LabelEditMode> = None;
                 let mut submit_action: Option<SubmitAction> = None;
 
@@ -931,42 +1440,110 @@ impl Component for LabelList {
                         if let crossterm::event::Event::Key(key) = event
                             && self.popup_search.is_none()
                         {
-                            match key.code {
-                                crossterm::event::KeyCode::Char('a') => {
+                            let action = [
+                                KeyAction::AddLabel,
+                                KeyAction::ReloadLabels,
+                                KeyAction::RemoveLabel,
+                                KeyAction::UndoRemoveLabel,
+                                KeyAction::RecolorLabel,
+                                KeyAction::FindLabel,
+                                KeyAction::CycleLabelSort,
+                            ]
+                            .into_iter()
+                            .find(|action| keymap().matches(*action, key));
+                            match action {
+                                Some(KeyAction::AddLabel) => {
                                     if self.state.is_focused() {
                                         self.state.focus.set(false);
                                         let input = TextInputState::new_focused();
-                                        next_mode = Some(LabelEditMode::Adding { input });
+                                        next_mode = Some(LabelEditMode::Adding {
+                                            input,
+                                            suggestion: None,
+                                        });
+                                        self.reload_repo_labels(false);
                                         handled = true;
                                     }
                                 }
-                                crossterm::event::KeyCode::Char('d') => {
+                                Some(KeyAction::ReloadLabels) if self.state.is_focused() => {
+                                    self.reload_repo_labels(true);
+                                    handled = true;
+                                }
+                                Some(KeyAction::RemoveLabel) => {
                                     if self.state.is_focused() {
-                                        self.handle_remove_selected().await;
+                                        next_mode = self.confirm_remove_selected();
                                         handled = true;
                                     }
                                 }
-                                crossterm::event::KeyCode::Char('f') => {
-                                    if self.state.is_focused() {
+                                Some(KeyAction::UndoRemoveLabel) => {
+                                    self.expire_status();
+                                    if self.state.is_focused()
+                                        && let Some(name) = self.undo_remove.take()
+                                    {
+                                        submit_action = Some(SubmitAction::Add(vec![name]));
+                                        handled = true;
+                                    }
+                                }
+                                Some(KeyAction::RecolorLabel) => {
+                                    if self.state.is_focused()
+                                        && let Some(label) = self
+                                            .state
+                                            .selected_checked()
+                                            .and_then(|idx| self.labels.get(idx))
+                                    {
+                                        next_mode = Some(LabelEditMode::edit_color(label));
                                         self.state.focus.set(false);
-                                        self.open_popup_search();
                                         handled = true;
                                     }
                                 }
+                                Some(KeyAction::CycleLabelSort) if self.state.is_focused() => {
+                                    self.cycle_sort();
+                                    handled = true;
+                                }
+                                Some(KeyAction::FindLabel) if self.state.is_focused() => {
+                                    self.state.focus.set(false);
+                                    self.open_popup_search();
+                                    handled = true;
+                                }
                                 _ => {}
                             }
                         }
                         if !handled {
-                            self.state.handle(event, Regular);
+                            self.state.handle(&*list_motion(event), Regular);
                         }
                     }
-                    LabelEditMode::Adding { input } => {
+                    LabelEditMode::Adding { input, suggestion } => {
                         let mut skip_input = false;
+                        let query = last_label(input.text(), self.label_separator);
+                        let suggestions = self.label_suggestions(query);
                         if let crossterm::event::Event::Key(key) = event {
                             match key.code {
+                                crossterm::event::KeyCode::Down if !suggestions.is_empty() => {
+                                    *suggestion = Some(
+                                        suggestion.map_or(0, |idx| (idx + 1) % suggestions.len()),
+                                    );
+                                    skip_input = true;
+                                }
+                                crossterm::event::KeyCode::Up if !suggestions.is_empty() => {
+                                    *suggestion = suggestion.and_then(|idx| idx.checked_sub(1));
+                                    skip_input = true;
+                                }
+                                crossterm::event::KeyCode::Enter
+                                    if let Some(label) =
+                                        suggestion.and_then(|idx| suggestions.get(idx)) =>
+                                {
+                                    // The suggestion completes the label being typed.
+                                    let text = input.text();
+                                    let typed = text.len() - query.len();
+                                    let mut names =
+                                        split_labels(&text[..typed], self.label_separator);
+                                    names.push(label.name.clone());
+                                    submit_action = Some(SubmitAction::Add(names));
+                                    next_mode = Some(LabelEditMode::Idle);
+                                }
                                 crossterm::event::KeyCode::Enter => {
-                                    if let Some(name) = Self::normalize_label_name(input.text()) {
-                                        submit_action = Some(SubmitAction::Add(name));
+                                    let names = split_labels(input.text(), self.label_separator);
+                                    if !names.is_empty() {
+                                        submit_action = Some(SubmitAction::Add(names));
                                         next_mode = Some(LabelEditMode::Idle);
                                     } else {
                                         self.set_status("Label name required.");
@@ -980,28 +1557,47 @@ impl Component for LabelList {
                             }
                         }
                         if next_mode.is_none() && !skip_input {
+                            let before = input.text().to_string();
                             input.handle(event, Regular);
+                            if input.text() != before {
+                                *suggestion = None;
+                            }
                         }
                     }
-                    LabelEditMode::ConfirmCreate { name } => {
+                    LabelEditMode::ConfirmRemove { name } => {
+                        if let crossterm::event::Event::Key(key) = event {
+                            match key.code {
+                                crossterm::event::KeyCode::Char('y')
+                                | crossterm::event::KeyCode::Char('Y') => {
+                                    submit_action = Some(SubmitAction::Remove(name.clone()));
+                                    next_mode = Some(LabelEditMode::Idle);
+                                }
+                                crossterm::event::KeyCode::Char('n')
+                                | crossterm::event::KeyCode::Char('N')
+                                | crossterm::event::KeyCode::Esc => {
+                                    next_mode = Some(LabelEditMode::Idle);
+                                }
+                                _ => {}
+                            }
+                        }
+                    }
+                    LabelEditMode::ConfirmCreate { names } => {
                         if let crossterm::event::Event::Key(key) = event {
                             match key.code {
                                 crossterm::event::KeyCode::Char('y')
                                 | crossterm::event::KeyCode::Char('Y') => {
                                     self.state.focus.set(false);
-                                    let mut input = TextInputState::new_focused();
-                                    input.set_text(DEFAULT_COLOR);
-                                    let picker = ColorPickerState::with_initial_hex(DEFAULT_COLOR);
-                                    next_mode = Some(LabelEditMode::CreateColor {
-                                        name: name.clone(),
-                                        input,
-                                        picker,
-                                    });
+                                    self.create_queue = names.drain(..).collect();
+                                    next_mode = self
+                                        .create_queue
+                                        .pop_front()
+                                        .map(LabelEditMode::create_color);
                                 }
                                 crossterm::event::KeyCode::Char('n')
                                 | crossterm::event::KeyCode::Char('N')
                                 | crossterm::event::KeyCode::Esc => {
                                     self.pending_status = None;
+                                    self.create_queue.clear();
                                     next_mode = Some(LabelEditMode::Idle);
                                 }
                                 _ => {}
@@ -1012,6 +1608,11 @@ impl Component for LabelList {
                         name,
                         input,
                         picker,
+                    }
+                    | LabelEditMode::EditColor {
+                        name,
+                        input,
+                        picker,
                     } => {
                         let mut skip_input = false;
                         if matches!(
@@ -1020,47 +1621,54 @@ impl Component for LabelList {
                         ) {
                             skip_input = true;
                         }
-                        if matches!(picker.handle(event, Regular), Outcome::Changed) {
+                        if picker.is_focused() && matches!(event, ct_event!(key press '#')) {
+                            // Escape hatch from the palette to a custom hex value.
+                            picker.focus().set(false);
+                            input.focus().set(true);
+                            input.set_text("#");
+                            skip_input = true;
+                        } else if matches!(picker.handle(event, Regular), Outcome::Changed) {
                             input.set_text(picker.selected_hex());
                             skip_input = true;
                         }
                         if let crossterm::event::Event::Key(key) = event {
                             match key.code {
                                 crossterm::event::KeyCode::Enter => {
-                                    if picker.is_focused() {
-                                        submit_action = Some(SubmitAction::Create {
-                                            name: name.clone(),
-                                            color: picker.selected_hex().to_string(),
-                                        });
-                                        next_mode = Some(LabelEditMode::Idle);
-                                    } else {
-                                        match Self::normalize_color(input.text()) {
-                                            Ok(color) => {
-                                                submit_action = Some(SubmitAction::Create {
-                                                    name: name.clone(),
-                                                    color,
-                                                });
-                                                next_mode = Some(LabelEditMode::Idle);
-                                            }
-                                            Err(message) => {
-                                                if let Some(action_tx) = &self.action_tx {
-                                                    let _ = action_tx
-                                                        .send(toast_action(
-                                                            format!(
-                                                                "Invalid color: {}",
-                                                                input.text()
-                                                            ),
-                                                            ToastType::Error,
-                                                        ))
-                                                        .await?;
-                                                }
-                                                self.set_status(message);
-                                                skip_input = true;
+                                    // The input follows every picker move, so it holds the color
+                                    // to submit whichever of the two has focus.
+                                    let color = Self::normalize_color(input.text());
+                                    match color {
+                                        Ok(color) if recolor => {
+                                            submit_action = Some(SubmitAction::Recolor {
+                                                name: name.clone(),
+                                                color,
+                                            });
+                                            next_mode = Some(LabelEditMode::Idle);
+                                        }
+                                        Ok(color) => {
+                                            next_mode = Some(LabelEditMode::CreateDescription {
+                                                name: name.clone(),
+                                                color,
+                                                input: TextInputState::new_focused(),
+                                            });
+                                        }
+                                        Err(message) => {
+                                            if let Some(action_tx) = &self.action_tx {
+                                                let _ = action_tx
+                                                    .send(toast_action(
+                                                        format!("Invalid color: {}", input.text()),
+                                                        ToastType::Error,
+                                                    ))
+                                                    .await?;
                                             }
+                                            self.set_status(message);
+                                            skip_input = true;
                                         }
                                     }
                                 }
                                 crossterm::event::KeyCode::Esc => {
+                                    // Cancelling one creation drops the rest of the batch.
+                                    self.create_queue.clear();
                                     next_mode = Some(LabelEditMode::Idle);
                                 }
                                 _ => {}
@@ -1074,6 +1682,31 @@ impl Component for LabelList {
                             }
                         }
                     }
+                    LabelEditMode::CreateDescription { name, color, input } => {
+                        let mut skip_input = false;
+                        if let crossterm::event::Event::Key(key) = event {
+                            match key.code {
+                                crossterm::event::KeyCode::Enter => {
+                                    submit_action = Some(SubmitAction::Create {
+                                        name: name.clone(),
+                                        color: color.clone(),
+                                        description: input.text().trim().to_string(),
+                                    });
+                                    next_mode = Some(LabelEditMode::Idle);
+                                }
+                                crossterm::event::KeyCode::Esc => {
+                                    self.create_queue.clear();
+                                    next_mode = Some(LabelEditMode::Idle);
+                                }
+                                crossterm::event::KeyCode::Tab
+                                | crossterm::event::KeyCode::BackTab => skip_input = true,
+                                _ => {}
+                            }
+                        }
+                        if next_mode.is_none() && !skip_input {
+                            input.handle(event, Regular);
+                        }
+                    }
                 }
 
                 self.mode = next_mode.unwrap_or(mode);
@@ -1081,9 +1714,13 @@ impl Component for LabelList {
                 if let Some(action) = submit_action {
                     match action {
                         SubmitAction::Add(name) => self.handle_add_submit(name).await,
-                        SubmitAction::Create { name, color } => {
-                            self.handle_create_and_add(name, color).await
-                        }
+                        SubmitAction::Remove(name) => self.handle_remove(name).await,
+                        SubmitAction::Create {
+                            name,
+                            color,
+                            description,
+                        } => self.handle_create_and_add(name, color, description).await,
+                        SubmitAction::Recolor { name, color } => self.recolor_label(name, color),
                     }
                 }
             }
@@ -1092,13 +1729,16 @@ impl Component for LabelList {
                     .state
                     .selected_checked()
                     .and_then(|idx| self.labels.get(idx).map(|label| label.name.clone()));
+                self.api_order = labels.iter().map(|label| label.name.clone()).collect();
                 self.labels = labels
                     .into_iter()
                     .map(Into::<LabelListItem>::into)
                     .collect();
+                self.sort();
                 self.current_issue_number = Some(number);
                 self.reset_selection(prev);
                 self.pending_status = None;
+                self.create_queue.clear();
                 self.status_message = None;
                 self.set_mode(LabelEditMode::Idle);
                 self.close_popup_search();
@@ -1109,17 +1749,24 @@ impl Component for LabelList {
                         .state
                         .selected_checked()
                         .and_then(|idx| self.labels.get(idx).map(|label| label.name.clone()));
+                    self.api_order = labels.iter().map(|label| label.name.clone()).collect();
                     self.labels = labels
                         .into_iter()
                         .map(Into::<LabelListItem>::into)
                         .collect();
+                    self.sort();
                     self.reset_selection(prev);
                     let status = self
                         .pending_status
                         .take()
                         .unwrap_or_else(|| "Labels updated.".to_string());
                     self.set_status(status);
+                    self.undo_remove = self.pending_undo.take();
                     self.set_mode(LabelEditMode::Idle);
+                    if let Some(name) = self.create_queue.pop_front() {
+                        self.state.focus.set(false);
+                        self.set_mode(LabelEditMode::create_color(name));
+                    }
                 }
             }
             Action::LabelSearchPageAppend {
@@ -1167,12 +1814,58 @@ impl Component for LabelList {
                     popup.error = Some(message);
                 }
             }
-            Action::LabelMissing { name } => {
-                self.set_status("Label not found.");
-                self.set_mode(LabelEditMode::ConfirmCreate { name });
+            Action::LabelAddReport {
+                added,
+                failed,
+                missing,
+            } => {
+                self.set_status(add_report_status(&added, &failed, &missing));
+                if missing.is_empty() {
+                    return Ok(());
+                }
+                if self.skip_create_confirm {
+                    self.state.focus.set(false);
+                    self.create_queue = missing.into();
+                    if let Some(name) = self.create_queue.pop_front() {
+                        self.set_mode(LabelEditMode::create_color(name));
+                    }
+                } else {
+                    self.set_mode(LabelEditMode::ConfirmCreate { names: missing });
+                }
+            }
+            Action::ReloadRepoLabels => self.reload_repo_labels(true),
+            Action::RepoLabelsLoaded { labels } => {
+                self.repo_labels_loading = false;
+                self.set_status(format!("Loaded {} repository labels.", labels.len()));
+                self.repo_labels = Some(RepoLabelCache {
+                    labels,
+                    fetched_at: Instant::now(),
+                });
+            }
+            Action::RepoLabelsError { message } => {
+                // Label edits fall back to looking each label up, so a failed refresh only
+                // needs reporting.
+                self.repo_labels_loading = false;
+                self.set_status(format!("Failed to load repository labels: {message}"));
+            }
+            Action::RepoLabelUpdated { label } => {
+                if let Some(cache) = self.repo_labels.as_mut()
+                    && let Some(cached) = cache.labels.iter_mut().find(|l| l.name == label.name)
+                {
+                    *cached = label.clone();
+                }
+                self.set_status(format!("Recolored: {}", label.name));
+                if let Some(item) = self.labels.iter_mut().find(|l| l.name == label.name) {
+                    item.0 = label;
+                }
+                if let Some(action_tx) = self.action_tx.as_ref() {
+                    let _ = action_tx.send(Action::ForceRender).await;
+                }
             }
             Action::LabelEditError { message } => {
                 self.pending_status = None;
+                self.pending_undo = None;
+                self.create_queue.clear();
                 self.set_status(format!("Error: {message}"));
                 self.set_mode(LabelEditMode::Idle);
             }
@@ -1206,8 +1899,11 @@ impl Component for LabelList {
             return popup.input.screen_cursor();
         }
         match &self.mode {
-            LabelEditMode::Adding { input } => input.screen_cursor(),
-            LabelEditMode::CreateColor { input, .. } => input.screen_cursor(),
+            LabelEditMode::Adding { input, .. } => input.screen_cursor(),
+            LabelEditMode::CreateColor { input, .. } | LabelEditMode::EditColor { input, .. } => {
+                input.screen_cursor()
+            }
+            LabelEditMode::CreateDescription { input, .. } => input.screen_cursor(),
             _ => None,
         }
     }
@@ -1229,13 +1925,20 @@ impl Component for LabelList {
         }
     }
 
+    fn help_lines(&self) -> Vec<Line<'static>> {
+        keybind_lines(HELP)
+    }
+
     fn capture_focus_event(&self, _event: &crossterm::event::Event) -> bool {
         self.popup_search.is_some()
             || matches!(
                 self.mode,
                 LabelEditMode::Adding { .. }
+                    | LabelEditMode::ConfirmRemove { .. }
                     | LabelEditMode::ConfirmCreate { .. }
                     | LabelEditMode::CreateColor { .. }
+                    | LabelEditMode::EditColor { .. }
+                    | LabelEditMode::CreateDescription { .. }
             )
     }
 }
@@ -1250,7 +1953,9 @@ impl HasFocus for LabelList {
         if let Some(input) = self.mode.input() {
             builder.leaf_widget(input);
         }
-        if let LabelEditMode::CreateColor { picker, .. } = &self.mode {
+        if let LabelEditMode::CreateColor { picker, .. } | LabelEditMode::EditColor { picker, .. } =
+            &self.mode
+        {
             builder.leaf_widget(picker);
         }
         builder.end(tag);
@@ -1263,3 +1968,160 @@ impl HasFocus for LabelList {
     }
 }
 
+#[cfg(test)]
+mod tests {
+    use octocrab::models::Label;
+
+    use std::time::{Duration, Instant};
+
+    use rat_widget::focus::HasFocus;
+
+    use super::{
+        LabelEditMode, LabelList, LabelListItem, LabelSort, RepoLabelCache, add_report_status,
+        label_group, last_label, sort_labels, suggest_labels,
+    };
+
+    fn labels(names: &[&str]) -> Vec<LabelListItem> {
+        names
+            .iter()
+            .map(|name| {
+                let label: Label = serde_json::from_value(serde_json::json!({
+                    "id": 1,
+                    "node_id": "L",
+                    "url": "https://api.github.com/repos/o/r/labels/x",
+                    "name": name,
+                    "color": "ededed",
+                    "default": false,
+                }))
+                .expect("valid label");
+                LabelListItem::from(label)
+            })
+            .collect()
+    }
+
+    fn names(labels: &[LabelListItem]) -> Vec<&str> {
+        labels.iter().map(|label| label.name.as_str()).collect()
+    }
+
+    #[test]
+    fn groups_on_first_colon() {
+        assert_eq!(label_group("area: parser"), Some("area"));
+        assert_eq!(label_group("type:bug:critical"), Some("type"));
+        assert_eq!(label_group("bug"), None);
+        assert_eq!(label_group(":odd"), None);
+        assert_eq!(label_group("trailing:"), None);
+    }
+
+    #[test]
+    fn sorts_by_name_within_groups() {
+        let mut items = labels(&["wontfix", "type: bug", "area: ui", "Bug", "area: core"]);
+
+        sort_labels(&mut items, LabelSort::Name, false);
+        assert_eq!(
+            names(&items),
+            ["area: core", "area: ui", "Bug", "type: bug", "wontfix"]
+        );
+
+        sort_labels(&mut items, LabelSort::Name, true);
+        assert_eq!(
+            names(&items),
+            ["area: core", "area: ui", "type: bug", "Bug", "wontfix"]
+        );
+    }
+
+    #[test]
+    fn sorts_by_hue_with_grays_last() {
+        let mut items = labels(&["wontfix", "docs", "bug", "good first issue"]);
+        for (item, color) in items
+            .iter_mut()
+            .zip(["ffffff", "0075ca", "d73a4a", "7057ff"])
+        {
+            item.0.color = color.to_string();
+        }
+
+        sort_labels(&mut items, LabelSort::Color, false);
+        assert_eq!(
+            names(&items),
+            ["bug", "docs", "good first issue", "wontfix"]
+        );
+    }
+
+    #[test]
+    fn suggests_unapplied_labels_matching_substring() {
+        let repo = labels(&["bug", "Debug build", "enhancement", "bugfix"])
+            .into_iter()
+            .map(|label| label.0)
+            .collect::<Vec<_>>();
+        let applied = labels(&["bugfix"]);
+
+        let suggestions = suggest_labels(&repo, &applied, "BUG");
+
+        assert_eq!(names(&suggestions), ["bug", "Debug build"]);
+        assert!(suggest_labels(&repo, &applied, "  ").is_empty());
+    }
+
+    #[test]
+    fn create_color_starts_on_the_picker() {
+        let LabelEditMode::CreateColor { input, picker, .. } =
+            LabelEditMode::create_color("bug".to_string())
+        else {
+            panic!("expected color selection mode");
+        };
+
+        assert!(picker.is_focused());
+        assert!(!input.is_focused());
+        assert_eq!(input.text(), picker.selected_hex());
+    }
+
+    #[test]
+    fn edit_color_submits_off_palette_colors_unchanged() {
+        let label: Label = serde_json::from_value(serde_json::json!({
+            "id": 1,
+            "node_id": "L",
+            "url": "https://api.github.com/repos/o/r/labels/x",
+            "name": "bug",
+            "color": "123abc",
+            "default": false,
+        }))
+        .expect("valid label");
+        let LabelEditMode::EditColor { input, picker, .. } = LabelEditMode::edit_color(&label)
+        else {
+            panic!("expected recolor mode");
+        };
+
+        assert!(picker.is_focused());
+        assert_ne!(picker.selected_hex(), "123abc");
+        assert_eq!(LabelList::normalize_color(input.text()).unwrap(), "123abc");
+    }
+
+    #[test]
+    fn repo_label_cache_expires_after_ttl() {
+        let cache = RepoLabelCache {
+            labels: labels(&["bug"]).into_iter().map(|label| label.0).collect(),
+            fetched_at: Instant::now(),
+        };
+
+        assert!(cache.contains("bug"));
+        assert!(!cache.contains("Bug"));
+        assert!(!cache.is_stale(Duration::from_secs(60)));
+        assert!(cache.is_stale(Duration::ZERO));
+    }
+
+    #[test]
+    fn summarizes_batch_label_adds() {
+        let strings = |names: &[&str]| names.iter().map(|n| n.to_string()).collect::<Vec<_>>();
+        assert_eq!(
+            add_report_status(
+                &strings(&["bug", "ui"]),
+                &strings(&["docs (403 Forbidden)"]),
+                &strings(&["new"])
+            ),
+            "Added: bug, ui · Failed: docs (403 Forbidden) · Not found: new"
+        );
+        assert_eq!(
+            add_report_status(&strings(&["bug"]), &[], &[]),
+            "Added: bug"
+        );
+        assert_eq!(last_label("bug; area:u", ';'), " area:u");
+    }
+}
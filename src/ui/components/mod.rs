@@ -1,11 +1,13 @@
 use async_trait::async_trait;
 use rat_widget::focus::HasFocus;
-use ratatui::buffer::Buffer;
+use ratatui::{buffer::Buffer, text::Line};
 
 use crate::errors::AppError;
 use crate::ui::{Action, layout::Layout};
 use ratatui::crossterm::event::Event;
 
+pub mod command_palette;
+pub mod error_panel;
 pub mod help;
 pub mod issue_conversation;
 pub mod issue_create;
@@ -55,4 +57,10 @@ pub trait Component: HasFocus {
     fn set_index(&mut self, index: usize) {}
 
     fn set_global_help(&self) {}
+
+    /// Keys for what the component is doing right now, shown by `?` while it has focus. When
+    /// empty, the help last sent with [`Action::SetHelp`] is shown instead.
+    fn help_lines(&self) -> Vec<Line<'static>> {
+        Vec::new()
+    }
 }
@@ -10,45 +10,98 @@ use ratatui::{
     buffer::Buffer,
     layout::Rect,
     style::Style,
+    text::Line,
     widgets::{Block, BorderType, StatefulWidget, Widget},
 };
 use std::sync::Arc;
 use throbber_widgets_tui::ThrobberState;
 use tracing::instrument;
-use tracing::trace;
+use tracing::{error, trace};
 
 use crate::{
-    app::GITHUB_CLIENT,
+    app::client,
     errors::AppError,
+    export::write_issues_csv,
+    github::{with_retry, with_timeout},
+    storage::{SearchHistory, read_search_history},
     ui::{
         Action, AppState, MergeStrategy,
-        components::{Component, help::HelpElementKind, issue_list::MainScreen},
+        components::{
+            Component,
+            help::{HelpElementKind, keybind_lines},
+            issue_list::MainScreen,
+        },
+        keymap::{KeyAction, pressed},
         layout::Layout,
         utils::{get_border_style, get_loader_area},
     },
 };
 
 const OPTIONS: [&str; 3] = ["Open", "Closed", "All"];
+/// Sort fields offered by the search API, shown in the sort dropdown.
+const SORT_OPTIONS: [&str; 3] = ["Created", "Updated", "Comments"];
+/// Page size of searches when `per_page` isn't configured.
+const SEARCH_PER_PAGE: u8 = 10;
 pub const HELP: &[HelpElementKind] = &[
     crate::help_text!("Search Bar Help"),
     crate::help_keybind!("Type", "issue text in Search"),
     crate::help_keybind!(
         "Type",
-        "labels in Search Labels (separate multiple with ';')"
+        "labels in Search Labels (separate with --label-separator, ';' by default; quote names containing it)"
     ),
-    crate::help_keybind!("Tab / Shift+Tab", "move between inputs and status selector"),
+    crate::help_keybind!(
+        "Tab / Shift+Tab",
+        "move between inputs, status and sort selectors"
+    ),
+    crate::help_keybind!("Alt+O", "toggle ascending/descending sort order"),
+    crate::help_keybind!("Alt+P", "include or leave out pull requests"),
     crate::help_keybind!("Enter", "run search"),
+    crate::help_action!(CancelLoading, "cancel a running search"),
+    crate::help_keybind!("Up / Down", "recall recent searches while Search is empty"),
+    crate::help_keybind!("Alt+A", "show issues assigned to you"),
+    crate::help_keybind!("Alt+M", "show issues mentioning you"),
+    crate::help_keybind!("Alt+E", "export all results of the current search to CSV"),
 ];
 
+/// Canned personal triage searches, run through the regular query builder.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum QuickFilter {
+    AssignedToMe,
+    MentionsMe,
+}
+
+impl QuickFilter {
+    fn query(self) -> &'static str {
+        match self {
+            QuickFilter::AssignedToMe => "assignee:@me",
+            QuickFilter::MentionsMe => "mentions:@me",
+        }
+    }
+}
+
 pub struct TextSearch {
     pub search_state: rat_widget::text_input::TextInputState,
     pub label_state: rat_widget::text_input::TextInputState,
     cstate: ChoiceState,
+    sort_state: ChoiceState,
+    ascending: bool,
+    /// Whether results include pull requests, which are marked in the issue list.
+    include_prs: bool,
+    history: SearchHistory,
+    /// How far back in `history` the recalled search is, while the input still shows it.
+    history_index: Option<usize>,
     state: State,
+    /// The running search, aborted when it is cancelled or replaced by a newer one.
+    search_task: Option<tokio::task::JoinHandle<()>>,
+    error: Option<String>,
+    exporting: bool,
     action_tx: Option<tokio::sync::mpsc::Sender<Action>>,
     loader_state: ThrobberState,
     repo: String,
     owner: String,
+    current_user: String,
+    label_separator: char,
+    per_page: u8,
     screen: MainScreen,
     focus: FocusFlag,
     area: Rect,
@@ -63,15 +116,41 @@ enum State {
 }
 
 impl TextSearch {
-    pub fn new(AppState { repo, owner, .. }: AppState) -> Self {
+    pub fn new(
+        AppState {
+            repo,
+            owner,
+            current_user,
+            label_separator,
+            per_page,
+            default_query,
+            ..
+        }: AppState,
+    ) -> Self {
+        let history = read_search_history(&owner, &repo);
+        let mut search_state = rat_widget::text_input::TextInputState::default();
+        if let Some(query) = default_query {
+            search_state.set_text(query);
+        }
         Self {
             repo,
             owner,
-            search_state: Default::default(),
+            current_user,
+            label_separator,
+            per_page: per_page.unwrap_or(SEARCH_PER_PAGE),
+            search_state,
             label_state: Default::default(),
             loader_state: Default::default(),
             state: Default::default(),
+            search_task: None,
+            error: None,
+            exporting: false,
             cstate: Default::default(),
+            sort_state: Default::default(),
+            ascending: false,
+            include_prs: true,
+            history,
+            history_index: None,
             action_tx: None,
             screen: MainScreen::default(),
             focus: FocusFlag::new().with_name("search_bar"),
@@ -83,14 +162,26 @@ impl TextSearch {
     fn render_w(&mut self, layout: Layout, buf: &mut Buffer) {
         let total_area = layout
             .text_search
-            .union(layout.label_search.union(layout.status_dropdown));
+            .union(layout.label_search)
+            .union(layout.status_dropdown)
+            .union(layout.sort_dropdown);
         self.area = total_area;
         let contents = (1..).zip(OPTIONS).collect::<Vec<_>>();
         let text_input = rat_widget::text_input::TextInput::new().block(
             Block::bordered()
                 .border_type(ratatui::widgets::BorderType::Rounded)
                 .border_style(get_border_style(&self.search_state))
-                .title(format!("[{}] Search", self.index)),
+                .title({
+                    let kind = if self.include_prs {
+                        ""
+                    } else {
+                        " (issues only)"
+                    };
+                    match &self.error {
+                        Some(err) => format!("[{}] Search{kind} | {err}", self.index),
+                        None => format!("[{}] Search{kind}", self.index),
+                    }
+                }),
         );
         let label = rat_widget::text_input::TextInput::new().block(
             Block::bordered()
@@ -115,6 +206,28 @@ impl TextSearch {
         block.render(layout.status_dropdown, buf);
         popup.render(layout.status_dropdown, buf, &mut self.cstate);
         widget.render(binner, buf, &mut self.cstate);
+
+        let (sort_widget, sort_popup) = Choice::new()
+            .items((1..).zip(SORT_OPTIONS).collect::<Vec<_>>())
+            .popup_placement(Placement::Below)
+            .focus_style(Style::default())
+            .select_style(Style::default())
+            .button_style(Style::default())
+            .style(Style::default())
+            .select_marker('>')
+            .into_widgets();
+        let sort_block = Block::bordered()
+            .border_type(ratatui::widgets::BorderType::Rounded)
+            .border_style(get_border_style(&self.sort_state))
+            .title(if self.ascending {
+                "Sort ↑"
+            } else {
+                "Sort ↓"
+            });
+        let sort_inner = sort_block.inner(layout.sort_dropdown);
+        sort_block.render(layout.sort_dropdown, buf);
+        sort_popup.render(layout.sort_dropdown, buf, &mut self.sort_state);
+        sort_widget.render(sort_inner, buf, &mut self.sort_state);
         text_input.render(layout.text_search, buf, &mut self.search_state);
         label.render(layout.label_search, buf, &mut self.label_state);
         if self.state == State::Loading {
@@ -132,14 +245,11 @@ impl TextSearch {
         }
     }
 
-    #[instrument(skip(self, action_tx))]
-    async fn execute_search(&mut self, action_tx: tokio::sync::mpsc::Sender<Action>) {
-        let mut search = self.search_state.text().to_string();
-        let label = self.label_state.text();
-        if !label.is_empty() {
-            let label_q = label.split(';').map(|s| format!("label:{s}"));
+    fn build_query(&self) -> String {
+        let mut search = expand_me(self.search_state.text(), &self.current_user);
+        for label in split_labels(self.label_state.text(), self.label_separator) {
             search.push(' ');
-            search.push_str(&label_q.collect::<Vec<_>>().join(" "));
+            search.push_str(&label_qualifier(&label));
         }
         let status = self.cstate.selected();
         trace!(status, "Searching with status");
@@ -151,34 +261,183 @@ impl TextSearch {
         let repo_q = format!("repo:{}/{}", self.owner, self.repo);
         search.push(' ');
         search.push_str(&repo_q);
-        search.push_str(" is:issue");
-        trace!(search, "Searching with query");
+        if !self.include_prs {
+            search.push_str(" is:issue");
+        }
+        search
+    }
+
+    /// Steps through past searches, `older` moving back in time. Stepping past the newest entry
+    /// clears the input again.
+    fn recall_history(&mut self, older: bool) {
+        let next = match (self.history_index, older) {
+            (None, true) => Some(0),
+            (None, false) => return,
+            (Some(idx), true) if idx + 1 < self.history.len() => Some(idx + 1),
+            (Some(idx), true) => Some(idx),
+            (Some(0), false) => None,
+            (Some(idx), false) => Some(idx - 1),
+        };
+        match next.and_then(|idx| self.history.recall(idx)) {
+            Some(search) => self.search_state.set_text(search),
+            None => {
+                self.search_state.clear();
+            }
+        }
+        self.history_index = next;
+    }
+
+    /// Up/Down only recall history while the input is empty or still shows a recalled entry, so
+    /// they never clobber a query being typed.
+    fn can_recall_history(&self) -> bool {
+        self.search_state.is_focused()
+            && !self.history.is_empty()
+            && (self.search_state.text().is_empty() || self.history_index.is_some())
+    }
+
+    /// Search API `sort` and `order` parameters for the current selection. Nothing selected means
+    /// newest first, matching the search's previous fixed order.
+    fn sort_params(&self) -> (&'static str, &'static str) {
+        let sort = match self.sort_state.selected() {
+            Some(1) => "updated",
+            Some(2) => "comments",
+            _ => "created",
+        };
+        (sort, if self.ascending { "asc" } else { "desc" })
+    }
+
+    #[instrument(skip(self, action_tx))]
+    async fn execute_search(&mut self, action_tx: tokio::sync::mpsc::Sender<Action>) {
+        let search = self.build_query();
+        let (sort, order) = self.sort_params();
+        let per_page = self.per_page;
+        trace!(search, sort, order, "Searching with query");
         self.state = State::Loading;
+        self.error = None;
+        self.history_index = None;
+        if self.history.push(self.search_state.text())
+            && let Err(err) = self.history.write_to_file(&self.owner, &self.repo)
+        {
+            error!("Failed to save search history: {err}");
+        }
+        let terms = free_text_terms(self.search_state.text());
+        let _ = action_tx.send(Action::SearchTermsChanged { terms }).await;
+        if let Some(task) = self.search_task.take() {
+            task.abort();
+        }
+        self.search_task = Some(tokio::spawn(async move {
+            let Ok(client) = client() else {
+                let _ = action_tx
+                    .send(Action::SearchError {
+                        message: "GitHub client not initialized.".to_string(),
+                    })
+                    .await;
+                return;
+            };
+            let page = with_timeout(with_retry(|| {
+                client
+                    .search()
+                    .issues_and_pull_requests(&search)
+                    .page(1_u32)
+                    .per_page(per_page)
+                    .sort(sort)
+                    .order(order)
+                    .send()
+            }))
+            .await;
+            match page {
+                Ok(page) => {
+                    let _ = action_tx
+                        .send(Action::NewPage(Arc::new(page), MergeStrategy::Replace))
+                        .await;
+                    let _ = action_tx.send(Action::FinishedLoading).await;
+                }
+                Err(err) => {
+                    let _ = action_tx
+                        .send(Action::SearchError {
+                            message: err.to_string().replace('\n', " "),
+                        })
+                        .await;
+                    // Without results the list is unusable, so show the full error.
+                    let _ = action_tx
+                        .send(Action::ShowError {
+                            error: Arc::new(err),
+                            retry: Some(Box::new(Action::RefreshIssueList)),
+                        })
+                        .await;
+                }
+            }
+        }));
+    }
+
+    /// Walks every page of the current query and writes the results to a CSV file.
+    fn export_csv(&mut self, action_tx: tokio::sync::mpsc::Sender<Action>) {
+        if self.exporting {
+            return;
+        }
+        self.exporting = true;
+        let search = self.build_query();
+        let (sort, order) = self.sort_params();
+        let owner = self.owner.clone();
+        let repo = self.repo.clone();
         tokio::spawn(async move {
-            let client = GITHUB_CLIENT.get().ok_or_else(|| {
-                AppError::Other(anyhow::anyhow!("github client is not initialized"))
-            })?;
-            let page = client
-                .search()
-                .issues_and_pull_requests(&search)
-                .page(1_u32)
-                .per_page(10)
-                .sort("created")
-                .order("desc")
-                .send()
-                .await?;
-            action_tx
-                .send(Action::NewPage(Arc::new(page), MergeStrategy::Replace))
-                .await?;
-            action_tx.send(Action::FinishedLoading).await?;
-            Ok::<(), crate::errors::AppError>(())
+            let result = async {
+                let Ok(client) = client() else {
+                    return Err("GitHub client not initialized.".to_string());
+                };
+                let client = client.inner();
+                let first = client
+                    .search()
+                    .issues_and_pull_requests(&search)
+                    .per_page(100)
+                    .sort(sort)
+                    .order(order)
+                    .send()
+                    .await
+                    .map_err(|err| err.to_string().replace('\n', " "))?;
+                let issues = client
+                    .all_pages(first)
+                    .await
+                    .map_err(|err| err.to_string().replace('\n', " "))?;
+                let path = write_issues_csv(&owner, &repo, &issues)
+                    .map_err(|err| format!("Failed to write CSV export: {err}"))?;
+                Ok((path, issues.len()))
+            }
+            .await;
+            let _ = action_tx
+                .send(Action::SearchExportFinished { result })
+                .await;
         });
     }
 
+    /// Stops waiting for the running search, keeping the results that were shown before it.
+    async fn cancel_search(&mut self) {
+        if let Some(task) = self.search_task.take() {
+            task.abort();
+        }
+        self.state = State::Loaded;
+        self.error = Some("Search cancelled.".to_string());
+        if let Some(action_tx) = self.action_tx.as_ref() {
+            let _ = action_tx.send(Action::FinishedLoading).await;
+        }
+    }
+
+    async fn apply_quick_filter(
+        &mut self,
+        filter: QuickFilter,
+        action_tx: tokio::sync::mpsc::Sender<Action>,
+    ) {
+        self.search_state.set_text(filter.query());
+        self.execute_search(action_tx).await;
+    }
+
     ///NOTE: Its named this way to not conflict with the `has_focus`
     /// fn from the impl_has_focus! macro
     fn self_is_focused(&self) -> bool {
-        self.search_state.is_focused() || self.label_state.is_focused() || self.cstate.is_focused()
+        self.search_state.is_focused()
+            || self.label_state.is_focused()
+            || self.cstate.is_focused()
+            || self.sort_state.is_focused()
     }
 }
 
@@ -188,6 +447,7 @@ impl HasFocus for TextSearch {
         builder.widget(&self.search_state);
         builder.widget(&self.label_state);
         builder.widget(&self.cstate);
+        builder.widget(&self.sort_state);
         builder.end(tag);
     }
     fn focus(&self) -> FocusFlag {
@@ -227,6 +487,10 @@ impl Component for TextSearch {
                 {
                     return Ok(());
                 }
+                if self.state == State::Loading && pressed(KeyAction::CancelLoading, event) {
+                    self.cancel_search().await;
+                    return Ok(());
+                }
                 if self.self_is_focused() {
                     match event {
                         ct_event!(keycode press Enter) => {
@@ -235,16 +499,79 @@ impl Component for TextSearch {
                                 return Ok(());
                             }
                         }
+                        ct_event!(keycode press Up) if self.can_recall_history() => {
+                            self.recall_history(true);
+                            return Ok(());
+                        }
+                        ct_event!(keycode press Down) if self.can_recall_history() => {
+                            self.recall_history(false);
+                            return Ok(());
+                        }
+                        ct_event!(key press ALT-'a') => {
+                            if let Some(action_tx) = self.action_tx.clone() {
+                                self.apply_quick_filter(QuickFilter::AssignedToMe, action_tx)
+                                    .await;
+                                return Ok(());
+                            }
+                        }
+                        ct_event!(key press ALT-'e') => {
+                            if let Some(action_tx) = self.action_tx.clone() {
+                                self.export_csv(action_tx);
+                                return Ok(());
+                            }
+                        }
+                        ct_event!(key press ALT-'o') => {
+                            self.ascending = !self.ascending;
+                            if let Some(action_tx) = self.action_tx.clone() {
+                                self.execute_search(action_tx).await;
+                                return Ok(());
+                            }
+                        }
+                        ct_event!(key press ALT-'p') => {
+                            self.include_prs = !self.include_prs;
+                            if let Some(action_tx) = self.action_tx.clone() {
+                                self.execute_search(action_tx).await;
+                                return Ok(());
+                            }
+                        }
+                        ct_event!(key press ALT-'m') => {
+                            if let Some(action_tx) = self.action_tx.clone() {
+                                self.apply_quick_filter(QuickFilter::MentionsMe, action_tx)
+                                    .await;
+                                return Ok(());
+                            }
+                        }
                         _ => {}
                     }
                 }
                 self.label_state.handle(event, Regular);
                 self.search_state.handle(event, Regular);
+                if let Some(idx) = self.history_index
+                    && self.history.recall(idx) != Some(self.search_state.text())
+                {
+                    self.history_index = None;
+                }
                 self.cstate.handle(event, Popup);
+                self.sort_state.handle(event, Popup);
             }
             Action::FinishedLoading => {
                 self.state = State::Loaded;
             }
+            Action::SearchError { message } => {
+                self.state = State::Loaded;
+                self.error = Some(message);
+                if let Some(action_tx) = self.action_tx.as_ref() {
+                    let _ = action_tx.send(Action::FinishedLoading).await;
+                }
+            }
+            Action::ExportSearchCsv => {
+                if let Some(action_tx) = self.action_tx.clone() {
+                    self.export_csv(action_tx);
+                }
+            }
+            Action::SearchExportFinished { .. } => {
+                self.exporting = false;
+            }
             Action::Tick => {
                 if self.state == State::Loading {
                     self.loader_state.calc_next();
@@ -259,6 +586,7 @@ impl Component for TextSearch {
             .screen_cursor()
             .or(self.label_state.screen_cursor())
             .or(self.cstate.screen_cursor())
+            .or(self.sort_state.screen_cursor())
     }
 
     fn is_animating(&self) -> bool {
@@ -278,7 +606,7 @@ impl Component for TextSearch {
         self.self_is_focused()
             && !matches!(
                 event,
-                ct_event!(keycode press Tab) | ct_event!(keycode press BackTab)
+                ct_event!(keycode press Tab) | ct_event!(keycode press SHIFT-BackTab)
             )
     }
 
@@ -287,4 +615,141 @@ impl Component for TextSearch {
             let _ = action_tx.try_send(Action::SetHelp(HELP));
         }
     }
+
+    fn help_lines(&self) -> Vec<Line<'static>> {
+        keybind_lines(HELP)
+    }
+}
+
+/// Replaces `@me` in qualifiers such as `assignee:@me` with the authenticated user's login.
+fn expand_me(search: &str, current_user: &str) -> String {
+    if current_user.is_empty() {
+        return search.to_string();
+    }
+    search
+        .split(' ')
+        .map(|term| match term.strip_suffix(":@me") {
+            Some(qualifier) => format!("{qualifier}:{current_user}"),
+            None => term.to_string(),
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// The free-text words and quoted phrases of a search, skipping qualifiers such as `label:bug`,
+/// exclusions and boolean operators.
+fn free_text_terms(search: &str) -> Vec<String> {
+    let mut terms = Vec::new();
+    let mut rest = search.trim_start();
+    while !rest.is_empty() {
+        let (term, tail) = if let Some(quoted) = rest.strip_prefix('"') {
+            quoted.split_once('"').unwrap_or((quoted, ""))
+        } else {
+            let end = rest.find(char::is_whitespace).unwrap_or(rest.len());
+            let word = &rest[..end];
+            let rest_after = &rest[end..];
+            if word.contains(':') {
+                // Qualifier values may be quoted, e.g. `label:"help wanted"`.
+                let closing = if word.matches('"').count() % 2 == 1 {
+                    rest_after.find('"').map_or(rest_after.len(), |idx| idx + 1)
+                } else {
+                    0
+                };
+                rest = rest_after[closing..].trim_start();
+                continue;
+            }
+            let skip = word.starts_with('-') || matches!(word, "AND" | "OR" | "NOT");
+            (if skip { "" } else { word }, rest_after)
+        };
+        let term = term.trim();
+        if !term.is_empty() {
+            terms.push(term.to_string());
+        }
+        rest = tail.trim_start();
+    }
+    terms
+}
+
+/// Splits the label field on `separator`, keeping separators inside double quotes. Segments are
+/// trimmed, surrounding quotes are dropped and empty segments are skipped.
+pub(crate) fn split_labels(input: &str, separator: char) -> Vec<String> {
+    let mut labels = Vec::new();
+    let mut current = String::new();
+    let mut in_quotes = false;
+    for ch in input.chars() {
+        match ch {
+            '"' => {
+                in_quotes = !in_quotes;
+                current.push(ch);
+            }
+            ch if ch == separator && !in_quotes => labels.push(std::mem::take(&mut current)),
+            ch => current.push(ch),
+        }
+    }
+    labels.push(current);
+    labels
+        .into_iter()
+        .filter_map(|label| {
+            let label = label.trim();
+            let label = label
+                .strip_prefix('"')
+                .and_then(|l| l.strip_suffix('"'))
+                .unwrap_or(label)
+                .trim();
+            (!label.is_empty()).then(|| label.to_string())
+        })
+        .collect()
+}
+
+/// Builds a `label:` qualifier, quoting names that GitHub would otherwise split.
+fn label_qualifier(label: &str) -> String {
+    if label
+        .chars()
+        .any(|ch| ch.is_whitespace() || matches!(ch, ',' | ';' | ':' | '(' | ')'))
+    {
+        format!("label:\"{}\"", label.replace('"', ""))
+    } else {
+        format!("label:{label}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{free_text_terms, label_qualifier, split_labels};
+
+    #[test]
+    fn splits_labels_and_skips_empty_segments() {
+        assert_eq!(
+            split_labels(" bug ;; help wanted;", ';'),
+            vec!["bug".to_string(), "help wanted".to_string()]
+        );
+        assert_eq!(
+            split_labels("bug, docs", ','),
+            vec!["bug".to_string(), "docs".to_string()]
+        );
+        assert!(split_labels(" ; ", ';').is_empty());
+    }
+
+    #[test]
+    fn extracts_free_text_terms() {
+        assert_eq!(
+            free_text_terms(
+                "crash label:bug \"on save\" -wontfix is:open OR label:\"help wanted\" editor"
+            ),
+            vec![
+                "crash".to_string(),
+                "on save".to_string(),
+                "editor".to_string()
+            ]
+        );
+    }
+
+    #[test]
+    fn keeps_separator_inside_quoted_labels() {
+        let labels = split_labels("\"foo;bar\"; baz", ';');
+
+        assert_eq!(labels, vec!["foo;bar".to_string(), "baz".to_string()]);
+        assert_eq!(label_qualifier(&labels[0]), "label:\"foo;bar\"");
+        assert_eq!(label_qualifier(&labels[1]), "label:baz");
+    }
 }
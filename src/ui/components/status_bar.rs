@@ -1,17 +1,44 @@
+use async_trait::async_trait;
 use rat_widget::statusline_stacked::StatusLineStacked;
 use ratatui::buffer::Buffer;
 use ratatui::style::{Style, Stylize};
 use ratatui::widgets::Widget;
 use ratatui_macros::{line, span};
-use std::sync::atomic::Ordering;
+use std::sync::atomic::{AtomicU32, AtomicU64, Ordering};
+use std::time::{Duration, Instant};
+use tracing::debug;
 
-use crate::ui::components::issue_list::LOADED_ISSUE_COUNT;
+use crate::app::client;
+use crate::errors::AppError;
 use crate::ui::components::DumbComponent;
-use crate::ui::{layout::Layout, AppState};
+use crate::ui::components::issue_list::LOADED_ISSUE_COUNT;
+use crate::ui::utils::unix_now;
+use crate::ui::{Action, AppState, layout::Layout};
+
+/// Core API quota as of the last rate limit check. A limit of 0 means it hasn't been fetched.
+static RATE_LIMIT_REMAINING: AtomicU32 = AtomicU32::new(0);
+static RATE_LIMIT_TOTAL: AtomicU32 = AtomicU32::new(0);
+static RATE_LIMIT_RESET: AtomicU64 = AtomicU64::new(0);
+
+/// How often the rate limit is refetched. Checking it doesn't count against the quota.
+const RATE_LIMIT_REFRESH: Duration = Duration::from_secs(60);
+
+/// Time until the core quota resets, once the rate limit has been fetched. `None` when the last
+/// fetched reset has already passed, since the quota it described is gone.
+pub fn rate_limit_resets_in() -> Option<Duration> {
+    if RATE_LIMIT_TOTAL.load(Ordering::Relaxed) == 0 {
+        return None;
+    }
+    let secs = RATE_LIMIT_RESET.load(Ordering::Relaxed) as i64 - unix_now();
+    (secs > 0).then(|| Duration::from_secs(secs as u64))
+}
 
 pub struct StatusBar {
     repo_label: String,
     user_label: String,
+    message: Option<(String, bool)>,
+    action_tx: Option<tokio::sync::mpsc::Sender<Action>>,
+    last_rate_limit_fetch: Option<Instant>,
 }
 
 impl StatusBar {
@@ -19,9 +46,42 @@ impl StatusBar {
         Self {
             repo_label: format!(" {}/{} ", app_state.owner, app_state.repo),
             user_label: app_state.current_user,
+            message: None,
+            action_tx: None,
+            last_rate_limit_fetch: None,
         }
     }
 
+    /// Refetches the core rate limit once [`RATE_LIMIT_REFRESH`] has passed since the last try.
+    fn refresh_rate_limit(&mut self) {
+        if self
+            .last_rate_limit_fetch
+            .is_some_and(|last| last.elapsed() < RATE_LIMIT_REFRESH)
+        {
+            return;
+        }
+        let Some(action_tx) = self.action_tx.clone() else {
+            return;
+        };
+        self.last_rate_limit_fetch = Some(Instant::now());
+        tokio::spawn(async move {
+            let Ok(client) = client() else {
+                return;
+            };
+            match client.inner().ratelimit().get().await {
+                Ok(limits) => {
+                    let core = limits.resources.core;
+                    let clamp = |value: usize| value.min(u32::MAX as usize) as u32;
+                    RATE_LIMIT_REMAINING.store(clamp(core.remaining), Ordering::Relaxed);
+                    RATE_LIMIT_TOTAL.store(clamp(core.limit), Ordering::Relaxed);
+                    RATE_LIMIT_RESET.store(core.reset, Ordering::Relaxed);
+                    let _ = action_tx.send(Action::ForceRender).await;
+                }
+                Err(err) => debug!("Failed to fetch rate limit: {err}"),
+            }
+        });
+    }
+
     pub fn render(&mut self, area: Layout, buf: &mut Buffer) {
         let issue_count = LOADED_ISSUE_COUNT.load(Ordering::Relaxed);
         let count_text = format!(" Issues: {} ", issue_count);
@@ -37,7 +97,29 @@ impl StatusBar {
                 ],
                 " ",
             )
-            .start(span!(self.repo_label.as_str()).style(Style::new()), " ")
+            .start(span!(self.repo_label.as_str()).style(Style::new()), " ");
+        if let Some((message, is_error)) = &self.message {
+            let style = if *is_error {
+                Style::new().black().on_red()
+            } else {
+                Style::new().black().on_cyan()
+            };
+            ss = ss.start(span!(format!(" {message} ")).style(style), " ");
+        }
+        let limit = RATE_LIMIT_TOTAL.load(Ordering::Relaxed);
+        if limit > 0 {
+            let remaining = RATE_LIMIT_REMAINING.load(Ordering::Relaxed);
+            let reset = RATE_LIMIT_RESET.load(Ordering::Relaxed);
+            let text = rate_limit_text(remaining, limit, reset as i64, unix_now());
+            // Turns red once less than a tenth of the quota is left.
+            let style = if u64::from(remaining) * 10 < u64::from(limit) {
+                Style::new().black().on_red().bold()
+            } else {
+                Style::new().black().on_gray()
+            };
+            ss = ss.end(span!(text).style(style), "");
+        }
+        ss = ss
             .end(span!(count_text).style(Style::new().black().on_blue()), "")
             .end(
                 line![
@@ -81,8 +163,50 @@ impl StatusBar {
     }
 }
 
+#[async_trait(?Send)]
 impl DumbComponent for StatusBar {
     fn render(&mut self, area: Layout, buf: &mut Buffer) {
         self.render(area, buf);
     }
+
+    fn register_action_tx(&mut self, action_tx: tokio::sync::mpsc::Sender<Action>) {
+        self.action_tx = Some(action_tx);
+    }
+
+    async fn handle_event(&mut self, event: Action) -> Result<(), AppError> {
+        // Ticks stop while nothing animates, so any event may trigger the periodic refresh.
+        if !matches!(event, Action::ForceRender | Action::None | Action::Quit) {
+            self.refresh_rate_limit();
+        }
+        if let Action::SearchExportFinished { result } = event {
+            self.message = Some(match result {
+                Ok((path, count)) => (
+                    format!("Exported {count} issue(s) to {}", path.display()),
+                    false,
+                ),
+                Err(err) => (format!("CSV export failed: {err}"), true),
+            });
+        }
+        Ok(())
+    }
+}
+
+/// Formats the remaining quota with the time left until it resets, e.g. ` API 4200/5000 · 12m `.
+fn rate_limit_text(remaining: u32, limit: u32, reset: i64, now: i64) -> String {
+    let minutes = ((reset - now).max(0) + 59) / 60;
+    format!(" API {remaining}/{limit} · {minutes}m ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::rate_limit_text;
+
+    #[test]
+    fn formats_remaining_quota_and_minutes_to_reset() {
+        assert_eq!(
+            rate_limit_text(42, 5000, 1_000 + 61, 1_000),
+            " API 42/5000 · 2m "
+        );
+        assert_eq!(rate_limit_text(0, 60, 900, 1_000), " API 0/60 · 0m ");
+    }
 }
@@ -0,0 +1,86 @@
+use std::marker::PhantomData;
+
+pub mod filters;
+
+/// A predicate over `T` that can be combined with other filters.
+///
+/// The combinators consume their operands and return a new `Filter`, so small predicates can be
+/// built once and composed, e.g. `open.and(by_alice).and(has_bug.not())`.
+pub struct Filter<F, T> {
+    predicate: F,
+    _item: PhantomData<fn(&T)>,
+}
+
+impl<F, T> Filter<F, T>
+where
+    F: Fn(&T) -> bool,
+{
+    pub fn new(predicate: F) -> Self {
+        Self {
+            predicate,
+            _item: PhantomData,
+        }
+    }
+
+    pub fn matches(&self, item: &T) -> bool {
+        (self.predicate)(item)
+    }
+
+    /// Matches items accepted by both filters. `other` is only checked when `self` matches.
+    pub fn and<G>(self, other: Filter<G, T>) -> Filter<impl Fn(&T) -> bool, T>
+    where
+        G: Fn(&T) -> bool,
+    {
+        Filter::new(move |item: &T| self.matches(item) && other.matches(item))
+    }
+
+    /// Matches items accepted by either filter. `other` is only checked when `self` rejects.
+    pub fn or<G>(self, other: Filter<G, T>) -> Filter<impl Fn(&T) -> bool, T>
+    where
+        G: Fn(&T) -> bool,
+    {
+        Filter::new(move |item: &T| self.matches(item) || other.matches(item))
+    }
+
+    /// Matches exactly the items this filter rejects.
+    #[allow(clippy::should_implement_trait)]
+    pub fn not(self) -> Filter<impl Fn(&T) -> bool, T> {
+        Filter::new(move |item: &T| !self.matches(item))
+    }
+}
+
+pub trait FilterExt<T> {
+    /// Keeps only the items matched by `filter`, preserving their order.
+    fn filter<F>(self, filter: &Filter<F, T>) -> Self
+    where
+        F: Fn(&T) -> bool;
+}
+
+impl<T> FilterExt<T> for Vec<T> {
+    fn filter<F>(mut self, filter: &Filter<F, T>) -> Self
+    where
+        F: Fn(&T) -> bool,
+    {
+        self.retain(|item| filter.matches(item));
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Filter, FilterExt};
+
+    #[test]
+    fn composes_predicates() {
+        let even = Filter::new(|n: &i32| n % 2 == 0);
+        let small = Filter::new(|n: &i32| *n < 5);
+        let big = Filter::new(|n: &i32| *n > 8);
+
+        let filter = even.and(small.not()).or(big);
+
+        assert_eq!(
+            (1..=10).collect::<Vec<_>>().filter(&filter),
+            vec![6, 8, 9, 10]
+        );
+    }
+}
@@ -0,0 +1,138 @@
+//! Ready-made filters over issues, applied locally to issues that are already loaded.
+//!
+//! Each constructor returns a plain [`Filter`], so criteria are combined with the usual
+//! combinators: `by_state(IssueState::Open).and(by_author("alice")).and(by_label("bug").not())`
+//! keeps Alice's open issues that aren't labelled `bug`, and `has_no_assignee().or(...)` widens
+//! a filter instead of narrowing it.
+
+use octocrab::models::{IssueState, issues::Issue};
+
+use crate::ui::{
+    filter::Filter,
+    issue_data::{UiIssue, UiIssuePool},
+};
+
+/// The parts of an issue the predicates look at, so they work on raw API issues as well as on
+/// the issue list's pooled ones.
+pub trait IssueFields {
+    fn state(&self) -> &IssueState;
+    fn title(&self) -> &str;
+    fn author_login(&self) -> &str;
+    fn has_label(&self, name: &str) -> bool;
+    fn has_assignees(&self) -> bool;
+}
+
+impl IssueFields for Issue {
+    fn state(&self) -> &IssueState {
+        &self.state
+    }
+
+    fn title(&self) -> &str {
+        &self.title
+    }
+
+    fn author_login(&self) -> &str {
+        &self.user.login
+    }
+
+    fn has_label(&self, name: &str) -> bool {
+        self.labels
+            .iter()
+            .any(|label| label.name.eq_ignore_ascii_case(name))
+    }
+
+    fn has_assignees(&self) -> bool {
+        !self.assignees.is_empty()
+    }
+}
+
+/// An issue from the list's pool, paired with the pool that resolves its strings.
+pub struct PooledIssue<'a> {
+    pub issue: &'a UiIssue,
+    pub pool: &'a UiIssuePool,
+}
+
+impl IssueFields for PooledIssue<'_> {
+    fn state(&self) -> &IssueState {
+        &self.issue.state
+    }
+
+    fn title(&self) -> &str {
+        self.pool.resolve_str(self.issue.title)
+    }
+
+    fn author_login(&self) -> &str {
+        self.pool.author_login(self.issue.author)
+    }
+
+    fn has_label(&self, name: &str) -> bool {
+        self.issue
+            .labels
+            .iter()
+            .any(|label| label.name.eq_ignore_ascii_case(name))
+    }
+
+    fn has_assignees(&self) -> bool {
+        !self.issue.assignees.is_empty()
+    }
+}
+
+pub fn by_state<T: IssueFields>(state: IssueState) -> Filter<impl Fn(&T) -> bool, T> {
+    Filter::new(move |issue: &T| *issue.state() == state)
+}
+
+/// Matches issues carrying the label `name`, ignoring case like GitHub does.
+pub fn by_label<T: IssueFields>(name: impl Into<String>) -> Filter<impl Fn(&T) -> bool, T> {
+    let name = name.into();
+    Filter::new(move |issue: &T| issue.has_label(&name))
+}
+
+/// Matches issues opened by `login`, ignoring case.
+pub fn by_author<T: IssueFields>(login: impl Into<String>) -> Filter<impl Fn(&T) -> bool, T> {
+    let login = login.into();
+    Filter::new(move |issue: &T| issue.author_login().eq_ignore_ascii_case(&login))
+}
+
+/// Matches issues whose title contains `text`, ignoring case.
+pub fn by_title<T: IssueFields>(text: impl Into<String>) -> Filter<impl Fn(&T) -> bool, T> {
+    let text = text.into().to_lowercase();
+    Filter::new(move |issue: &T| issue.title().to_lowercase().contains(&text))
+}
+
+pub fn has_no_assignee<T: IssueFields>() -> Filter<impl Fn(&T) -> bool, T> {
+    Filter::new(|issue: &T| !issue.has_assignees())
+}
+
+#[cfg(test)]
+mod tests {
+    use octocrab::models::IssueState;
+    use serde_json::json;
+
+    use super::{by_author, by_label, by_state, has_no_assignee};
+    use crate::{github::mock::fixture_issue, ui::filter::FilterExt};
+
+    #[test]
+    fn combines_issue_predicates() {
+        let issues = vec![
+            fixture_issue(json!({ "user": "alice", "labels": ["bug"] }), 1),
+            fixture_issue(json!({ "user": "alice", "labels": ["docs"] }), 2),
+            fixture_issue(json!({ "user": "Alice", "state": "closed" }), 3),
+            fixture_issue(json!({ "user": "bob", "assignees": ["alice"] }), 4),
+        ];
+
+        let numbers = |filtered: Vec<octocrab::models::issues::Issue>| {
+            filtered
+                .iter()
+                .map(|issue| issue.number)
+                .collect::<Vec<_>>()
+        };
+        let alice_open_not_bug = by_state(IssueState::Open)
+            .and(by_author("alice"))
+            .and(by_label("BUG").not());
+        assert_eq!(numbers(issues.clone().filter(&alice_open_not_bug)), vec![2]);
+        assert_eq!(
+            numbers(issues.filter(&has_no_assignee().and(by_author("bob").not()))),
+            vec![1, 2, 3]
+        );
+    }
+}
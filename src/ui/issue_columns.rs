@@ -0,0 +1,348 @@
+use octocrab::models::IssueState;
+use ratatui::{style::Style, text::Span};
+use textwrap::core::display_width;
+
+use crate::ui::{
+    issue_data::{UiIssue, UiIssuePool},
+    utils::label_color,
+};
+
+const MIN_TITLE_WIDTH: usize = 10;
+/// Shown before the title of pull requests.
+const PR_MARKER: &str = "PR ";
+/// Drawn once per label in the label dot column, in the label's color.
+const LABEL_DOT: &str = "●";
+
+/// A field shown in the summary line of each issue list row.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IssueColumn {
+    Number,
+    Title,
+    Author,
+    State,
+    Labels,
+    /// One colored dot per label.
+    Dots,
+    Comments,
+    Updated,
+}
+
+pub const DEFAULT_ISSUE_COLUMNS: &[IssueColumn] = &[
+    IssueColumn::Number,
+    IssueColumn::Title,
+    IssueColumn::State,
+    IssueColumn::Dots,
+    IssueColumn::Comments,
+];
+
+impl IssueColumn {
+    pub const ALL: &[IssueColumn] = &[
+        IssueColumn::Number,
+        IssueColumn::Title,
+        IssueColumn::Author,
+        IssueColumn::State,
+        IssueColumn::Labels,
+        IssueColumn::Dots,
+        IssueColumn::Comments,
+        IssueColumn::Updated,
+    ];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            IssueColumn::Number => "number",
+            IssueColumn::Title => "title",
+            IssueColumn::Author => "author",
+            IssueColumn::State => "state",
+            IssueColumn::Labels => "labels",
+            IssueColumn::Dots => "dots",
+            IssueColumn::Comments => "comments",
+            IssueColumn::Updated => "updated",
+        }
+    }
+
+    fn from_name(name: &str) -> Option<Self> {
+        Self::ALL
+            .iter()
+            .copied()
+            .find(|column| column.name().eq_ignore_ascii_case(name))
+    }
+
+    /// Fixed display width of the column. The title is the only flexible column and takes
+    /// whatever space is left.
+    fn width(self) -> Option<usize> {
+        match self {
+            IssueColumn::Number => Some(7),
+            IssueColumn::Title => None,
+            IssueColumn::Author => Some(16),
+            IssueColumn::State => Some(6),
+            IssueColumn::Labels => Some(20),
+            IssueColumn::Dots => Some(6),
+            IssueColumn::Comments => Some(5),
+            IssueColumn::Updated => Some(16),
+        }
+    }
+
+    fn cell(self, issue: &UiIssue, pool: &UiIssuePool) -> (String, Style) {
+        match self {
+            IssueColumn::Number => (format!("#{}", issue.number), Style::new().dim()),
+            IssueColumn::Title => (
+                pool.resolve_str(issue.title).to_string(),
+                if issue.is_pull_request {
+                    Style::new().blue()
+                } else {
+                    Style::new()
+                },
+            ),
+            IssueColumn::Author => (
+                pool.author_login(issue.author).to_string(),
+                Style::new().cyan(),
+            ),
+            IssueColumn::State => match issue.state {
+                IssueState::Open => ("open".to_string(), Style::new().green()),
+                _ => ("closed".to_string(), Style::new().magenta()),
+            },
+            IssueColumn::Labels => (
+                issue
+                    .labels
+                    .iter()
+                    .map(|label| label.name.as_str())
+                    .collect::<Vec<_>>()
+                    .join(", "),
+                Style::new().yellow(),
+            ),
+            // Drawn span by span in `build_column_spans`.
+            IssueColumn::Dots => (String::new(), Style::new()),
+            IssueColumn::Comments => (format!("{:>4}", issue.comments), Style::new().dim()),
+            IssueColumn::Updated => (
+                pool.resolve_str(issue.updated_at_short).to_string(),
+                Style::new().dim(),
+            ),
+        }
+    }
+}
+
+/// Parses a comma separated column list such as `number,title,author`.
+///
+/// Unknown and repeated names are skipped and returned alongside the parsed columns so the
+/// caller can report them. An empty result falls back to [`DEFAULT_ISSUE_COLUMNS`].
+pub fn parse_issue_columns(spec: &str) -> (Vec<IssueColumn>, Vec<String>) {
+    let mut columns = Vec::new();
+    let mut invalid = Vec::new();
+    for name in spec
+        .split(',')
+        .map(str::trim)
+        .filter(|name| !name.is_empty())
+    {
+        match IssueColumn::from_name(name) {
+            Some(column) if !columns.contains(&column) => columns.push(column),
+            _ => invalid.push(name.to_string()),
+        }
+    }
+    if columns.is_empty() {
+        columns = DEFAULT_ISSUE_COLUMNS.to_vec();
+    }
+    (columns, invalid)
+}
+
+/// Lays the columns out within `width` cells, one space apart. Occurrences of `highlight` terms
+/// in the title are emphasized.
+pub fn build_column_spans(
+    columns: &[IssueColumn],
+    issue: &UiIssue,
+    pool: &UiIssuePool,
+    width: usize,
+    highlight: &[String],
+) -> Vec<Span<'static>> {
+    let gaps = columns.len().saturating_sub(1);
+    let fixed: usize = columns.iter().filter_map(|column| column.width()).sum();
+    let title_width = width.saturating_sub(fixed + gaps).max(MIN_TITLE_WIDTH);
+
+    let mut spans = Vec::with_capacity(columns.len() * 2);
+    for (idx, column) in columns.iter().enumerate() {
+        if idx > 0 {
+            spans.push(Span::raw(" "));
+        }
+        if *column == IssueColumn::Dots {
+            spans.extend(label_dot_spans(issue, column.width().unwrap_or_default()));
+            continue;
+        }
+        let (text, style) = column.cell(issue, pool);
+        let mut cell_width = column.width().unwrap_or(title_width);
+        if *column == IssueColumn::Title && issue.is_pull_request {
+            spans.push(Span::styled(PR_MARKER, Style::new().blue().bold()));
+            cell_width = cell_width.saturating_sub(PR_MARKER.len());
+        }
+        let text = fit(&text, cell_width);
+        if *column == IssueColumn::Title && !highlight.is_empty() {
+            spans.extend(highlight_spans(&text, highlight, style));
+        } else {
+            spans.push(Span::styled(text, style));
+        }
+    }
+    spans
+}
+
+/// A dot per label in its adapted color, padded to `width` cells. When there are more labels
+/// than fit, the last cell shows `+` instead.
+fn label_dot_spans(issue: &UiIssue, width: usize) -> Vec<Span<'static>> {
+    let labels = &issue.labels;
+    let shown = if labels.len() > width {
+        width.saturating_sub(1)
+    } else {
+        labels.len()
+    };
+    let mut spans = labels[..shown]
+        .iter()
+        .map(|label| Span::styled(LABEL_DOT, Style::new().fg(label_color(&label.color))))
+        .collect::<Vec<_>>();
+    if shown < labels.len() {
+        spans.push(Span::styled("+", Style::new().dim()));
+    }
+    let used = spans.len();
+    if used < width {
+        spans.push(Span::raw(" ".repeat(width - used)));
+    }
+    spans
+}
+
+/// Splits `text` into spans, styling case-insensitive matches of any of `terms` as bold yellow.
+pub fn highlight_spans(text: &str, terms: &[String], style: Style) -> Vec<Span<'static>> {
+    let mut marked = vec![false; text.len()];
+    for (start, _) in text.char_indices() {
+        for term in terms {
+            if let Some(len) = match_len_ignore_case(&text[start..], term) {
+                marked[start..start + len].fill(true);
+            }
+        }
+    }
+
+    let highlight_style = style.yellow().bold();
+    let mut spans = Vec::new();
+    let mut run_start = 0;
+    for (idx, _) in text.char_indices().skip(1) {
+        if marked[idx] != marked[run_start] {
+            let run_style = if marked[run_start] {
+                highlight_style
+            } else {
+                style
+            };
+            spans.push(Span::styled(text[run_start..idx].to_string(), run_style));
+            run_start = idx;
+        }
+    }
+    if run_start < text.len() {
+        let run_style = if marked[run_start] {
+            highlight_style
+        } else {
+            style
+        };
+        spans.push(Span::styled(text[run_start..].to_string(), run_style));
+    }
+    spans
+}
+
+/// Byte length of the prefix of `text` matching `term` case-insensitively, if any.
+fn match_len_ignore_case(text: &str, term: &str) -> Option<usize> {
+    if term.is_empty() {
+        return None;
+    }
+    let mut text_chars = text.char_indices();
+    for term_char in term.chars() {
+        let (_, text_char) = text_chars.next()?;
+        if !text_char.to_lowercase().eq(term_char.to_lowercase()) {
+            return None;
+        }
+    }
+    Some(text_chars.next().map_or(text.len(), |(idx, _)| idx))
+}
+
+/// Truncates `text` with an ellipsis or pads it with spaces so it is exactly `width` cells wide.
+fn fit(text: &str, width: usize) -> String {
+    let text_width = display_width(text);
+    if text_width <= width {
+        return format!("{text}{}", " ".repeat(width - text_width));
+    }
+    let mut out = String::new();
+    let mut used = 0;
+    let mut buf = [0u8; 4];
+    for ch in text.chars() {
+        let ch_width = display_width(ch.encode_utf8(&mut buf));
+        if used + ch_width + 1 > width {
+            break;
+        }
+        out.push(ch);
+        used += ch_width;
+    }
+    out.push('…');
+    used += 1;
+    out.push_str(&" ".repeat(width.saturating_sub(used)));
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use ratatui::style::Style;
+    use serde_json::json;
+
+    use super::{
+        DEFAULT_ISSUE_COLUMNS, IssueColumn, fit, highlight_spans, label_dot_spans,
+        parse_issue_columns,
+    };
+    use crate::{
+        github::mock::fixture_issue,
+        ui::issue_data::{UiIssue, UiIssuePool},
+    };
+
+    #[test]
+    fn parses_columns_in_order_and_reports_unknown_names() {
+        let (columns, invalid) = parse_issue_columns("state, Number,title,bogus,number");
+
+        assert_eq!(
+            columns,
+            vec![IssueColumn::State, IssueColumn::Number, IssueColumn::Title]
+        );
+        assert_eq!(invalid, vec!["bogus".to_string(), "number".to_string()]);
+    }
+
+    #[test]
+    fn falls_back_to_default_columns() {
+        let (columns, invalid) = parse_issue_columns("nope");
+
+        assert_eq!(columns, DEFAULT_ISSUE_COLUMNS);
+        assert_eq!(invalid, vec!["nope".to_string()]);
+    }
+
+    #[test]
+    fn highlights_terms_case_insensitively() {
+        let spans = highlight_spans("Crash on Save", &["save".to_string()], Style::new());
+        let text = spans
+            .iter()
+            .map(|span| (span.content.as_ref(), span.style == Style::new()))
+            .collect::<Vec<_>>();
+
+        assert_eq!(text, vec![("Crash on ", true), ("Save", false)]);
+    }
+
+    #[test]
+    fn fits_text_to_width() {
+        assert_eq!(fit("abc", 5), "abc  ");
+        assert_eq!(fit("abcdef", 4), "abc…");
+    }
+
+    #[test]
+    fn draws_a_dot_per_label_within_the_column() {
+        let mut pool = UiIssuePool::default();
+        let mut text = |labels: serde_json::Value| {
+            let issue = fixture_issue(json!({ "labels": labels }), 1);
+            let issue = UiIssue::from_octocrab(&issue, &mut pool);
+            label_dot_spans(&issue, 4)
+                .iter()
+                .map(|span| span.content.to_string())
+                .collect::<String>()
+        };
+
+        assert_eq!(text(json!(["bug"])), "●   ");
+        assert_eq!(text(json!(["a", "b", "c", "d"])), "●●●●");
+        assert_eq!(text(json!(["a", "b", "c", "d", "e"])), "●●●+");
+    }
+}
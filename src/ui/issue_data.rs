@@ -25,12 +25,15 @@ pub struct UiIssue {
     pub created_at_short: StrId,
     pub created_at_full: StrId,
     pub updated_at_short: StrId,
+    pub updated_ts: i64,
     pub comments: u32,
     pub assignees: Vec<AuthorId>,
     pub milestone: Option<StrId>,
     pub is_pull_request: bool,
     pub pull_request_url: Option<StrId>,
     pub labels: Vec<Label>,
+    pub locked: bool,
+    pub html_url: StrId,
 }
 
 impl UiIssue {
@@ -48,6 +51,7 @@ impl UiIssue {
             created_at_short: pool.intern_str(created_at_short.as_str()),
             created_at_full: pool.intern_str(created_at_full.as_str()),
             updated_at_short: pool.intern_str(updated_at_short.as_str()),
+            updated_ts: issue.updated_at.timestamp(),
             comments: issue.comments,
             assignees: issue
                 .assignees
@@ -64,6 +68,8 @@ impl UiIssue {
                 .as_ref()
                 .map(|pr| pool.intern_str(pr.html_url.as_str())),
             labels: issue.labels.clone(),
+            locked: issue.locked,
+            html_url: pool.intern_str(issue.html_url.as_str()),
         }
     }
 }
@@ -305,6 +311,10 @@ impl UiIssuePool {
         issue_id
     }
 
+    pub fn issue_id_by_number(&self, number: u64) -> Option<IssueId> {
+        self.issue_by_number.get(&number).copied()
+    }
+
     pub fn get_issue(&self, issue_id: IssueId) -> &UiIssue {
         self.issues
             .get(issue_id)
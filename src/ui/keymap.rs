@@ -0,0 +1,362 @@
+use std::{borrow::Cow, collections::HashMap, str::FromStr, sync::OnceLock};
+
+use anyhow::anyhow;
+use crossterm::event::{Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers};
+
+use crate::errors::AppError;
+
+static KEYMAP: OnceLock<Keymap> = OnceLock::new();
+
+/// An action whose key can be changed in the `[keybindings]` section of the config file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum KeyAction {
+    SendComment,
+    EditComment,
+    EditIssue,
+    ExternalEditComment,
+    DeleteComment,
+    QuoteComment,
+    AddReaction,
+    RemoveReaction,
+    CopyMessage,
+    OpenInBrowser,
+    ToggleIssueState,
+    EditAssignees,
+    SetMilestone,
+    ReloadComments,
+    CancelLoading,
+    ToggleDetails,
+    TogglePreview,
+    AddLabel,
+    RemoveLabel,
+    UndoRemoveLabel,
+    RecolorLabel,
+    FindLabel,
+    ReloadLabels,
+    CycleLabelSort,
+}
+
+impl KeyAction {
+    /// Config name and default keys of every action.
+    const ALL: &[(KeyAction, &str, &str)] = &[
+        (
+            KeyAction::SendComment,
+            "send_comment",
+            "ctrl+enter, alt+enter",
+        ),
+        (KeyAction::EditComment, "edit_comment", "e"),
+        (KeyAction::EditIssue, "edit_issue", "E"),
+        (
+            KeyAction::ExternalEditComment,
+            "external_edit_comment",
+            "alt+e",
+        ),
+        (KeyAction::DeleteComment, "delete_comment", "d"),
+        (KeyAction::QuoteComment, "quote_comment", ">"),
+        (KeyAction::AddReaction, "add_reaction", "r"),
+        (KeyAction::RemoveReaction, "remove_reaction", "R"),
+        (KeyAction::CopyMessage, "copy_message", "y"),
+        (KeyAction::OpenInBrowser, "open_in_browser", "o"),
+        (KeyAction::ToggleIssueState, "toggle_issue_state", "x"),
+        (KeyAction::EditAssignees, "edit_assignees", "a"),
+        (KeyAction::SetMilestone, "set_milestone", "m"),
+        (KeyAction::ReloadComments, "reload_comments", "ctrl+r"),
+        (KeyAction::CancelLoading, "cancel_loading", "ctrl+g"),
+        (KeyAction::ToggleDetails, "toggle_details", "z"),
+        (KeyAction::TogglePreview, "toggle_preview", "ctrl+p"),
+        (KeyAction::AddLabel, "add_label", "a"),
+        (KeyAction::RemoveLabel, "remove_label", "d"),
+        (KeyAction::UndoRemoveLabel, "undo_remove_label", "u"),
+        (KeyAction::RecolorLabel, "recolor_label", "c"),
+        (KeyAction::FindLabel, "find_label", "f"),
+        (KeyAction::ReloadLabels, "reload_labels", "R"),
+        (KeyAction::CycleLabelSort, "cycle_label_sort", "s"),
+    ];
+
+    fn from_name(name: &str) -> Option<Self> {
+        Self::ALL
+            .iter()
+            .find(|(_, action_name, _)| *action_name == name)
+            .map(|(action, _, _)| *action)
+    }
+}
+
+/// A key with its modifiers, written like `d`, `R`, `ctrl+enter` or `alt+shift+f2`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct KeyBinding {
+    code: KeyCode,
+    modifiers: KeyModifiers,
+}
+
+impl FromStr for KeyBinding {
+    type Err = AppError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        // `+` on its own (or as the last part, like `ctrl++`) is the plus key.
+        let (modifier_part, key) = match s.strip_suffix("++") {
+            Some(rest) => (rest, "+"),
+            None if s == "+" => ("", "+"),
+            None => s.rsplit_once('+').unwrap_or(("", s)),
+        };
+        let mut modifiers = KeyModifiers::NONE;
+        for modifier in modifier_part.split('+').filter(|m| !m.is_empty()) {
+            modifiers |= match modifier.to_ascii_lowercase().as_str() {
+                "ctrl" | "control" => KeyModifiers::CONTROL,
+                "alt" => KeyModifiers::ALT,
+                "shift" => KeyModifiers::SHIFT,
+                _ => return Err(anyhow!("unknown modifier `{modifier}` in key `{s}`").into()),
+            };
+        }
+        let mut chars = key.chars();
+        let code = match (chars.next(), chars.next()) {
+            (Some(c), None) => KeyCode::Char(c),
+            _ => match key.to_ascii_lowercase().as_str() {
+                "enter" => KeyCode::Enter,
+                "esc" => KeyCode::Esc,
+                "tab" => KeyCode::Tab,
+                "space" => KeyCode::Char(' '),
+                "backspace" => KeyCode::Backspace,
+                "delete" => KeyCode::Delete,
+                "up" => KeyCode::Up,
+                "down" => KeyCode::Down,
+                "left" => KeyCode::Left,
+                "right" => KeyCode::Right,
+                "home" => KeyCode::Home,
+                "end" => KeyCode::End,
+                "pageup" => KeyCode::PageUp,
+                "pagedown" => KeyCode::PageDown,
+                other => match other.strip_prefix('f').and_then(|n| n.parse::<u8>().ok()) {
+                    Some(n @ 1..=12) => KeyCode::F(n),
+                    _ => return Err(anyhow!("unknown key `{key}` in `{s}`").into()),
+                },
+            },
+        };
+        Ok(Self { code, modifiers })
+    }
+}
+
+impl std::fmt::Display for KeyBinding {
+    /// Writes the key the way the help lists it, e.g. `Ctrl+R`, `Alt+Enter` or `E`.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (modifier, name) in [
+            (KeyModifiers::CONTROL, "Ctrl+"),
+            (KeyModifiers::ALT, "Alt+"),
+            (KeyModifiers::SHIFT, "Shift+"),
+        ] {
+            if self.modifiers.contains(modifier) {
+                f.write_str(name)?;
+            }
+        }
+        match self.code {
+            KeyCode::Char(' ') => f.write_str("Space"),
+            KeyCode::Char(c) if self.modifiers.is_empty() => write!(f, "{c}"),
+            KeyCode::Char(c) => write!(f, "{}", c.to_ascii_uppercase()),
+            KeyCode::PageUp => f.write_str("PageUp"),
+            KeyCode::PageDown => f.write_str("PageDown"),
+            code => write!(f, "{code}"),
+        }
+    }
+}
+
+impl KeyBinding {
+    /// The key press this binding stands for, e.g. to replay it.
+    pub fn key_event(&self) -> KeyEvent {
+        KeyEvent::new(self.code, self.modifiers)
+    }
+
+    pub fn matches(&self, key: &KeyEvent) -> bool {
+        if key.kind != KeyEventKind::Press || key.code != self.code {
+            return false;
+        }
+        // Terminals disagree on whether an upper-case letter also reports Shift, so the
+        // character alone decides.
+        let ignored = match self.code {
+            KeyCode::Char(_) => KeyModifiers::SHIFT,
+            _ => KeyModifiers::NONE,
+        };
+        key.modifiers - ignored == self.modifiers - ignored
+    }
+}
+
+/// Keys bound to each [`KeyAction`]. Actions that aren't configured keep their defaults.
+#[derive(Debug, Clone)]
+pub struct Keymap {
+    bindings: HashMap<KeyAction, Vec<KeyBinding>>,
+    /// Whether `j`/`k`/`g`/`G` move through lists.
+    vim_keys: bool,
+}
+
+impl Default for Keymap {
+    fn default() -> Self {
+        let bindings = KeyAction::ALL
+            .iter()
+            .map(|(action, _, keys)| {
+                let keys = parse_keys(keys).expect("default key bindings are valid");
+                (*action, keys)
+            })
+            .collect();
+        Self {
+            bindings,
+            vim_keys: true,
+        }
+    }
+}
+
+impl Keymap {
+    /// Overrides the defaults with `bindings` from the config file, mapping action names to one
+    /// or more comma separated keys.
+    pub fn from_bindings(bindings: &HashMap<String, String>) -> Result<Self, AppError> {
+        let mut keymap = Self::default();
+        for (name, keys) in bindings {
+            let action = KeyAction::from_name(name)
+                .ok_or_else(|| anyhow!("unknown key binding action `{name}`"))?;
+            keymap.bindings.insert(action, parse_keys(keys)?);
+        }
+        Ok(keymap)
+    }
+
+    pub fn with_vim_keys(mut self, enabled: bool) -> Self {
+        self.vim_keys = enabled;
+        self
+    }
+
+    pub fn keys(&self, action: KeyAction) -> &[KeyBinding] {
+        self.bindings.get(&action).map_or(&[], Vec::as_slice)
+    }
+
+    /// The keys bound to `action` as the help shows them, e.g. `Ctrl+Enter / Alt+Enter`.
+    pub fn describe(&self, action: KeyAction) -> String {
+        self.keys(action)
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>()
+            .join(" / ")
+    }
+
+    pub fn matches(&self, action: KeyAction, key: &KeyEvent) -> bool {
+        self.bindings
+            .get(&action)
+            .is_some_and(|keys| keys.iter().any(|binding| binding.matches(key)))
+    }
+}
+
+fn parse_keys(keys: &str) -> Result<Vec<KeyBinding>, AppError> {
+    keys.split(',')
+        .filter(|key| !key.trim().is_empty())
+        .map(KeyBinding::from_str)
+        .collect()
+}
+
+/// Installs the keymap from the config file. Only the first call has an effect.
+pub fn set_keymap(keymap: Keymap) {
+    let _ = KEYMAP.set(keymap);
+}
+
+pub fn keymap() -> &'static Keymap {
+    KEYMAP.get_or_init(Keymap::default)
+}
+
+/// Whether `event` is a key press bound to `action`.
+pub fn pressed(action: KeyAction, event: &Event) -> bool {
+    match event {
+        Event::Key(key) => keymap().matches(action, key),
+        _ => false,
+    }
+}
+
+/// Translates `j`/`k`/`g`/`G` into the keys a list moves by (down, up, first and last row) when
+/// vim keys are enabled, leaving every other event as it is.
+///
+/// Lists only react to keys while they are focused, so the letters still reach a focused text
+/// input untouched.
+pub fn list_motion(event: &Event) -> Cow<'_, Event> {
+    let Event::Key(key) = event else {
+        return Cow::Borrowed(event);
+    };
+    if !keymap().vim_keys
+        || key.kind != KeyEventKind::Press
+        || !(key.modifiers - KeyModifiers::SHIFT).is_empty()
+    {
+        return Cow::Borrowed(event);
+    }
+    let (code, modifiers) = match key.code {
+        KeyCode::Char('j') => (KeyCode::Down, KeyModifiers::NONE),
+        KeyCode::Char('k') => (KeyCode::Up, KeyModifiers::NONE),
+        KeyCode::Char('g') => (KeyCode::Up, KeyModifiers::CONTROL),
+        KeyCode::Char('G') => (KeyCode::Down, KeyModifiers::CONTROL),
+        _ => return Cow::Borrowed(event),
+    };
+    Cow::Owned(Event::Key(KeyEvent::new(code, modifiers)))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use crossterm::event::{Event, KeyCode, KeyEvent, KeyModifiers};
+
+    use super::{KeyAction, KeyBinding, Keymap, list_motion};
+
+    #[test]
+    fn parses_keys_with_modifiers() {
+        let binding: KeyBinding = "Ctrl+Enter".parse().unwrap();
+        assert!(binding.matches(&KeyEvent::new(KeyCode::Enter, KeyModifiers::CONTROL)));
+        assert!(!binding.matches(&KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE)));
+
+        let binding: KeyBinding = "R".parse().unwrap();
+        assert!(binding.matches(&KeyEvent::new(KeyCode::Char('R'), KeyModifiers::SHIFT)));
+        assert!("hyper+x".parse::<KeyBinding>().is_err());
+        assert!("f13".parse::<KeyBinding>().is_err());
+    }
+
+    #[test]
+    fn configured_keys_replace_defaults() {
+        let bindings = HashMap::from([("add_label".to_string(), "+, ctrl+a".to_string())]);
+        let keymap = Keymap::from_bindings(&bindings).unwrap();
+
+        let plus = KeyEvent::new(KeyCode::Char('+'), KeyModifiers::NONE);
+        let a = KeyEvent::new(KeyCode::Char('a'), KeyModifiers::NONE);
+        assert!(keymap.matches(KeyAction::AddLabel, &plus));
+        assert!(!keymap.matches(KeyAction::AddLabel, &a));
+        assert!(keymap.matches(
+            KeyAction::RemoveLabel,
+            &KeyEvent::new(KeyCode::Char('d'), KeyModifiers::NONE)
+        ));
+
+        let unknown = HashMap::from([("launch_rockets".to_string(), "l".to_string())]);
+        assert!(Keymap::from_bindings(&unknown).is_err());
+    }
+
+    #[test]
+    fn describes_keys_like_the_help() {
+        let keymap = Keymap::default();
+        assert_eq!(
+            keymap.describe(KeyAction::SendComment),
+            "Ctrl+Enter / Alt+Enter"
+        );
+        assert_eq!(keymap.describe(KeyAction::EditIssue), "E");
+        assert_eq!(keymap.describe(KeyAction::ReloadComments), "Ctrl+R");
+        assert_eq!(keymap.describe(KeyAction::ExternalEditComment), "Alt+E");
+
+        let bindings = HashMap::from([("reload_labels".to_string(), "f5".to_string())]);
+        let keymap = Keymap::from_bindings(&bindings).unwrap();
+        assert_eq!(keymap.describe(KeyAction::ReloadLabels), "F5");
+    }
+
+    #[test]
+    fn vim_letters_become_list_moves() {
+        let key = |code, modifiers| Event::Key(KeyEvent::new(code, modifiers));
+
+        assert_eq!(
+            *list_motion(&key(KeyCode::Char('j'), KeyModifiers::NONE)),
+            key(KeyCode::Down, KeyModifiers::NONE)
+        );
+        assert_eq!(
+            *list_motion(&key(KeyCode::Char('G'), KeyModifiers::SHIFT)),
+            key(KeyCode::Down, KeyModifiers::CONTROL)
+        );
+        let ctrl_k = key(KeyCode::Char('k'), KeyModifiers::CONTROL);
+        assert_eq!(*list_motion(&ctrl_k), ctrl_k);
+    }
+}
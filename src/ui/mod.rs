@@ -1,7 +1,11 @@
 pub mod components;
+pub mod filter;
+pub mod issue_columns;
 pub mod issue_data;
+pub mod keymap;
 pub mod layout;
 pub mod macros;
+pub mod navigation;
 pub mod theme;
 pub mod utils;
 pub mod widgets;
@@ -10,36 +14,42 @@ pub mod widgets;
 pub(crate) mod testing;
 
 use crate::{
-    app::GITHUB_CLIENT,
+    app::{cli::LogLevel, client},
     bookmarks::{Bookmarks, read_bookmarks},
     define_cid_map,
     errors::{AppError, Result},
+    github::{IssueTemplate, SubscriptionState},
+    logging,
     ui::components::{
         Component, DumbComponent,
+        command_palette::{CommandPalette, PaletteCommandKind, PaletteOutcome},
         help::HelpElementKind,
-        issue_conversation::IssueConversation,
+        issue_conversation::{DEFAULT_COMMENT_WARN_CHARS, IssueConversation},
         issue_create::IssueCreate,
         issue_detail::IssuePreview,
         issue_list::{IssueList, MainScreen},
-        label_list::LabelList,
+        label_list::{LabelList, LabelSort},
         search_bar::TextSearch,
         status_bar::StatusBar,
         title_bar::TitleBar,
     },
+    ui::issue_columns::{DEFAULT_ISSUE_COLUMNS, IssueColumn},
 };
-use ratatui_toaster::{ToastBuilder, ToastEngine, ToastEngineBuilder, ToastMessage};
+use ratatui_toaster::{ToastBuilder, ToastEngine, ToastEngineBuilder, ToastMessage, ToastType};
 
 use crossterm::{
     event::{
-        DisableBracketedPaste, EnableBracketedPaste, EventStream, KeyEvent,
-        KeyboardEnhancementFlags, PopKeyboardEnhancementFlags, PushKeyboardEnhancementFlags,
+        DisableBracketedPaste, DisableFocusChange, DisableMouseCapture, EnableBracketedPaste,
+        EnableFocusChange, EnableMouseCapture, Event, EventStream, KeyEvent,
+        KeyboardEnhancementFlags, MouseEventKind, PopKeyboardEnhancementFlags,
+        PushKeyboardEnhancementFlags,
     },
     execute,
 };
 use futures::{StreamExt, future::FutureExt};
 use octocrab::{
     Page,
-    models::{Label, issues::Issue, reactions::ReactionContent},
+    models::{Author, IssueState, Label, Milestone, issues::Issue, reactions::ReactionContent},
 };
 use rat_widget::{
     event::{HandleEvent, Outcome, Regular},
@@ -48,7 +58,7 @@ use rat_widget::{
 use ratatui::{
     crossterm,
     prelude::*,
-    widgets::{Block, Clear, Padding, Paragraph, WidgetRef, Wrap},
+    widgets::{Block, Padding, WidgetRef},
 };
 use std::{
     collections::HashMap,
@@ -59,17 +69,23 @@ use std::{
 };
 use tachyonfx::{EffectManager, Interpolation, fx};
 use termprofile::{DetectorSettings, TermProfile};
-use tokio::{select, sync::mpsc::Sender};
+use tokio::{
+    select,
+    sync::{mpsc::Sender, watch},
+    time::MissedTickBehavior,
+};
 use tokio_util::sync::CancellationToken;
 use tracing::{error, info, instrument, trace};
 
 use anyhow::anyhow;
 
 use crate::ui::components::{
-    issue_conversation::{CommentView, IssueConversationSeed, TimelineEventView},
+    error_panel::ErrorPanel,
+    issue_conversation::{CommentView, IssueConversationSeed, PrFileView, TimelineEventView},
     issue_detail::{IssuePreviewSeed, PrSummary},
 };
 use crate::ui::issue_data::{IssueId, UiIssuePool};
+use crate::ui::navigation::NavigationHistory;
 
 const TICK_RATE: std::time::Duration = std::time::Duration::from_millis(60);
 pub static COLOR_PROFILE: OnceLock<TermProfile> = OnceLock::new();
@@ -83,20 +99,22 @@ const HELP_TEXT: &[HelpElementKind] = &[
     crate::help_keybind!("4", "focus Label List"),
     crate::help_keybind!("5", "focus Issue Create"),
     crate::help_keybind!("q / Ctrl+C", "quit the application"),
-    crate::help_keybind!("? / Ctrl+H", "toggle help menu"),
+    crate::help_keybind!("?", "toggle help for the focused component"),
+    crate::help_keybind!("Ctrl+H", "toggle this global help"),
+    crate::help_keybind!(": / Ctrl+Shift+P", "open command palette"),
+    crate::help_keybind!("Ctrl+X", "dismiss warning banner"),
+    crate::help_keybind!("Tab / Shift+Tab", "move focus forward / backward"),
+    crate::help_keybind!(
+        "Alt+Left / Alt+Right",
+        "go back / forward through visited issues"
+    ),
     crate::help_text!(""),
     crate::help_text!(
         "Navigate with the focus keys above. Components may have additional controls."
     ),
 ];
 
-pub async fn run(
-    AppState {
-        repo,
-        owner,
-        current_user,
-    }: AppState,
-) -> Result<(), AppError> {
+pub async fn run(state: AppState) -> Result<(), AppError> {
     if COLOR_PROFILE.get().is_none() {
         COLOR_PROFILE
             .set(TermProfile::detect(&stdout(), DetectorSettings::default()))
@@ -105,12 +123,7 @@ pub async fn run(
     let mut terminal = ratatui::init();
     setup_more_panic_hooks();
     let (action_tx, action_rx) = tokio::sync::mpsc::channel(100);
-    let mut app = App::new(
-        action_tx,
-        action_rx,
-        AppState::new(repo, owner, current_user),
-    )
-    .await?;
+    let mut app = App::new(action_tx, action_rx, state).await?;
     let run_result = app.run(&mut terminal).await;
     ratatui::restore();
     finish_teardown()?;
@@ -127,20 +140,42 @@ struct App {
     dumb_components: Vec<Box<dyn DumbComponent>>,
     help: Option<&'static [HelpElementKind]>,
     in_help: bool,
+    /// Whether the help overlay shows the global keys rather than the focused component's.
+    global_help: bool,
     in_editor: bool,
+    command_palette: Option<CommandPalette>,
     last_frame: time::Instant,
     current_screen: MainScreen,
     last_focused: Option<FocusFlag>,
-    last_event_error: Option<String>,
+    error_panel: Option<ErrorPanel>,
+    banner: Option<String>,
     effects_manager: EffectManager<()>,
     bookmarks: Arc<RwLock<Bookmarks>>,
+    history: NavigationHistory,
+    /// Whether the event task should emit [`Action::Tick`]. While nothing is animating the task
+    /// only wakes for input, so an idle UI doesn't redraw.
+    ticking: watch::Sender<bool>,
 }
 
+pub const DEFAULT_LABEL_CACHE_TTL: std::time::Duration = std::time::Duration::from_secs(300);
+
 #[derive(Debug, Default, Clone)]
 pub struct AppState {
     repo: String,
     owner: String,
     current_user: String,
+    banner: Option<String>,
+    issue_columns: Vec<IssueColumn>,
+    skip_label_confirm: bool,
+    label_sort: LabelSort,
+    group_labels: bool,
+    extra_self_logins: Vec<String>,
+    poll_interval: Option<std::time::Duration>,
+    label_separator: char,
+    label_cache_ttl: std::time::Duration,
+    comment_warn_chars: usize,
+    per_page: Option<u8>,
+    default_query: Option<String>,
 }
 
 impl AppState {
@@ -149,8 +184,91 @@ impl AppState {
             repo,
             owner,
             current_user,
+            banner: None,
+            issue_columns: DEFAULT_ISSUE_COLUMNS.to_vec(),
+            skip_label_confirm: false,
+            label_sort: LabelSort::default(),
+            group_labels: false,
+            extra_self_logins: Vec::new(),
+            poll_interval: None,
+            label_separator: ';',
+            label_cache_ttl: DEFAULT_LABEL_CACHE_TTL,
+            comment_warn_chars: DEFAULT_COMMENT_WARN_CHARS,
+            per_page: None,
+            default_query: None,
         }
     }
+
+    /// Sets a warning shown in place of the title bar until dismissed.
+    pub fn with_banner(mut self, banner: Option<String>) -> Self {
+        self.banner = banner;
+        self
+    }
+
+    /// Sets the columns shown on each issue list row.
+    pub fn with_issue_columns(mut self, columns: Vec<IssueColumn>) -> Self {
+        self.issue_columns = columns;
+        self
+    }
+
+    /// Skips the y/n prompt before creating a missing label and goes straight to picking a color.
+    pub fn with_skip_label_confirm(mut self, skip: bool) -> Self {
+        self.skip_label_confirm = skip;
+        self
+    }
+
+    /// Sets additional logins, such as bot accounts, whose comments are styled as your own.
+    pub fn with_self_logins(mut self, logins: Vec<String>) -> Self {
+        self.extra_self_logins = logins;
+        self
+    }
+
+    /// Polls the open issue for new comments every `interval`. `None` disables polling.
+    pub fn with_poll_interval(mut self, interval: Option<std::time::Duration>) -> Self {
+        self.poll_interval = interval;
+        self
+    }
+
+    /// Sets how long the cached repository labels are used before being refetched.
+    pub fn with_label_cache_ttl(mut self, ttl: std::time::Duration) -> Self {
+        self.label_cache_ttl = ttl;
+        self
+    }
+
+    /// Sets the character separating multiple labels in the search bar's label field.
+    pub fn with_label_separator(mut self, separator: char) -> Self {
+        self.label_separator = separator;
+        self
+    }
+
+    /// Sets the composer length at which its character count turns red.
+    pub fn with_comment_warn_chars(mut self, chars: usize) -> Self {
+        self.comment_warn_chars = chars;
+        self
+    }
+
+    /// Sets the page size of searches and the query the issue list starts with. `None` keeps the
+    /// built-in page sizes and the open issues.
+    pub fn with_search_defaults(mut self, per_page: Option<u8>, query: Option<String>) -> Self {
+        self.per_page = per_page;
+        self.default_query = query;
+        self
+    }
+
+    /// The authenticated user followed by any extra logins treated as them.
+    pub fn self_logins(&self) -> Vec<String> {
+        std::iter::once(self.current_user.clone())
+            .chain(self.extra_self_logins.iter().cloned())
+            .filter(|login| !login.is_empty())
+            .collect()
+    }
+
+    /// Sets the label order and whether `prefix:` labels are shown in sections.
+    pub fn with_label_display(mut self, sort: LabelSort, group: bool) -> Self {
+        self.label_sort = sort;
+        self.group_labels = group;
+        self
+    }
 }
 
 fn focus(state: &mut App) -> Result<&mut Focus, AppError> {
@@ -175,7 +293,7 @@ impl App {
     fn capture_error(&mut self, err: impl Display) {
         let message = err.to_string();
         error!(error = %message, "captured ui error");
-        self.last_event_error = Some(message);
+        self.error_panel = Some(ErrorPanel::from_message(message));
     }
 
     pub async fn new(
@@ -191,18 +309,15 @@ impl App {
         let mut issue_conversation = IssueConversation::new(state.clone(), issue_pool.clone());
         let mut issue_create = IssueCreate::new(state.clone(), issue_pool.clone());
         let bookmarks = Arc::new(RwLock::new(read_bookmarks()));
-        let issue_handler = GITHUB_CLIENT
-            .get()
-            .ok_or_else(|| AppError::Other(anyhow!("github client is not initialized")))?
+        let issue_handler = client()?
             .inner()
             .issues(state.owner.clone(), state.repo.clone());
         let mut issue_list = IssueList::new(
             issue_handler,
-            state.owner.clone(),
-            state.repo.clone(),
+            &state,
             action_tx.clone(),
             bookmarks.clone(),
-            issue_pool.clone(),
+            issue_pool,
         )
         .await;
 
@@ -219,6 +334,8 @@ impl App {
             focus: None,
             toast_engine: None,
             in_help: false,
+            global_help: false,
+            command_palette: None,
             last_frame: time::Instant::now(),
             in_editor: false,
             current_screen: MainScreen::default(),
@@ -227,8 +344,11 @@ impl App {
             effects_manager,
             action_rx,
             bookmarks,
+            history: NavigationHistory::default(),
+            ticking: watch::Sender::new(true),
             last_focused: None,
-            last_event_error: None,
+            error_panel: None,
+            banner: state.banner.clone(),
             cancel_action: Default::default(),
             components: comps,
             dumb_components: vec![
@@ -247,22 +367,39 @@ impl App {
         for component in self.components.iter_mut() {
             component.register_action_tx(action_tx.clone());
         }
+        for component in self.dumb_components.iter_mut() {
+            component.register_action_tx(action_tx.clone());
+        }
 
         if let Err(err) = setup_terminal() {
             self.capture_error(err);
         }
 
+        let mut ticking = self.ticking.subscribe();
         tokio::spawn(async move {
             let mut tick_interval = tokio::time::interval(TICK_RATE);
+            tick_interval.set_missed_tick_behavior(MissedTickBehavior::Skip);
             let mut event_stream = EventStream::new();
 
             loop {
+                let should_tick = *ticking.borrow_and_update();
                 let event = select! {
                     _ = ctok.cancelled() => break,
-                    _ = tick_interval.tick() => Action::Tick,
+                    _ = tick_interval.tick(), if should_tick => Action::Tick,
+                    changed = ticking.changed() => {
+                        if changed.is_err() {
+                            break;
+                        }
+                        tick_interval.reset();
+                        continue;
+                    }
                     kevent = event_stream.next().fuse() => {
                         match kevent {
-                            Some(Ok(kevent)) => Action::AppEvent(kevent),
+                            // Plain pointer motion changes nothing and would redraw on every move.
+                            Some(Ok(Event::Mouse(mouse))) if mouse.kind == MouseEventKind::Moved => {
+                                continue;
+                            }
+                            Some(Ok(kevent)) => Action::AppEvent(utils::normalize_back_tab(kevent)),
                             Some(Err(..)) => Action::None,
                             None => break,
                         }
@@ -300,11 +437,26 @@ impl App {
                 if self.in_editor && matches!(action, Action::Tick | Action::AppEvent(_)) {
                     continue;
                 }
+            }
+            let palette_consumed = match &action {
+                Some(Action::AppEvent(event)) => match self.handle_palette_event(event).await {
+                    Ok(consumed) => consumed,
+                    Err(err) => {
+                        self.capture_error(err);
+                        should_draw_error_popup = true;
+                        true
+                    }
+                },
+                _ => false,
+            };
+            if let Some(ref action) = action
+                && !palette_consumed
+            {
                 for component in self.components.iter_mut() {
                     if let Err(err) = component.handle_event(action.clone()).await {
                         let message = err.to_string();
                         error!(error = %message, "captured ui error");
-                        self.last_event_error = Some(message);
+                        self.error_panel = Some(ErrorPanel::new(&err, None));
                         should_draw_error_popup = true;
                     }
                     if component.gained_focus() && self.last_focused != Some(component.focus()) {
@@ -316,7 +468,7 @@ impl App {
                     if let Err(err) = component.handle_event(action.clone()).await {
                         let message = err.to_string();
                         error!(error = %message, "captured ui error");
-                        self.last_event_error = Some(message);
+                        self.error_panel = Some(ErrorPanel::new(&err, None));
                         should_draw_error_popup = true;
                     }
                 }
@@ -388,6 +540,7 @@ impl App {
                         should_draw_error_popup = true;
                     }
                 },
+                Some(Action::AppEvent(_)) if palette_consumed => {}
                 Some(Action::AppEvent(ref event)) => {
                     info!(?event, "Received app event");
                     if let Err(err) = self.handle_event(event).await {
@@ -395,6 +548,29 @@ impl App {
                         should_draw_error_popup = true;
                     }
                 }
+                Some(Action::ShowError {
+                    ref error,
+                    ref retry,
+                }) => {
+                    error!(error = %error, "showing error panel");
+                    let retry = retry.as_deref().cloned();
+                    self.error_panel = Some(ErrorPanel::new(error.as_ref(), retry));
+                }
+                Some(Action::SetLogLevel(ref level)) => {
+                    let toast = match logging::set_level(level.clone()) {
+                        Ok(()) => {
+                            info!(%level, "changed log level");
+                            toast_action(format!("Log level set to {level}"), ToastType::Info)
+                        }
+                        Err(err) => toast_action(
+                            format!("Failed to change the log level: {err}"),
+                            ToastType::Error,
+                        ),
+                    };
+                    if let Err(err) = self.action_tx.send(toast).await {
+                        self.capture_error(err);
+                    }
+                }
                 Some(Action::SetHelp(help)) => {
                     self.help = Some(help);
                 }
@@ -405,6 +581,9 @@ impl App {
                     self.current_screen = screen;
                     focus_noret(self);
                 }
+                Some(Action::EnterIssueDetails { ref seed }) => {
+                    self.history.visit(seed.number);
+                }
                 Some(Action::Quit) | None => {
                     ctok.cancel();
                 }
@@ -427,6 +606,12 @@ impl App {
                     self.capture_error(err);
                 }
             }
+            let needs_ticks = self.needs_ticks();
+            self.ticking.send_if_modified(|ticking| {
+                let changed = *ticking != needs_ticks;
+                *ticking = needs_ticks;
+                changed
+            });
             if self.cancel_action.is_cancelled() {
                 if let Ok(bm) = self.bookmarks.try_write() {
                     if let Err(err) = bm.write_to_file() {
@@ -456,17 +641,27 @@ impl App {
             self.cancel_action.cancel();
             return Ok(());
         }
-        if self.last_event_error.is_some() {
-            if matches!(
+        if let Some(panel) = self.error_panel.as_mut() {
+            if matches!(event, ct_event!(key press 'r'))
+                && let Some(retry) = panel.take_retry()
+            {
+                self.error_panel = None;
+                self.action_tx.send(retry).await?;
+            } else if matches!(
                 event,
                 ct_event!(keycode press Esc) | ct_event!(keycode press Enter)
             ) {
-                self.last_event_error = None;
+                self.error_panel = None;
             }
             return Ok(());
         }
+        if self.banner.is_some() && matches!(event, ct_event!(key press CONTROL-'x')) {
+            self.banner = None;
+            return Ok(());
+        }
         if matches!(event, ct_event!(key press CONTROL-'h')) {
             self.in_help = !self.in_help;
+            self.global_help = true;
             self.help = Some(HELP_TEXT);
             return Ok(());
         }
@@ -507,26 +702,91 @@ impl App {
                         .map_err(|_| {
                             AppError::Other(anyhow!("focus shortcut is out of expected range"))
                         })?;
-                    //SAFETY: cid is always in map, and map is static
-                    trace!("Focusing {}", index);
-                    let cid_map = CIDMAP
-                        .get()
-                        .ok_or_else(|| AppError::ErrorSettingGlobal("component id map"))?;
-                    let cid = cid_map.get(&index).ok_or_else(|| {
-                        AppError::Other(anyhow!("component id {index} not found in focus map"))
-                    })?;
-                    //SAFETY: cid is in map, and map is static
-                    let component = unsafe { self.components.get_unchecked(*cid) };
-
-                    if let Some(f) = self.focus.as_mut() {
-                        f.focus(component.as_ref());
-                    }
+                    self.focus_component(index)?;
                 }
                 _ => {}
             }
         }
         Ok(())
     }
+    /// Routes `event` to the command palette if it is open, or opens it on the trigger key.
+    /// Returns `true` when the event was consumed and should not reach the components.
+    async fn handle_palette_event(
+        &mut self,
+        event: &crossterm::event::Event,
+    ) -> Result<bool, AppError> {
+        use rat_widget::event::ct_event;
+        if let Some(palette) = self.command_palette.as_mut() {
+            if matches!(
+                event,
+                ct_event!(key press CONTROL-'c') | ct_event!(key press CONTROL-'q')
+            ) {
+                self.command_palette = None;
+                return Ok(false);
+            }
+            match palette.handle_event(event) {
+                PaletteOutcome::Continue => {}
+                PaletteOutcome::Close => self.command_palette = None,
+                PaletteOutcome::Run(command) => {
+                    self.command_palette = None;
+                    self.run_palette_command(command).await?;
+                }
+            }
+            return Ok(true);
+        }
+        if self.error_panel.is_some() || !CommandPalette::is_trigger(event) {
+            return Ok(false);
+        }
+        let capture_focus = self
+            .components
+            .iter()
+            .any(|c| c.should_render() && c.capture_focus_event(event));
+        if capture_focus {
+            return Ok(false);
+        }
+        self.in_help = false;
+        self.command_palette = Some(CommandPalette::new(self.help));
+        Ok(true)
+    }
+
+    async fn run_palette_command(&mut self, command: PaletteCommandKind) -> Result<(), AppError> {
+        match command {
+            PaletteCommandKind::Dispatch(actions) => {
+                for action in actions {
+                    self.action_tx.send(action).await?;
+                }
+            }
+            PaletteCommandKind::Focus(index) => self.focus_component(index)?,
+            PaletteCommandKind::ToggleHelp => {
+                self.in_help = !self.in_help;
+                self.global_help = false;
+            }
+            PaletteCommandKind::Key(key) => {
+                self.action_tx
+                    .send(Action::AppEvent(crossterm::event::Event::Key(key)))
+                    .await?;
+            }
+        }
+        Ok(())
+    }
+
+    fn focus_component(&mut self, index: u8) -> Result<(), AppError> {
+        trace!("Focusing {}", index);
+        let cid_map = CIDMAP
+            .get()
+            .ok_or_else(|| AppError::ErrorSettingGlobal("component id map"))?;
+        let cid = cid_map.get(&index).ok_or_else(|| {
+            AppError::Other(anyhow!("component id {index} not found in focus map"))
+        })?;
+        //SAFETY: cid is in map, and map is static
+        let component = unsafe { self.components.get_unchecked(*cid) };
+
+        if let Some(f) = self.focus.as_mut() {
+            f.focus(component.as_ref());
+        }
+        Ok(())
+    }
+
     async fn handle_key(&mut self, key: &crossterm::event::KeyEvent) -> Result<(), AppError> {
         use crossterm::event::KeyCode::*;
         if matches!(key.code, Char('q'))
@@ -543,17 +803,56 @@ impl App {
         }
         if matches!(key.code, Char('?')) {
             self.in_help = !self.in_help;
+            self.global_help = false;
+        }
+        if key.modifiers == crossterm::event::KeyModifiers::ALT {
+            match key.code {
+                Left => {
+                    let target = self.history.back();
+                    self.open_from_history(target, "No earlier issue to go back to.")
+                        .await?;
+                }
+                Right => {
+                    let target = self.history.forward();
+                    self.open_from_history(target, "No later issue to go forward to.")
+                        .await?;
+                }
+                _ => {}
+            }
         }
 
         Ok(())
     }
 
+    /// Hands an issue picked from the navigation history to the issue list, which opens it.
+    async fn open_from_history(
+        &mut self,
+        number: Option<u64>,
+        empty_message: &str,
+    ) -> Result<(), AppError> {
+        let action = match number {
+            Some(number) => Action::OpenIssueFromHistory { number },
+            None => toast_action(empty_message, ToastType::Info),
+        };
+        self.action_tx.send(action).await?;
+        Ok(())
+    }
+
     fn has_animated_components(&self) -> bool {
         self.components
             .iter()
             .any(|component| component.should_render() && component.is_animating())
     }
 
+    fn needs_ticks(&self) -> bool {
+        self.has_animated_components()
+            || self.effects_manager.is_running()
+            || self
+                .toast_engine
+                .as_ref()
+                .is_some_and(|engine| engine.has_toast())
+    }
+
     fn draw(
         &mut self,
         terminal: &mut Terminal<CrosstermBackend<impl std::io::Write>>,
@@ -575,6 +874,9 @@ impl App {
                     f.set_cursor_position(p);
                 }
             }
+            if let Some(palette) = self.command_palette.as_ref() {
+                f.set_cursor_position(palette.cursor(area));
+            }
             let buf = f.buffer_mut();
 
             for component in self.components.iter_mut() {
@@ -586,32 +888,37 @@ impl App {
                 for component in self.dumb_components.iter_mut() {
                     component.render(layout, buf);
                 }
+                if let Some(banner) = self.banner.as_deref() {
+                    render_banner(banner, layout.title_bar, buf);
+                }
             }
             if self.in_help {
                 let help_text = self.help.unwrap_or(HELP_TEXT);
-                let help_component = components::help::HelpComponent::new(help_text)
-                    .set_constraint(30)
-                    .block(
-                        Block::bordered()
-                            .title("Help")
-                            .padding(Padding::horizontal(2))
-                            .border_type(ratatui::widgets::BorderType::Rounded),
-                    );
+                let mut help_component = components::help::HelpComponent::new(help_text);
+                let context_lines = self
+                    .components
+                    .iter()
+                    .find(|component| component.should_render() && component.is_focused())
+                    .map(|component| component.help_lines())
+                    .filter(|lines| !self.global_help && !lines.is_empty());
+                if let Some(mut lines) = context_lines {
+                    lines.push(Line::default());
+                    lines.push(Line::from("Ctrl+H shows the global keys").centered().dim());
+                    help_component = help_component.lines(lines);
+                }
+                let help_component = help_component.set_constraint(30).block(
+                    Block::bordered()
+                        .title("Help")
+                        .padding(Padding::horizontal(2))
+                        .border_type(ratatui::widgets::BorderType::Rounded),
+                );
                 help_component.render(area, buf);
             }
-            if let Some(err) = self.last_event_error.as_ref() {
-                let popup_area = area.centered(Constraint::Percentage(60), Constraint::Length(5));
-                Clear.render(popup_area, buf);
-                let popup = Paragraph::new(err.as_str())
-                    .wrap(Wrap { trim: false })
-                    .block(
-                        Block::bordered()
-                            .title("Error")
-                            .title_bottom("Esc/Enter: dismiss")
-                            .padding(Padding::horizontal(1))
-                            .border_type(ratatui::widgets::BorderType::Rounded),
-                    );
-                popup.render(popup_area, buf);
+            if let Some(palette) = self.command_palette.as_ref() {
+                palette.render(area, buf);
+            }
+            if let Some(panel) = self.error_panel.as_ref() {
+                panel.render(area, buf);
             }
             if let Some(ref mut toast_engine) = self.toast_engine {
                 toast_engine.set_area(area);
@@ -631,6 +938,14 @@ pub enum Action {
     Quit,
     AppEvent(crossterm::event::Event),
     RefreshIssueList,
+    /// Switches the log file's verbosity without restarting.
+    SetLogLevel(LogLevel),
+    /// Shows `error` and its causes in the error panel. `retry` is sent again if the user
+    /// chooses to retry from the panel.
+    ShowError {
+        error: Arc<AppError>,
+        retry: Option<Box<Action>>,
+    },
     NewPage(Arc<Page<Issue>>, MergeStrategy),
     ForceRender,
     SelectedIssue {
@@ -666,9 +981,23 @@ pub enum Action {
     EnterIssueDetails {
         seed: IssueConversationSeed,
     },
+    /// Opens an issue picked from the back/forward history. The issue list only moves its
+    /// cursor when the issue is one of the listed ones.
+    OpenIssueFromHistory {
+        number: u64,
+    },
+    /// First page of an issue's comments. `more` is set while further pages are still being
+    /// fetched.
     IssueCommentsLoaded {
         number: u64,
         comments: Vec<CommentView>,
+        more: bool,
+    },
+    /// A later page of comments, appended to the ones already loaded.
+    IssueCommentsPageLoaded {
+        number: u64,
+        comments: Vec<CommentView>,
+        more: bool,
     },
     IssueTimelineLoaded {
         number: u64,
@@ -678,6 +1007,15 @@ pub enum Action {
         number: u64,
         message: String,
     },
+    PullRequestFilesLoaded {
+        number: u64,
+        files: Vec<PrFileView>,
+        truncated: bool,
+    },
+    PullRequestFilesError {
+        number: u64,
+        message: String,
+    },
     IssueReactionsLoaded {
         reactions: HashMap<u64, Vec<(ReactionContent, u64)>>,
         own_reactions: HashMap<u64, Vec<ReactionContent>>,
@@ -690,6 +1028,18 @@ pub enum Action {
         number: u64,
         comment: CommentView,
     },
+    IssueSubscriptionLoaded {
+        number: u64,
+        state: Option<SubscriptionState>,
+    },
+    IssueSubscriptionError {
+        number: u64,
+        message: String,
+    },
+    IssueCommentCountPolled {
+        number: u64,
+        count: u32,
+    },
     IssueCommentsError {
         number: u64,
         message: String,
@@ -703,11 +1053,33 @@ pub enum Action {
         comment_id: u64,
         result: std::result::Result<String, String>,
     },
-    IssueCommentPatched {
-        issue_number: u64,
+    IssueCommentEdited {
+        number: u64,
         comment: CommentView,
     },
+    /// The issue's title and body after editing them.
+    IssueEdited {
+        number: u64,
+        title: String,
+        body: Option<String>,
+    },
+    IssueEditError {
+        number: u64,
+        message: String,
+    },
+    IssueCommentDeleted {
+        number: u64,
+        id: u64,
+    },
+    IssueCommentDeleteError {
+        number: u64,
+        id: u64,
+        message: String,
+    },
     EnterIssueCreate,
+    IssueTemplatesLoaded {
+        templates: Vec<IssueTemplate>,
+    },
     IssueCreateSuccess {
         issue_id: IssueId,
     },
@@ -721,12 +1093,66 @@ pub enum Action {
         number: u64,
         message: String,
     },
+    IssueLockChanged {
+        number: u64,
+        locked: bool,
+    },
+    IssueLockError {
+        number: u64,
+        message: String,
+    },
+    /// The issue's assignees after some were added or removed.
+    IssueAssigneesUpdated {
+        number: u64,
+        assignees: Vec<Author>,
+    },
+    IssueAssigneesError {
+        number: u64,
+        message: String,
+    },
+    /// Open milestones fetched for the milestone picker of issue `number`.
+    MilestonesLoaded {
+        number: u64,
+        milestones: Vec<Milestone>,
+    },
+    /// The issue's milestone title after it was set, or `None` once cleared.
+    IssueMilestoneUpdated {
+        number: u64,
+        milestone: Option<String>,
+    },
+    IssueMilestoneError {
+        number: u64,
+        message: String,
+    },
+    IssueStateChanged {
+        number: u64,
+        state: IssueState,
+    },
+    IssueStateChangeError {
+        number: u64,
+        message: String,
+    },
     IssueLabelsUpdated {
         number: u64,
         labels: Vec<Label>,
     },
-    LabelMissing {
-        name: String,
+    /// Outcome of adding one or more labels. `failed` holds each name with the reason;
+    /// `missing` names don't exist in the repository yet.
+    LabelAddReport {
+        added: Vec<String>,
+        failed: Vec<String>,
+        missing: Vec<String>,
+    },
+    ReloadRepoLabels,
+    RepoLabelsLoaded {
+        labels: Vec<Label>,
+    },
+    RepoLabelsError {
+        message: String,
+    },
+    /// A repository label was changed, e.g. recolored from the label list.
+    RepoLabelUpdated {
+        label: octocrab::models::Label,
     },
     LabelEditError {
         message: String,
@@ -746,6 +1172,16 @@ pub enum Action {
         request_id: u64,
         message: String,
     },
+    SearchError {
+        message: String,
+    },
+    SearchTermsChanged {
+        terms: Vec<String>,
+    },
+    ExportSearchCsv,
+    SearchExportFinished {
+        result: Result<(std::path::PathBuf, usize), String>,
+    },
     ChangeIssueScreen(MainScreen),
     FinishedLoading,
     ForceFocusChange,
@@ -794,10 +1230,42 @@ impl CloseIssueReason {
     }
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LockIssueReason {
+    OffTopic,
+    TooHeated,
+    Resolved,
+    Spam,
+}
+
+impl LockIssueReason {
+    pub const ALL: [Self; 4] = [Self::OffTopic, Self::TooHeated, Self::Resolved, Self::Spam];
+
+    pub const fn label(self) -> &'static str {
+        match self {
+            Self::OffTopic => "Off-topic",
+            Self::TooHeated => "Too heated",
+            Self::Resolved => "Resolved",
+            Self::Spam => "Spam",
+        }
+    }
+
+    pub const fn to_octocrab(self) -> octocrab::params::LockReason {
+        match self {
+            Self::OffTopic => octocrab::params::LockReason::OffTopic,
+            Self::TooHeated => octocrab::params::LockReason::TooHeated,
+            Self::Resolved => octocrab::params::LockReason::Resolved,
+            Self::Spam => octocrab::params::LockReason::Spam,
+        }
+    }
+}
+
 fn finish_teardown() -> Result<()> {
     let mut stdout = stdout();
     execute!(stdout, PopKeyboardEnhancementFlags)?;
     execute!(stdout, DisableBracketedPaste)?;
+    execute!(stdout, DisableFocusChange)?;
+    execute!(stdout, DisableMouseCapture)?;
 
     Ok(())
 }
@@ -821,6 +1289,8 @@ fn setup_terminal() -> Result<()> {
         PushKeyboardEnhancementFlags(KeyboardEnhancementFlags::DISAMBIGUATE_ESCAPE_CODES)
     )?;
     execute!(stdout, EnableBracketedPaste)?;
+    execute!(stdout, EnableFocusChange)?;
+    execute!(stdout, EnableMouseCapture)?;
 
     Ok(())
 }
@@ -835,6 +1305,17 @@ fn setup_more_panic_hooks() {
     }));
 }
 
+fn render_banner(message: &str, area: Rect, buf: &mut Buffer) {
+    let style = Style::new().black().on_yellow();
+    buf.set_style(area, style);
+    Line::from(vec![
+        Span::styled(" ! ", style.bold()),
+        Span::styled(message.to_string(), style),
+        Span::styled("  (Ctrl+X: dismiss)", style.italic()),
+    ])
+    .render(area, buf);
+}
+
 fn toast_action(message: impl Into<String>, toast_type: ratatui_toaster::ToastType) -> Action {
     use ratatui_toaster::ToastPosition::TopRight;
     Action::ToastAction(ratatui_toaster::ToastMessage::Show {
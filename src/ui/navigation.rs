@@ -0,0 +1,94 @@
+/// Maximum number of issues remembered in each direction.
+pub const MAX_HISTORY: usize = 50;
+
+/// Browser-like back/forward history of the issues opened in the conversation view.
+#[derive(Debug, Default)]
+pub struct NavigationHistory {
+    back: Vec<u64>,
+    forward: Vec<u64>,
+    current: Option<u64>,
+}
+
+impl NavigationHistory {
+    /// Records that `number` was opened. Opening a different issue than the current one drops
+    /// the forward history; reopening the current issue is a no-op.
+    pub fn visit(&mut self, number: u64) {
+        if self.current == Some(number) {
+            return;
+        }
+        if let Some(previous) = self.current.replace(number) {
+            self.back.push(previous);
+            if self.back.len() > MAX_HISTORY {
+                self.back.remove(0);
+            }
+        }
+        self.forward.clear();
+    }
+
+    /// Steps back and returns the issue to show, if there is one.
+    pub fn back(&mut self) -> Option<u64> {
+        let previous = self.back.pop()?;
+        if let Some(current) = self.current.replace(previous) {
+            self.forward.push(current);
+        }
+        Some(previous)
+    }
+
+    /// Steps forward again after [`NavigationHistory::back`].
+    pub fn forward(&mut self) -> Option<u64> {
+        let next = self.forward.pop()?;
+        if let Some(current) = self.current.replace(next) {
+            self.back.push(current);
+        }
+        Some(next)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{MAX_HISTORY, NavigationHistory};
+
+    #[test]
+    fn moves_back_and_forward() {
+        let mut history = NavigationHistory::default();
+        history.visit(1);
+        history.visit(2);
+        history.visit(3);
+
+        assert_eq!(history.back(), Some(2));
+        assert_eq!(history.back(), Some(1));
+        assert_eq!(history.back(), None);
+        assert_eq!(history.forward(), Some(2));
+
+        // Re-dispatching the issue we navigated to must not drop the forward entries.
+        history.visit(2);
+        assert_eq!(history.forward(), Some(3));
+        assert_eq!(history.forward(), None);
+    }
+
+    #[test]
+    fn new_visit_clears_forward_history() {
+        let mut history = NavigationHistory::default();
+        history.visit(1);
+        history.visit(2);
+        assert_eq!(history.back(), Some(1));
+
+        history.visit(4);
+        assert_eq!(history.forward(), None);
+        assert_eq!(history.back(), Some(1));
+    }
+
+    #[test]
+    fn caps_history_depth() {
+        let mut history = NavigationHistory::default();
+        for number in 0..(MAX_HISTORY as u64 + 10) {
+            history.visit(number);
+        }
+
+        let mut steps = 0;
+        while history.back().is_some() {
+            steps += 1;
+        }
+        assert_eq!(steps, MAX_HISTORY);
+    }
+}
@@ -238,7 +238,8 @@ fn make_issue(
     let created_ts = 1_704_067_200_i64 + (idx as i64 * 3_600);
     let created_at_short = format_timestamp(created_ts, false);
     let created_at_full = format_timestamp(created_ts, true);
-    let updated_at_short = format_timestamp(created_ts + 1_800, false);
+    let updated_ts = created_ts + 1_800;
+    let updated_at_short = format_timestamp(updated_ts, false);
     let milestone = (idx % 3 == 0).then(|| {
         let milestone = milestones
             .choose(rng)
@@ -271,12 +272,17 @@ fn make_issue(
         created_at_short: pool.intern_str(&created_at_short),
         created_at_full: pool.intern_str(&created_at_full),
         updated_at_short: pool.intern_str(&updated_at_short),
+        updated_ts,
         comments: 2 + (idx % 8) as u32,
         assignees,
         milestone,
         is_pull_request,
         pull_request_url,
         labels: Vec::new(),
+        locked: false,
+        html_url: pool.intern_str(&format!(
+            "https://github.com/example/repo/issues/{issue_number}"
+        )),
     }
 }
 
@@ -291,11 +297,17 @@ fn make_comments(
         .map(|comment_idx| {
             let author = &authors[(issue_idx + comment_idx) % authors.len()];
             let created_ts = 1_704_067_200_i64 + (issue_idx as i64 * 7_200) + comment_idx as i64;
+            let id = issue_number * 100 + comment_idx as u64;
             CommentView {
-                id: issue_number * 100 + comment_idx as u64,
+                id,
+                html_url: format!(
+                    "https://github.com/example/repo/issues/{issue_number}#issuecomment-{id}"
+                )
+                .into(),
                 author: author.login.clone().into(),
                 created_at: format_timestamp(created_ts, false).into(),
                 created_ts,
+                updated_ts: None,
                 body: format!(
                     "{}\n\n{}",
                     Paragraph(1..3).fake_with_rng::<String, _>(rng),
@@ -1,5 +1,13 @@
+use crossterm::event::{Event, KeyCode, KeyModifiers};
+use std::str::FromStr;
+
 use rat_widget::focus::HasFocus;
-use ratatui::{layout::Rect, style::Style};
+use ratatui::{
+    layout::Rect,
+    style::{Color, Style},
+};
+
+use crate::ui::COLOR_PROFILE;
 
 pub fn get_loader_area(area: Rect) -> Rect {
     Rect {
@@ -20,3 +28,105 @@ pub fn get_border_style(state: &impl HasFocus) -> Style {
         default_border_style
     }
 }
+
+/// Parses a GitHub label color such as `d73a4a` and adapts it to what the terminal can show.
+/// Malformed colors fall back to gray.
+pub fn label_color(hex: &str) -> Color {
+    let color = Color::from_str(&format!("#{hex}")).unwrap_or(Color::Gray);
+    COLOR_PROFILE
+        .get()
+        .and_then(|profile| profile.adapt_color(color))
+        .unwrap_or(color)
+}
+
+/// Converts the carriage returns some terminals send inside bracketed pastes into newlines so
+/// pasted text lands in a text area with the same line breaks it was copied with.
+pub fn normalize_paste(text: &str) -> String {
+    text.replace("\r\n", "\n").replace('\r', "\n")
+}
+
+/// Terminals disagree on how Shift+Tab is reported: some send `BackTab` without a modifier,
+/// others `Tab` with Shift. Both are rewritten to `Shift+BackTab`, the form the focus system
+/// and components match on for moving focus backward.
+pub fn normalize_back_tab(event: Event) -> Event {
+    match event {
+        Event::Key(mut key)
+            if key.code == KeyCode::BackTab
+                || (key.code == KeyCode::Tab && key.modifiers.contains(KeyModifiers::SHIFT)) =>
+        {
+            key.code = KeyCode::BackTab;
+            key.modifiers |= KeyModifiers::SHIFT;
+            Event::Key(key)
+        }
+        event => event,
+    }
+}
+
+/// Current unix time in seconds.
+pub fn unix_now() -> i64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map_or(0, |elapsed| elapsed.as_secs() as i64)
+}
+
+/// Humanized age of unix timestamp `ts` relative to `now`, such as "3 hours ago". Returns `None`
+/// once the timestamp is more than a week old so callers can show the absolute date instead.
+pub fn relative_time(ts: i64, now: i64) -> Option<String> {
+    const MINUTE: i64 = 60;
+    const HOUR: i64 = 60 * MINUTE;
+    const DAY: i64 = 24 * HOUR;
+
+    let elapsed = now.saturating_sub(ts).max(0);
+    let (count, unit) = match elapsed {
+        e if e < MINUTE => return Some("just now".to_string()),
+        e if e < HOUR => (e / MINUTE, "minute"),
+        e if e < DAY => (e / HOUR, "hour"),
+        e if e <= 7 * DAY => (e / DAY, "day"),
+        _ => return None,
+    };
+    let plural = if count == 1 { "" } else { "s" };
+    Some(format!("{count} {unit}{plural} ago"))
+}
+
+#[cfg(test)]
+mod tests {
+    use crossterm::event::{Event, KeyCode, KeyEvent, KeyModifiers};
+
+    use super::{normalize_back_tab, relative_time};
+
+    #[test]
+    fn humanizes_recent_timestamps() {
+        let now = 1_700_000_000;
+        assert_eq!(relative_time(now - 5, now).as_deref(), Some("just now"));
+        assert_eq!(relative_time(now + 30, now).as_deref(), Some("just now"));
+        assert_eq!(
+            relative_time(now - 60, now).as_deref(),
+            Some("1 minute ago")
+        );
+        assert_eq!(
+            relative_time(now - 3 * 3600 - 59, now).as_deref(),
+            Some("3 hours ago")
+        );
+        assert_eq!(
+            relative_time(now - 2 * 86400, now).as_deref(),
+            Some("2 days ago")
+        );
+        assert_eq!(relative_time(now - 8 * 86400, now), None);
+    }
+
+    #[test]
+    fn normalizes_shift_tab_variants() {
+        let expected = Event::Key(KeyEvent::new(KeyCode::BackTab, KeyModifiers::SHIFT));
+        for (code, modifiers) in [
+            (KeyCode::BackTab, KeyModifiers::NONE),
+            (KeyCode::BackTab, KeyModifiers::SHIFT),
+            (KeyCode::Tab, KeyModifiers::SHIFT),
+        ] {
+            let event = Event::Key(KeyEvent::new(code, modifiers));
+            assert_eq!(normalize_back_tab(event), expected);
+        }
+
+        let tab = Event::Key(KeyEvent::new(KeyCode::Tab, KeyModifiers::NONE));
+        assert_eq!(normalize_back_tab(tab.clone()), tab);
+    }
+}
@@ -1,5 +1,3 @@
-use std::str::FromStr;
-
 use rat_widget::{
     event::{HandleEvent, Outcome, Regular},
     focus::{FocusFlag, HasFocus},
@@ -13,7 +11,7 @@ use ratatui::{
     widgets::{Block, Clear, Paragraph, Widget},
 };
 
-use crate::ui::COLOR_PROFILE;
+use crate::ui::utils::label_color;
 
 const HUES: [(&str, [&str; 5]); 8] = [
     ("Red", ["ffebe9", "ffcecb", "ffaba8", "ff8182", "fa4549"]),
@@ -157,7 +155,7 @@ impl ColorPicker {
                 Style::default().add_modifier(Modifier::BOLD),
             )];
             for (col_idx, shade) in shades.iter().enumerate() {
-                let bg = parse_hex_color(shade);
+                let bg = label_color(shade);
                 let is_selected = row_idx == state.row && col_idx == state.col;
                 let text = if is_selected { "<>" } else { "  " };
                 let mut style = Style::default().bg(bg);
@@ -172,7 +170,7 @@ impl ColorPicker {
         Paragraph::new(lines).render(grid_area, buf);
 
         let selected = state.selected_hex();
-        let preview = parse_hex_color(selected);
+        let preview = label_color(selected);
         let info = Line::from(vec![
             Span::styled(" ", Style::default().bg(preview)),
             Span::raw(format!(" #{selected}")),
@@ -180,13 +178,3 @@ impl ColorPicker {
         Paragraph::new(info).render(info_area, buf);
     }
 }
-
-fn parse_hex_color(hex: &str) -> Color {
-    let mut c = Color::from_str(&format!("#{hex}")).unwrap_or(Color::Gray);
-    if let Some(profile) = COLOR_PROFILE.get()
-        && let Some(adapted) = profile.adapt_color(c)
-    {
-        c = adapted;
-    }
-    c
-}
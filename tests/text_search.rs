@@ -1,9 +1,9 @@
 mod support;
 use crate::support::buffer_to_string;
-use gitv_tui::ui::AppState;
 use gitv_tui::ui::components::Component;
 use gitv_tui::ui::components::search_bar::TextSearch;
 use gitv_tui::ui::layout::Layout;
+use gitv_tui::ui::{Action, AppState};
 use insta::assert_snapshot;
 use ratatui::buffer::Buffer;
 use ratatui::layout::Rect;
@@ -57,3 +57,34 @@ fn text_search_both_inputs() {
     });
     assert_snapshot!(result);
 }
+
+#[tokio::test]
+async fn search_before_client_init_reports_error() {
+    let (action_tx, mut action_rx) = tokio::sync::mpsc::channel(8);
+    let mut search = TextSearch::new(AppState::new(
+        "owner".to_string(),
+        "repo".to_string(),
+        "user".to_string(),
+    ));
+    search.register_action_tx(action_tx);
+
+    search
+        .handle_event(Action::RefreshIssueList)
+        .await
+        .expect("refresh should not fail");
+
+    match action_rx.recv().await {
+        Some(Action::SearchTermsChanged { terms }) => assert!(terms.is_empty()),
+        other => panic!("expected the search terms first, got {other:?}"),
+    }
+    let action = tokio::time::timeout(std::time::Duration::from_secs(5), action_rx.recv())
+        .await
+        .expect("search task should report back")
+        .expect("action channel closed");
+    match action {
+        Action::SearchError { message } => {
+            assert_eq!(message, "GitHub client not initialized.");
+        }
+        other => panic!("expected a search error, got {other:?}"),
+    }
+}